@@ -1,25 +1,46 @@
 use bevy::{prelude::*, sprite::collide_aabb::collide, math::Vec3Swizzles};
-use std::collections::HashSet;
+use std::{collections::HashSet, f32::consts::TAU};
 use rand::{thread_rng, Rng};
 
-use lib::{meteor_score, MeteorSizeType, BULWARK_HEAL_CHANCE};
+use lib::{
+    meteor_score, meteor_mass, meteor_material_fragments, MeteorSizeType, MeteorMaterial, BULWARK_HEAL_CHANCE, PLAYER_MAX_SPEED,
+    BOSS_WEAK_POINT_DAMAGE_MULT, BOSS_CONTACT_DAMAGE, BOSS_SCORE,
+    ELITE_BLAST_DAMAGE, ELITE_BLAST_RADIUS, ELITE_EXTRA_FRAGMENTS, ELITE_SCORE_MULT,
+    EXPLOSIVE_METEOR_BLAST_DAMAGE, EXPLOSIVE_METEOR_BLAST_RADIUS, GOLD_SCORE_MULT,
+    ICE_SLOW_DURATION, METALLIC_DAMAGE_MULT, BULLET_IMPULSE,
+};
 
 use crate::{
-    common::common_components::{HitBoxSize, CollisionDespawnableWithDamage, DamageCollision, MeteorSplitEvent},
+    common::{
+        common_components::{
+            HitBoxSize, CollisionDespawnableWithDamage, DamageCollision, DamageSource, MeteorSplitEvent,
+            AreaBlastEvent, Held, Position, Velocity,
+        },
+        convex::ConvexCollider,
+    },
     effects::particle::spawn_explosion,
-    events::events::PlayerDeadEvent,
+    events::events::{PlayerDeadEvent, ShotKillEvent},
     objects::{
-        meteor::{MeteorComponent, spawn_meteor, MeteorHitFlash},
-        projectile::{ProjectileComponent, spawn_shrapnel},
-        powerup::PowerUpComponent,
-        ufo::{UfoComponent, UfoHitFlash, UfoProjectileComponent},
+        boss::{BossComponent, BossHitFlash, BossWeakPoint},
+        elite::{EliteAffix, EliteComponent},
+        meteor::{MeteorComponent, spawn_meteor, MeteorAssets, MeteorHitFlash, MeteorSpawn},
+        meteor_shape::{split_outline, MeteorShape},
+        projectile::{PrimaryShot, ProjectileComponent, spawn_shrapnel},
+        powerup::{PowerUpComponent, roll_powerup, spawn_powerup_at},
+        ufo::{UfoComponent, UfoHitFlash, UfoProjectileComponent, UfoType},
     },
     player::{
+        hyperspace::InHyperspace,
         player::{PlayerComponent, PlayerDamageFlash},
         ship::ShipComponent,
+        tractor::Thrown,
+    },
+    resources::{
+        CameraShake, GameSettings, GameSprites, IsPaused, LevelResource, Life, PlayerBuff, PlayerUpgrades, Score,
+        SecondaryAmmo, UpgradeSelectionState,
     },
-    resources::{CameraShake, GameSprites, IsPaused, Life, PlayerBuff, PlayerUpgrades, Score},
     state::states::GameStates,
+    utils::run_stats::RunStats,
 };
 
 pub struct CollisionPlugin;
@@ -31,9 +52,12 @@ impl Plugin for CollisionPlugin {
             (
                 player_projectile_hit_meteor_system,
                 player_projectile_hit_ufo_system,
+                player_projectile_hit_boss_system,
                 meteor_split_system,
+                area_blast_system,
                 player_hit_by_meteor_system,
                 player_hit_by_ufo_projectile_system,
+                player_hit_by_boss_system,
                 apply_damage_system,
                 player_collect_powerup_system,
             )
@@ -49,27 +73,31 @@ fn player_projectile_hit_meteor_system(
     mut commands:     Commands,
     game_sprites:     Res<GameSprites>,
     mut shake:        ResMut<CameraShake>,
-    mut projectile_q: Query<(Entity, &Transform, &HitBoxSize, &mut ProjectileComponent), Without<UfoProjectileComponent>>,
-    mut meteor_q:     Query<(Entity, &Transform, &HitBoxSize, &mut MeteorComponent)>,
+    settings:         Res<GameSettings>,
+    mut projectile_q: Query<(Entity, &Transform, &HitBoxSize, &Velocity, &mut ProjectileComponent, Option<&PrimaryShot>), (Without<UfoProjectileComponent>, Without<MeteorComponent>)>,
+    // A rock in the tractor beam sits in front of the guns, so bullets pass it.
+    mut meteor_q:     Query<(Entity, &Transform, &ConvexCollider, &MeteorShape, &mut MeteorComponent, &mut Velocity, Option<&EliteComponent>), Without<Held>>,
     mut score:        ResMut<Score>,
+    mut stats:        ResMut<RunStats>,
     mut upgrades:     ResMut<PlayerUpgrades>,
     mut life:         ResMut<Life>,
+    mut shot_kills:   EventWriter<ShotKillEvent>,
     ship_q:           Query<&ShipComponent, With<PlayerComponent>>,
 ) {
-    let ship_type = ship_q.get_single().map(|s| s.ship_type).unwrap_or(lib::ShipType::Normal);
+    let bolt = ship_q.get_single().map_or_else(|_| game_sprites.ships[0].projectile.clone(), |s| game_sprites.projectile(s));
     let mut rng = thread_rng();
 
     let mut despawned_projectiles: HashSet<Entity> = HashSet::new();
     let mut despawned_meteors:     HashSet<Entity> = HashSet::new();
 
-    for (proj_e, proj_tf, proj_hit, mut projectile) in projectile_q.iter_mut() {
+    for (proj_e, proj_tf, proj_hit, proj_vel, mut projectile, primary) in projectile_q.iter_mut() {
         if despawned_projectiles.contains(&proj_e) {
             continue;
         }
 
         let proj_scale = proj_tf.scale.xy();
 
-        for (meteor_e, meteor_tf, meteor_hit, mut meteor) in meteor_q.iter_mut() {
+        for (meteor_e, meteor_tf, collider, shape, mut meteor, mut meteor_vel, elite) in meteor_q.iter_mut() {
             if despawned_meteors.contains(&meteor_e)
                 || despawned_projectiles.contains(&proj_e)
                 || projectile.hit_meteors.contains(&meteor_e)
@@ -77,43 +105,45 @@ fn player_projectile_hit_meteor_system(
                 continue;
             }
 
-            let meteor_scale = meteor_tf.scale.xy();
+            if !collider.overlaps_rect(meteor_tf, proj_tf, proj_hit.0 * proj_scale) {
+                continue;
+            }
 
-            let hit = collide(
-                proj_tf.translation, proj_hit.0 * proj_scale,
-                meteor_tf.translation, meteor_hit.0 * meteor_scale,
-            );
+            // A piercing shot counts toward accuracy once.
+            if primary.is_some() && projectile.hit_meteors.is_empty() {
+                stats.shots_hit += 1;
+            }
 
-            if hit.is_none() {
+            // Shielded elites soak bullets that hit their front; pierce doesn't help.
+            if elite.is_some_and(|e| e.shield_blocks(meteor_tf.translation.xy(), proj_tf.translation.xy())) {
+                commands.entity(proj_e).despawn();
+                despawned_projectiles.insert(proj_e);
                 continue;
             }
 
+            // Meteor physics: the bullet's momentum shoves the rock, less so for big ones.
+            if settings.meteor_physics {
+                meteor_vel.0 += proj_vel.0.normalize_or_zero() * BULLET_IMPULSE / meteor_mass(meteor.size);
+            }
+
             // Deal damage.
-            meteor.health -= projectile.damage;
+            meteor.health -= material_damage(meteor.material, projectile.damage, &upgrades, &mut rng);
 
             if meteor.health <= 0 {
                 // Destroyed – despawn and schedule fragment spawn.
                 let meteor_pos  = meteor_tf.translation;
                 let meteor_size = meteor.size;
-                commands.entity(meteor_e).despawn();
+                destroy_meteor(
+                    &mut commands, &game_sprites, &mut shake, &mut score, &mut stats, meteor_e, meteor_tf, &meteor, shape, elite,
+                );
                 despawned_meteors.insert(meteor_e);
-
-                score.current += meteor_score(meteor_size);
-
-                // Screen shake – bigger for large asteroids.
-                if meteor_size == MeteorSizeType::Large {
-                    shake.trigger(4.0);
-                } else {
-                    shake.trigger(1.5);
-                }
-
-                // Explosion particles.
-                spawn_explosion(&mut commands, &game_sprites, meteor_pos, meteor_size);
+                projectile.kills += 1;
+                shot_kills.send(ShotKillEvent { kills: projectile.kills });
 
                 // Explosive Rounds: scatter shrapnel on large asteroid kills only.
                 // (Limiting to Large prevents chain-kills on split children.)
                 if upgrades.explosive_rounds && meteor_size == MeteorSizeType::Large {
-                    spawn_shrapnel(&mut commands, &game_sprites, meteor_pos.xy(), ship_type);
+                    spawn_shrapnel(&mut commands, &bolt, meteor_pos.xy());
                 }
 
                 // Bulwark: 35% chance to heal 1 HP on large asteroid kill.
@@ -125,15 +155,6 @@ fn player_projectile_hit_meteor_system(
 
                 // Activate chain reaction burst if the player has that upgrade.
                 crate::objects::projectile::trigger_chain_reaction(&mut upgrades);
-
-                // Emit a split event so fragment spawning doesn't need GameSprites here.
-                commands.spawn((
-                    MeteorSplitEvent {
-                        size:        meteor_size as i32,
-                        translation: meteor_pos,
-                    },
-                    Name::new("MeteorSplitEvent"),
-                ));
             } else {
                 // Survived – flash white.
                 commands.entity(meteor_e).insert(MeteorHitFlash(
@@ -161,21 +182,22 @@ fn player_projectile_hit_ufo_system(
     mut commands:  Commands,
     game_sprites:  Res<GameSprites>,
     mut shake:     ResMut<CameraShake>,
-    projectile_q:  Query<(Entity, &Transform, &HitBoxSize, &ProjectileComponent), Without<UfoProjectileComponent>>,
-    mut ufo_q:     Query<(Entity, &Transform, &HitBoxSize, &mut UfoComponent)>,
+    projectile_q:  Query<(Entity, &Transform, &HitBoxSize, &ProjectileComponent, Option<&PrimaryShot>), Without<UfoProjectileComponent>>,
+    mut ufo_q:     Query<(Entity, &Transform, &HitBoxSize, &mut UfoComponent, Option<&EliteComponent>)>,
     mut score:     ResMut<Score>,
+    mut stats:     ResMut<RunStats>,
 ) {
     let mut despawned_projectiles: HashSet<Entity> = HashSet::new();
     let mut despawned_ufos:        HashSet<Entity> = HashSet::new();
 
-    for (proj_e, proj_tf, proj_hit, projectile) in &projectile_q {
+    for (proj_e, proj_tf, proj_hit, projectile, primary) in &projectile_q {
         if despawned_projectiles.contains(&proj_e) {
             continue;
         }
 
         let proj_scale = proj_tf.scale.xy();
 
-        for (ufo_e, ufo_tf, ufo_hit, mut ufo) in ufo_q.iter_mut() {
+        for (ufo_e, ufo_tf, ufo_hit, mut ufo, elite) in ufo_q.iter_mut() {
             if despawned_ufos.contains(&ufo_e) || despawned_projectiles.contains(&proj_e) {
                 continue;
             }
@@ -189,20 +211,26 @@ fn player_projectile_hit_ufo_system(
                 continue;
             }
 
-            ufo.hp -= projectile.damage;
-
-            if ufo.hp <= 0 {
-                let ufo_pos = ufo_tf.translation;
-                commands.entity(ufo_e).despawn();
-                despawned_ufos.insert(ufo_e);
+            if primary.is_some() && projectile.hit_meteors.is_empty() {
+                stats.shots_hit += 1;
+            }
 
-                score.current += 150;
-                shake.trigger(3.0);
-                spawn_explosion(&mut commands, &game_sprites, ufo_pos, MeteorSizeType::Large);
-            } else {
-                commands.entity(ufo_e).insert(UfoHitFlash(
-                    Timer::from_seconds(0.15, TimerMode::Once),
-                ));
+            // Shielded elites soak bullets that hit their front.
+            let blocked = elite.is_some_and(|e| e.shield_blocks(ufo_tf.translation.xy(), proj_tf.translation.xy()));
+            if !blocked {
+                ufo.hp -= projectile.damage;
+
+                if ufo.hp <= 0 {
+                    destroy_ufo(
+                        &mut commands, &game_sprites, &mut shake, &mut score, &mut stats,
+                        ufo_e, ufo_tf.translation, ufo.ufo_type, elite,
+                    );
+                    despawned_ufos.insert(ufo_e);
+                } else {
+                    commands.entity(ufo_e).insert(UfoHitFlash(
+                        Timer::from_seconds(0.15, TimerMode::Once),
+                    ));
+                }
             }
 
             if !despawned_projectiles.contains(&proj_e) {
@@ -213,11 +241,218 @@ fn player_projectile_hit_ufo_system(
     }
 }
 
+// ── Projectile → Boss ────────────────────────────────────────────────────────
+
+fn player_projectile_hit_boss_system(
+    mut commands:  Commands,
+    game_sprites:  Res<GameSprites>,
+    mut shake:     ResMut<CameraShake>,
+    mut score:     ResMut<Score>,
+    mut stats:     ResMut<RunStats>,
+    mut level:     ResMut<LevelResource>,
+    mut selection: ResMut<UpgradeSelectionState>,
+    projectile_q:  Query<(Entity, &Transform, &HitBoxSize, &ProjectileComponent, Option<&PrimaryShot>), Without<UfoProjectileComponent>>,
+    mut boss_q:    Query<(Entity, &Transform, &HitBoxSize, &mut BossComponent)>,
+    mut weak_q:    Query<(Entity, &Transform, &HitBoxSize, &mut BossWeakPoint)>,
+) {
+    let Ok((boss_e, boss_tf, boss_hit, mut boss)) = boss_q.get_single_mut() else { return };
+    if boss.hp <= 0 {
+        return;
+    }
+
+    let mut despawned_weak: HashSet<Entity> = HashSet::new();
+
+    for (proj_e, proj_tf, proj_hit, projectile, primary) in &projectile_q {
+        let proj_size = proj_hit.0 * proj_tf.scale.xy();
+
+        // Weak points sit on top of the hull, so they take priority.
+        let weak_hit = weak_q.iter().find_map(|(weak_e, weak_tf, weak_hitbox, _)| {
+            if despawned_weak.contains(&weak_e) {
+                return None;
+            }
+            collide(
+                proj_tf.translation, proj_size,
+                weak_tf.translation, weak_hitbox.0 * weak_tf.scale.xy(),
+            )
+            .map(|_| (weak_e, weak_tf.translation))
+        });
+
+        let damage = if let Some((weak_e, weak_pos)) = weak_hit {
+            if let Ok((_, _, _, mut weak)) = weak_q.get_mut(weak_e) {
+                weak.hp -= projectile.damage;
+                if weak.hp <= 0 {
+                    commands.entity(weak_e).despawn();
+                    despawned_weak.insert(weak_e);
+                    shake.trigger(4.0);
+                    spawn_explosion(&mut commands, &game_sprites, weak_pos, MeteorSizeType::Medium);
+                }
+            }
+            projectile.damage * BOSS_WEAK_POINT_DAMAGE_MULT
+        } else if collide(
+            proj_tf.translation, proj_size,
+            boss_tf.translation, boss_hit.0 * boss_tf.scale.xy(),
+        ).is_some() {
+            projectile.damage
+        } else {
+            continue;
+        };
+
+        if primary.is_some() && projectile.hit_meteors.is_empty() {
+            stats.shots_hit += 1;
+        }
+        commands.entity(proj_e).despawn();
+        boss.hp -= damage;
+
+        if boss.hp > 0 {
+            commands.entity(boss_e).insert(BossHitFlash(Timer::from_seconds(0.12, TimerMode::Once)));
+            continue;
+        }
+
+        // Boss destroyed – tear down the hull and any remaining weak points.
+        let boss_pos = boss_tf.translation;
+        commands.entity(boss_e).despawn();
+        for (weak_e, _, _, _) in weak_q.iter() {
+            if !despawned_weak.contains(&weak_e) {
+                commands.entity(weak_e).despawn();
+            }
+        }
+
+        score.current += BOSS_SCORE;
+        stats.bosses  += 1;
+        shake.trigger(20.0);
+
+        let mut rng = thread_rng();
+        for _ in 0..6 {
+            let offset = Vec3::new(rng.gen_range(-90.0..90.0), rng.gen_range(-60.0..60.0), 0.0);
+            spawn_explosion(&mut commands, &game_sprites, boss_pos + offset, MeteorSizeType::Large);
+        }
+
+        level.boss_defeated = true;
+        selection.boss_reward = true;
+        break;
+    }
+}
+
+// ── Shared death paths ───────────────────────────────────────────────────────
+
+/// Metallic meteors shrug off half of each hit unless Heavy Rounds is owned.
+/// The fractional part is rounded probabilistically so 1-damage bullets still
+/// chip away on average.
+fn material_damage(material: MeteorMaterial, damage: i32, upgrades: &PlayerUpgrades, rng: &mut impl Rng) -> i32 {
+    if material != MeteorMaterial::Metallic || upgrades.heavy_rounds > 0 {
+        return damage;
+    }
+    let scaled = damage as f32 * METALLIC_DAMAGE_MULT;
+    let whole  = scaled.floor();
+    whole as i32 + (rng.gen::<f32>() < scaled - whole) as i32
+}
+
+/// Despawns a meteor and emits everything its death produces: score, shake,
+/// explosion, the split marker, material effects and any elite death effects.
+/// Upgrade effects tied to the killing projectile stay with the caller.
+fn destroy_meteor(
+    commands:     &mut Commands,
+    game_sprites: &GameSprites,
+    shake:        &mut CameraShake,
+    score:        &mut Score,
+    stats:        &mut RunStats,
+    meteor_e:     Entity,
+    meteor_tf:    &Transform,
+    meteor:       &MeteorComponent,
+    shape:        &MeteorShape,
+    elite:        Option<&EliteComponent>,
+) {
+    commands.entity(meteor_e).despawn();
+    let meteor_pos  = meteor_tf.translation;
+    let meteor_size = meteor.size;
+
+    let mut score_mult = if elite.is_some() { ELITE_SCORE_MULT } else { 1 };
+    if meteor.material == MeteorMaterial::Gold {
+        score_mult *= GOLD_SCORE_MULT;
+    }
+    score.current += meteor_score(meteor_size) * score_mult;
+    stats.record_meteor(meteor_size);
+
+    // Screen shake – bigger for large asteroids.
+    if meteor_size == MeteorSizeType::Large {
+        shake.trigger(4.0);
+    } else {
+        shake.trigger(1.5);
+    }
+
+    // Explosion particles.
+    spawn_explosion(commands, game_sprites, meteor_pos, meteor_size);
+
+    // Emit a split event so fragment spawning doesn't need GameSprites here.
+    let extra_fragments = if elite.is_some_and(|e| e.has(EliteAffix::Splitting)) { ELITE_EXTRA_FRAGMENTS } else { 0 };
+    commands.spawn((
+        MeteorSplitEvent {
+            size:        meteor_size as i32,
+            translation: meteor_pos,
+            rotation:    meteor_tf.rotation.to_euler(EulerRot::XYZ).2,
+            outline:     shape.outline.clone(),
+            extra_fragments,
+            material:    meteor.material,
+        },
+        Name::new("MeteorSplitEvent"),
+    ));
+
+    match meteor.material {
+        MeteorMaterial::Explosive => {
+            spawn_area_blast(commands, meteor_pos, EXPLOSIVE_METEOR_BLAST_RADIUS, EXPLOSIVE_METEOR_BLAST_DAMAGE, true);
+        }
+        MeteorMaterial::Gold => {
+            // Guaranteed drop, drifting slowly so the player can grab it.
+            let mut rng = thread_rng();
+            let (kind, tier) = roll_powerup(&mut rng);
+            let drift = Vec2::new(rng.gen_range(-0.6..0.6), rng.gen_range(-0.6..0.6));
+            spawn_powerup_at(commands, game_sprites, meteor_pos.xy(), drift, kind, tier);
+        }
+        _ => {}
+    }
+
+    if elite.is_some_and(|e| e.has(EliteAffix::Volatile)) {
+        spawn_area_blast(commands, meteor_pos, ELITE_BLAST_RADIUS, ELITE_BLAST_DAMAGE, true);
+    }
+}
+
+fn destroy_ufo(
+    commands:     &mut Commands,
+    game_sprites: &GameSprites,
+    shake:        &mut CameraShake,
+    score:        &mut Score,
+    stats:        &mut RunStats,
+    ufo_e:        Entity,
+    ufo_pos:      Vec3,
+    ufo_type:     UfoType,
+    elite:        Option<&EliteComponent>,
+) {
+    commands.entity(ufo_e).despawn();
+
+    let score_mult = if elite.is_some() { ELITE_SCORE_MULT } else { 1 };
+    score.current += 150 * score_mult;
+    stats.record_ufo(ufo_type);
+    shake.trigger(3.0);
+    spawn_explosion(commands, game_sprites, ufo_pos, MeteorSizeType::Large);
+
+    if elite.is_some_and(|e| e.has(EliteAffix::Volatile)) {
+        spawn_area_blast(commands, ufo_pos, ELITE_BLAST_RADIUS, ELITE_BLAST_DAMAGE, true);
+    }
+}
+
+pub fn spawn_area_blast(commands: &mut Commands, translation: Vec3, radius: f32, damage: i32, hurts_player: bool) {
+    commands.spawn((
+        AreaBlastEvent { translation, radius, damage, hurts_player },
+        Name::new("AreaBlastEvent"),
+    ));
+}
+
 // ── Meteor fragment spawner ───────────────────────────────────────────────────
 
 fn meteor_split_system(
     mut commands:  Commands,
     game_sprites:  Res<GameSprites>,
+    mut assets:    MeteorAssets,
     upgrades:      Res<PlayerUpgrades>,
     query:         Query<(Entity, &MeteorSplitEvent)>,
 ) {
@@ -236,14 +471,28 @@ fn meteor_split_system(
             _ => MeteorSizeType::Medium,
         };
 
-        for i in 1..=3 {
-            let rot       = rng.gen_range(-1.0_f32..1.0);
+        // Material decides how many pieces come off and what they're made of.
+        let (count, child_material, speed_mult) = meteor_material_fragments(event.material);
+
+        let total = count + event.extra_fragments;
+        if total == 0 {
+            continue;
+        }
+
+        // Carve the parent's outline into wedges so the pieces match its silhouette.
+        let pieces   = split_outline(&event.outline, total, rng.gen_range(0.0..TAU));
+        let parent_q = Quat::from_rotation_z(event.rotation);
+
+        for (i, (outline, offset)) in (1_u32..).zip(pieces) {
             let rot_speed = rng.gen_range(-0.05_f32..0.05);
-            let speed_val = i as f32 * 0.8;
-            let mut vel   = Vec2::new(
+            let speed_val = i.min(3) as f32 * 0.8 * speed_mult;
+            let offset    = (parent_q * offset.extend(0.0)).truncate();
+
+            // Fragments fly outward from the parent's centre, with a little scatter.
+            let mut vel = offset.normalize_or_zero() * speed_val + Vec2::new(
                 rng.gen_range(-speed_val..speed_val),
                 rng.gen_range(-speed_val..speed_val),
-            );
+            ) * 0.5;
 
             if upgrades.overclock {
                 vel *= lib::OVERCLOCK_SPEED_MULT;
@@ -252,55 +501,132 @@ fn meteor_split_system(
             spawn_meteor(
                 &mut commands,
                 &game_sprites,
-                child_size,
-                event.translation,
-                Vec2::new(event.translation.x, event.translation.y),
-                rot,
-                rot_speed,
-                vel,
-                1, // fragment children always have 1 HP
+                &mut assets,
+                MeteorSpawn {
+                    size:           child_size,
+                    material:       child_material,
+                    position:       event.translation.xy() + offset,
+                    rotation:       event.rotation,
+                    rotation_speed: rot_speed,
+                    velocity:       vel,
+                    hp:             1, // fragment children always have 1 HP
+                    outline:        Some(outline),
+                },
             );
         }
     }
 }
 
+// ── Area blasts ──────────────────────────────────────────────────────────────
+
+/// Resolves `AreaBlastEvent` markers: damages the player and every meteor or
+/// UFO inside the radius. Kills go through the shared death paths, so a
+/// Volatile elite caught in a blast chains into its own blast next frame.
+fn area_blast_system(
+    mut commands:  Commands,
+    game_sprites:  Res<GameSprites>,
+    mut shake:     ResMut<CameraShake>,
+    mut score:     ResMut<Score>,
+    mut stats:     ResMut<RunStats>,
+    blast_q:       Query<(Entity, &AreaBlastEvent)>,
+    player_q:      Query<&Transform, (With<PlayerComponent>, Without<InHyperspace>)>,
+    mut meteor_q:  Query<(Entity, &Transform, &mut MeteorComponent, &MeteorShape, Option<&EliteComponent>)>,
+    mut ufo_q:     Query<(Entity, &Transform, &mut UfoComponent, Option<&EliteComponent>)>,
+) {
+    let mut destroyed: HashSet<Entity> = HashSet::new();
+
+    for (blast_e, blast) in &blast_q {
+        commands.entity(blast_e).despawn();
+
+        let center = blast.translation.xy();
+        shake.trigger(6.0);
+        spawn_explosion(&mut commands, &game_sprites, blast.translation, MeteorSizeType::Large);
+
+        if blast.hurts_player {
+            for player_tf in &player_q {
+                if player_tf.translation.xy().distance(center) <= blast.radius {
+                    commands.spawn((
+                        DamageCollision(blast.damage, DamageSource::Blast),
+                        Name::new("BlastDamage"),
+                    ));
+                }
+            }
+        }
+
+        for (meteor_e, meteor_tf, mut meteor, shape, elite) in meteor_q.iter_mut() {
+            if destroyed.contains(&meteor_e) || meteor_tf.translation.xy().distance(center) > blast.radius {
+                continue;
+            }
+            meteor.health -= blast.damage;
+            if meteor.health <= 0 {
+                destroy_meteor(
+                    &mut commands, &game_sprites, &mut shake, &mut score, &mut stats, meteor_e, meteor_tf, &meteor, shape, elite,
+                );
+                destroyed.insert(meteor_e);
+            } else {
+                commands.entity(meteor_e).insert(MeteorHitFlash(Timer::from_seconds(0.15, TimerMode::Once)));
+            }
+        }
+
+        for (ufo_e, ufo_tf, mut ufo, elite) in ufo_q.iter_mut() {
+            if destroyed.contains(&ufo_e) || ufo_tf.translation.xy().distance(center) > blast.radius {
+                continue;
+            }
+            ufo.hp -= blast.damage;
+            if ufo.hp <= 0 {
+                destroy_ufo(
+                    &mut commands, &game_sprites, &mut shake, &mut score, &mut stats,
+                    ufo_e, ufo_tf.translation, ufo.ufo_type, elite,
+                );
+                destroyed.insert(ufo_e);
+            } else {
+                commands.entity(ufo_e).insert(UfoHitFlash(Timer::from_seconds(0.15, TimerMode::Once)));
+            }
+        }
+    }
+}
+
 // ── Player ← Meteor ───────────────────────────────────────────────────────────
 
 fn player_hit_by_meteor_system(
     mut commands:  Commands,
     mut shake:     ResMut<CameraShake>,
-    player_q:      Query<(Entity, &Transform, &HitBoxSize), With<PlayerComponent>>,
-    meteor_q:      Query<(Entity, &Transform, &HitBoxSize, &CollisionDespawnableWithDamage), With<MeteorComponent>>,
+    mut buff:      ResMut<PlayerBuff>,
+    player_q:      Query<(&Transform, &HitBoxSize), (With<PlayerComponent>, Without<InHyperspace>)>,
+    // Held and thrown rocks are the player's own.
+    meteor_q:      Query<(Entity, &Transform, &ConvexCollider, &CollisionDespawnableWithDamage, &MeteorComponent), (Without<Held>, Without<Thrown>)>,
 ) {
     let mut despawned: HashSet<Entity> = HashSet::new();
 
-    for (player_e, player_tf, player_hit) in &player_q {
+    for (player_tf, player_hit) in &player_q {
         let player_scale = player_tf.scale.xy();
 
-        for (meteor_e, meteor_tf, meteor_hit, damageable) in &meteor_q {
+        for (meteor_e, meteor_tf, collider, damageable, meteor) in &meteor_q {
             if despawned.contains(&meteor_e) {
                 continue;
             }
 
-            let meteor_scale = meteor_tf.scale.xy();
-
-            let hit = collide(
-                player_tf.translation, player_hit.0 * player_scale,
-                meteor_tf.translation, meteor_hit.0 * meteor_scale,
-            );
-
-            if hit.is_none() {
+            if !collider.overlaps_rect(meteor_tf, player_tf, player_hit.0 * player_scale) {
                 continue;
             }
 
             commands.entity(meteor_e).despawn();
             despawned.insert(meteor_e);
 
+            match meteor.material {
+                MeteorMaterial::Ice => buff.slow_timer = ICE_SLOW_DURATION,
+                // The contact hit already hurt the player; the blast only catches neighbours.
+                MeteorMaterial::Explosive => spawn_area_blast(
+                    &mut commands, meteor_tf.translation,
+                    EXPLOSIVE_METEOR_BLAST_RADIUS, EXPLOSIVE_METEOR_BLAST_DAMAGE, false,
+                ),
+                _ => {}
+            }
+
             if damageable.should_damage {
                 shake.trigger(8.0);
-                commands.entity(player_e).insert(PlayerDamageFlash::new());
                 commands.spawn((
-                    DamageCollision(damageable.damage),
+                    DamageCollision(damageable.damage, DamageSource::Meteor),
                     Name::new("ContactDamage"),
                 ));
             }
@@ -313,12 +639,12 @@ fn player_hit_by_meteor_system(
 fn player_hit_by_ufo_projectile_system(
     mut commands: Commands,
     mut shake:    ResMut<CameraShake>,
-    player_q:     Query<(Entity, &Transform, &HitBoxSize), With<PlayerComponent>>,
+    player_q:     Query<(&Transform, &HitBoxSize), (With<PlayerComponent>, Without<InHyperspace>)>,
     proj_q:       Query<(Entity, &Transform, &HitBoxSize), With<UfoProjectileComponent>>,
 ) {
     let mut despawned: HashSet<Entity> = HashSet::new();
 
-    for (player_e, player_tf, player_hit) in &player_q {
+    for (player_tf, player_hit) in &player_q {
         let player_scale = player_tf.scale.xy();
 
         for (proj_e, proj_tf, proj_hit) in &proj_q {
@@ -339,33 +665,77 @@ fn player_hit_by_ufo_projectile_system(
             despawned.insert(proj_e);
 
             shake.trigger(6.0);
-            commands.entity(player_e).insert(PlayerDamageFlash::new());
             commands.spawn((
-                DamageCollision(1),
+                DamageCollision(1, DamageSource::UfoShot),
                 Name::new("UfoProjectileDamage"),
             ));
         }
     }
 }
 
+// ── Player ← Boss hull ───────────────────────────────────────────────────────
+
+/// Ramming the boss hurts and knocks the ship back out of the hull.
+fn player_hit_by_boss_system(
+    mut commands: Commands,
+    mut shake:    ResMut<CameraShake>,
+    boss_q:       Query<(&Transform, &HitBoxSize), With<BossComponent>>,
+    mut player_q: Query<(&Transform, &HitBoxSize, &mut Position, &mut Velocity), (With<PlayerComponent>, Without<InHyperspace>)>,
+) {
+    let Ok((boss_tf, boss_hit)) = boss_q.get_single() else { return };
+
+    for (player_tf, player_hit, mut pos, mut vel) in &mut player_q {
+        let hit = collide(
+            player_tf.translation, player_hit.0 * player_tf.scale.xy(),
+            boss_tf.translation,   boss_hit.0 * boss_tf.scale.xy(),
+        );
+
+        if hit.is_none() {
+            continue;
+        }
+
+        let away = (pos.0 - boss_tf.translation.xy()).normalize_or_zero();
+        let away = if away == Vec2::ZERO { Vec2::NEG_Y } else { away };
+        pos.0 += away * 24.0;
+        vel.0  = away * PLAYER_MAX_SPEED * 1.5;
+
+        shake.trigger(8.0);
+        commands.spawn((
+            DamageCollision(BOSS_CONTACT_DAMAGE, DamageSource::Boss),
+            Name::new("BossContactDamage"),
+        ));
+    }
+}
+
 // ── Damage application ────────────────────────────────────────────────────────
 
+/// Applies queued damage. Each hit that lands opens a post-hit invulnerability
+/// window, so overlapping fragments from one split only cost a single hit.
 fn apply_damage_system(
     mut commands:  Commands,
     damage_q:      Query<(Entity, &DamageCollision)>,
+    player_q:      Query<Entity, With<PlayerComponent>>,
     mut ev_dead:   EventWriter<PlayerDeadEvent>,
     mut life:      ResMut<Life>,
-    buff:          Res<PlayerBuff>,
+    mut buff:      ResMut<PlayerBuff>,
+    mut stats:     ResMut<RunStats>,
+    settings:      Res<GameSettings>,
+    upgrades:      Res<PlayerUpgrades>,
 ) {
     for (entity, damage) in &damage_q {
         commands.entity(entity).despawn();
 
-        // Shield buff grants full invincibility.
-        if buff.shield_timer > 0.0 {
+        // Shield buff, dash and post-hit i-frames grant full invincibility.
+        if buff.shield_timer > 0.0 || buff.dash_timer > 0.0 || buff.invuln_timer > 0.0 {
             continue;
         }
 
+        stats.record_damage(damage.1, damage.0.min(life.current_life));
         life.current_life = (life.current_life - damage.0).max(0);
+        buff.invuln_timer = upgrades.hit_invuln_window(settings.hit_invuln);
+        if let Ok(player_e) = player_q.get_single() {
+            commands.entity(player_e).insert(PlayerDamageFlash::new());
+        }
 
         if life.current_life == 0 {
             ev_dead.send(PlayerDeadEvent);
@@ -382,6 +752,8 @@ fn player_collect_powerup_system(
     powerup_q:     Query<(Entity, &Transform, &HitBoxSize, &PowerUpComponent), With<PowerUpComponent>>,
     mut life:      ResMut<Life>,
     mut buff:      ResMut<PlayerBuff>,
+    mut ammo:      ResMut<SecondaryAmmo>,
+    mut stats:     ResMut<RunStats>,
 ) {
     let mut collected: HashSet<Entity> = HashSet::new();
 
@@ -405,7 +777,8 @@ fn player_collect_powerup_system(
             collected.insert(powerup_e);
             commands.entity(powerup_e).despawn();
 
-            powerup.apply(&mut life, &mut buff);
+            powerup.apply(&mut life, &mut buff, &mut ammo);
+            stats.record_powerup(powerup.kind);
         }
     }
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use lib::MeteorMaterial;
 
 #[derive(Component)]
 pub struct HitBoxSize(pub Vec2);
@@ -20,6 +21,11 @@ pub struct BoundsWarpable;
 #[derive(Component)]
 pub struct BoundsDespawnable(pub Vec2);
 
+/// Entity is being carried by something else (a meteor in the tractor beam).
+/// Velocity integration and bounds despawn skip it.
+#[derive(Component)]
+pub struct Held;
+
 /// Entity despawns on collision with the player and optionally deals damage.
 #[derive(Component)]
 pub struct CollisionDespawnableWithDamage {
@@ -35,12 +41,40 @@ impl CollisionDespawnableWithDamage {
 
 /// Temporary marker used to carry damage into the collision processing system.
 #[derive(Component)]
-pub struct DamageCollision(pub i32);
+pub struct DamageCollision(pub i32, pub DamageSource);
+
+/// What dealt a `DamageCollision`, for the run stats.
+#[derive(Clone, Copy, PartialEq)]
+pub enum DamageSource {
+    Meteor,
+    UfoShot,
+    Boss,
+    Blast,
+    Hyperspace,
+}
 
 /// Temporary marker emitted when a meteor loses its last HP.
 /// Carries the position for spawning split fragments.
 #[derive(Component)]
 pub struct MeteorSplitEvent {
-    pub size:        i32,   // parent size ordinal; children are (size - 1)
-    pub translation: Vec3,
+    pub size:            i32,   // parent size ordinal; children are (size - 1)
+    pub translation:     Vec3,
+    /// Parent rotation (radians) and jagged outline; fragments are wedges cut
+    /// from the outline so they look like pieces of the parent.
+    pub rotation:        f32,
+    pub outline:         Vec<Vec2>,
+    /// Extra fragments on top of the material's usual count (Splitting elites).
+    pub extra_fragments: u32,
+    /// Parent material; decides fragment count and child material.
+    pub material:        MeteorMaterial,
+}
+
+/// Temporary marker for an explosion that damages everything in `radius`.
+/// Emitted by Volatile elites on death, proximity mines and the nuke.
+#[derive(Component)]
+pub struct AreaBlastEvent {
+    pub translation:  Vec3,
+    pub radius:       f32,
+    pub damage:       i32,
+    pub hurts_player: bool,
 }
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use crate::{
-    common::common_components::{Position, RotationAngle, Velocity, BoundsDespawnable, BoundsWarpable},
+    common::common_components::{Position, RotationAngle, Velocity, BoundsDespawnable, BoundsWarpable, Held},
     resources::{WindowSize, WindowDespawnBorder},
 };
 
@@ -27,7 +27,7 @@ pub fn warp_if_reached_window_bounds_system(
 
 pub fn despawn_if_reached_bounds_system(
     mut commands: Commands,
-    query:        Query<(Entity, &Velocity, &Position, &BoundsDespawnable)>,
+    query:        Query<(Entity, &Velocity, &Position, &BoundsDespawnable), Without<Held>>,
     border:       Res<WindowDespawnBorder>,
 ) {
     for (entity, vel, pos, despawnable) in &query {
@@ -45,7 +45,7 @@ pub fn despawn_if_reached_bounds_system(
     }
 }
 
-pub fn movement_system(mut query: Query<(&Velocity, &mut Position)>) {
+pub fn movement_system(mut query: Query<(&Velocity, &mut Position), Without<Held>>) {
     for (vel, mut pos) in &mut query {
         pos.0 += vel.0;
     }
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+// ── Component ─────────────────────────────────────────────────────────────────
+
+/// Convex collision hull in local space (counter-clockwise). Entities with a
+/// hull are tested with SAT instead of the `HitBoxSize` AABB.
+#[derive(Component, Clone)]
+pub struct ConvexCollider {
+    pub hull: Vec<Vec2>,
+}
+
+impl ConvexCollider {
+    pub fn new(hull: Vec<Vec2>) -> Self {
+        ConvexCollider { hull }
+    }
+
+    /// Hull transformed into world space.
+    pub fn world_points(&self, tf: &Transform) -> Vec<Vec2> {
+        self.hull.iter().map(|p| tf.transform_point(p.extend(0.0)).truncate()).collect()
+    }
+
+    /// True when the hull overlaps a (possibly rotated) rectangle, e.g. a
+    /// bullet or the player's hit-box.
+    pub fn overlaps_rect(&self, tf: &Transform, rect_tf: &Transform, rect_size: Vec2) -> bool {
+        sat_penetration(&self.world_points(tf), &rect_points(rect_tf, rect_size)).is_some()
+    }
+}
+
+// ── Geometry helpers ──────────────────────────────────────────────────────────
+
+/// Corners of an `size`-sized rectangle centred on `tf` and rotated with it.
+pub fn rect_points(tf: &Transform, size: Vec2) -> [Vec2; 4] {
+    let half = size * 0.5;
+    let center = tf.translation.truncate();
+    let rot = tf.rotation;
+    [
+        Vec2::new(-half.x, -half.y),
+        Vec2::new( half.x, -half.y),
+        Vec2::new( half.x,  half.y),
+        Vec2::new(-half.x,  half.y),
+    ]
+    .map(|c| center + (rot * c.extend(0.0)).truncate())
+}
+
+/// Separating-axis test between two convex polygons in world space.
+/// Returns the minimum translation axis (pointing from `a` towards `b`) and
+/// the penetration depth, or `None` if they don't overlap.
+pub fn sat_penetration(a: &[Vec2], b: &[Vec2]) -> Option<(Vec2, f32)> {
+    let mut best_axis  = Vec2::ZERO;
+    let mut best_depth = f32::MAX;
+
+    for poly in [a, b] {
+        for i in 0..poly.len() {
+            let edge = poly[(i + 1) % poly.len()] - poly[i];
+            let axis = edge.perp().normalize_or_zero();
+            if axis == Vec2::ZERO {
+                continue;
+            }
+
+            let (a_min, a_max) = project(a, axis);
+            let (b_min, b_max) = project(b, axis);
+            let depth = a_max.min(b_max) - a_min.max(b_min);
+            if depth <= 0.0 {
+                return None;
+            }
+            if depth < best_depth {
+                best_depth = depth;
+                best_axis  = axis;
+            }
+        }
+    }
+
+    if best_axis.dot(centroid(b) - centroid(a)) < 0.0 {
+        best_axis = -best_axis;
+    }
+    Some((best_axis, best_depth))
+}
+
+fn project(points: &[Vec2], axis: Vec2) -> (f32, f32) {
+    points.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+        let d = p.dot(axis);
+        (lo.min(d), hi.max(d))
+    })
+}
+
+fn centroid(points: &[Vec2]) -> Vec2 {
+    points.iter().copied().sum::<Vec2>() / points.len().max(1) as f32
+}
+
+/// Convex hull (Andrew's monotone chain), counter-clockwise.
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut pts = points.to_vec();
+    pts.sort_by(|p, q| p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y)));
+    if pts.len() < 3 {
+        return pts;
+    }
+
+    let cross = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+    let mut hull: Vec<Vec2> = Vec::with_capacity(pts.len() * 2);
+
+    for &p in pts.iter() {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let lower_len = hull.len() + 1;
+    for &p in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull.pop();
+    hull
+}
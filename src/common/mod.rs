@@ -1,3 +1,5 @@
 pub mod common_components;
 pub mod common_systems;
-pub mod collision;
\ No newline at end of file
+pub mod collision;
+pub mod physics;
+pub mod convex;
\ No newline at end of file
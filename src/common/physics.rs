@@ -0,0 +1,125 @@
+use bevy::{prelude::*, math::Vec3Swizzles};
+
+use lib::{meteor_damage, meteor_mass, MeteorSizeType, UFO_IMPACT_COOLDOWN};
+use crate::{
+    common::{
+        common_components::{HitBoxSize, Position, Velocity},
+        convex::{sat_penetration, ConvexCollider},
+    },
+    effects::particle::spawn_explosion,
+    objects::{
+        meteor::MeteorComponent,
+        ufo::{UfoComponent, UfoHitFlash},
+    },
+    resources::{CameraShake, GameSettings, GameSprites, IsPaused},
+    state::states::GameStates,
+};
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// Keeps a UFO from taking damage every frame while it is still overlapping
+/// the rock that just hit it.
+#[derive(Component)]
+pub struct UfoImpactCooldown(pub Timer);
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Optional meteor physics, enabled by `GameSettings::meteor_physics`.
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                meteor_meteor_collision_system,
+                meteor_ufo_collision_system,
+                ufo_impact_cooldown_system,
+            )
+                .run_if(in_state(GameStates::InGame))
+                .run_if(|p: Res<IsPaused>| !p.0)
+                .run_if(|s: Res<GameSettings>| s.meteor_physics),
+        );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// Elastic response between every overlapping pair of meteors, using the
+/// separating axis of their convex hulls as the contact normal.
+fn meteor_meteor_collision_system(
+    mut query: Query<(&MeteorComponent, &ConvexCollider, &Transform, &mut Position, &mut Velocity)>,
+) {
+    let mut pairs = query.iter_combinations_mut();
+    while let Some([(a, a_col, a_tf, mut a_pos, mut a_vel), (b, b_col, b_tf, mut b_pos, mut b_vel)]) = pairs.fetch_next() {
+        let Some((normal, overlap)) = sat_penetration(&a_col.world_points(a_tf), &b_col.world_points(b_tf)) else {
+            continue;
+        };
+
+        let (ma, mb)  = (meteor_mass(a.size), meteor_mass(b.size));
+        let inv_total = 1.0 / (ma + mb);
+
+        // Separate the pair so they don't stay interlocked; the heavier rock moves less.
+        a_pos.0 -= normal * overlap * mb * inv_total;
+        b_pos.0 += normal * overlap * ma * inv_total;
+
+        // Exchange momentum only while they are still closing.
+        let closing = (a_vel.0 - b_vel.0).dot(normal);
+        if closing <= 0.0 {
+            continue;
+        }
+        a_vel.0 -= normal * 2.0 * closing * mb * inv_total;
+        b_vel.0 += normal * 2.0 * closing * ma * inv_total;
+    }
+}
+
+/// Meteors that fly into a UFO damage it and bounce off. Kills here are
+/// environmental, so they award no score.
+fn meteor_ufo_collision_system(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    mut shake:    ResMut<CameraShake>,
+    mut ufo_q:    Query<(Entity, &Transform, &HitBoxSize, &mut UfoComponent), Without<UfoImpactCooldown>>,
+    mut meteor_q: Query<(&MeteorComponent, &Transform, &ConvexCollider, &Position, &mut Velocity)>,
+) {
+    for (ufo_e, ufo_tf, ufo_hit, mut ufo) in &mut ufo_q {
+        for (meteor, meteor_tf, collider, meteor_pos, mut meteor_vel) in &mut meteor_q {
+            if !collider.overlaps_rect(meteor_tf, ufo_tf, ufo_hit.0 * ufo_tf.scale.xy()) {
+                continue;
+            }
+
+            // Reflect the meteor away from the UFO.
+            let normal = (meteor_pos.0 - ufo_tf.translation.xy()).normalize_or_zero();
+            let into   = meteor_vel.0.dot(normal);
+            if into < 0.0 {
+                meteor_vel.0 -= normal * 2.0 * into;
+            }
+
+            ufo.hp -= meteor_damage(meteor.size);
+            if ufo.hp <= 0 {
+                commands.entity(ufo_e).despawn();
+                shake.trigger(3.0);
+                spawn_explosion(&mut commands, &game_sprites, ufo_tf.translation, MeteorSizeType::Large);
+            } else {
+                commands.entity(ufo_e).insert((
+                    UfoHitFlash(Timer::from_seconds(0.15, TimerMode::Once)),
+                    UfoImpactCooldown(Timer::from_seconds(UFO_IMPACT_COOLDOWN, TimerMode::Once)),
+                ));
+            }
+            break;
+        }
+    }
+}
+
+fn ufo_impact_cooldown_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    mut query:    Query<(Entity, &mut UfoImpactCooldown)>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0.tick(time.delta());
+        if cooldown.0.finished() {
+            commands.entity(entity).remove::<UfoImpactCooldown>();
+        }
+    }
+}
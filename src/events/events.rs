@@ -12,6 +12,13 @@ pub struct PlayerDeadEvent;
 #[derive(Event)]
 pub struct PlayerSpawnEvent;
 
+/// A player projectile destroyed a meteor; `kills` counts every meteor it has
+/// destroyed so far.
+#[derive(Event)]
+pub struct ShotKillEvent {
+    pub kills: u32,
+}
+
 pub struct EventsPlugin;
 
 impl Plugin for EventsPlugin {
@@ -19,6 +26,7 @@ impl Plugin for EventsPlugin {
         app
             .add_event::<PlayerDeadEvent>()
             .add_event::<PlayerSpawnEvent>()
+            .add_event::<ShotKillEvent>()
             .add_systems(
                 Update,
                 (
@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::gamepad::{PadInput, BACK_BUTTON};
+use crate::{
+    resources::{IsPaused, UpgradeSelectionState},
+    state::states::GameStates,
+    utils::storage,
+};
+
+/// Opens the rebinding screen from the pause menu. Not rebindable, so the
+/// screen can always be reached again after a bad binding.
+pub const REBIND_KEY: KeyCode = KeyCode::Tab;
+/// Keys each action can hold at once.
+pub const MAX_BINDINGS: usize = 2;
+
+const BINDINGS_FILE: &str = "bindings.cfg";
+
+/// Keys that can be bound. Also the lookup table for reading the bindings file.
+const BINDABLE_KEYS: [KeyCode; 56] = [
+    KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F, KeyCode::G,
+    KeyCode::H, KeyCode::I, KeyCode::J, KeyCode::K, KeyCode::L, KeyCode::M, KeyCode::N,
+    KeyCode::O, KeyCode::P, KeyCode::Q, KeyCode::R, KeyCode::S, KeyCode::T, KeyCode::U,
+    KeyCode::V, KeyCode::W, KeyCode::X, KeyCode::Y, KeyCode::Z,
+    KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5,
+    KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9, KeyCode::Key0,
+    KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right,
+    KeyCode::Space, KeyCode::Return, KeyCode::Escape, KeyCode::Back,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight,
+    KeyCode::Comma, KeyCode::Period, KeyCode::Slash, KeyCode::Semicolon, KeyCode::Minus, KeyCode::Equals,
+];
+
+// ── Actions ───────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Action {
+    Thrust,
+    TurnLeft,
+    TurnRight,
+    /// Up/Down in menus, and movement along the screen's Y axis in twin stick.
+    Up,
+    Down,
+    Fire,
+    Pause,
+    Confirm,
+    Back,
+    Ability,
+    Secondary,
+    Tractor,
+    Hyperspace,
+    /// Resumes the saved run from the ship select screen.
+    Continue,
+    /// Opens the hangar from the ship select screen.
+    Hangar,
+    /// Spends a free reroll on the upgrade offer.
+    Reroll,
+    /// Opens the achievements screen from the ship select screen.
+    Achievements,
+}
+
+impl Action {
+    pub const ALL: [Action; 17] = [
+        Action::Thrust,
+        Action::TurnLeft,
+        Action::TurnRight,
+        Action::Up,
+        Action::Down,
+        Action::Fire,
+        Action::Pause,
+        Action::Confirm,
+        Action::Back,
+        Action::Ability,
+        Action::Secondary,
+        Action::Tractor,
+        Action::Hyperspace,
+        Action::Continue,
+        Action::Hangar,
+        Action::Reroll,
+        Action::Achievements,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::Thrust       => "THRUST",
+            Action::TurnLeft     => "TURN LEFT",
+            Action::TurnRight    => "TURN RIGHT",
+            Action::Fire         => "FIRE",
+            Action::Pause        => "PAUSE",
+            Action::Confirm      => "CONFIRM",
+            Action::Back         => "BACK",
+            Action::Ability      => "ABILITY",
+            Action::Secondary    => "SECONDARY",
+            Action::Tractor      => "TRACTOR BEAM",
+            Action::Hyperspace   => "HYPERSPACE",
+            Action::Up           => "UP",
+            Action::Down         => "DOWN",
+            Action::Continue     => "CONTINUE",
+            Action::Hangar       => "HANGAR",
+            Action::Reroll       => "REROLL",
+            Action::Achievements => "ACHIEVEMENTS",
+        }
+    }
+
+    /// Name used in the bindings file and in `{...}` prompt placeholders.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Action::Thrust       => "Thrust",
+            Action::TurnLeft     => "TurnLeft",
+            Action::TurnRight    => "TurnRight",
+            Action::Fire         => "Fire",
+            Action::Pause        => "Pause",
+            Action::Confirm      => "Confirm",
+            Action::Back         => "Back",
+            Action::Ability      => "Ability",
+            Action::Secondary    => "Secondary",
+            Action::Tractor      => "Tractor",
+            Action::Hyperspace   => "Hyperspace",
+            Action::Up           => "Up",
+            Action::Down         => "Down",
+            Action::Continue     => "Continue",
+            Action::Hangar       => "Hangar",
+            Action::Reroll       => "Reroll",
+            Action::Achievements => "Achievements",
+        }
+    }
+
+    /// Turn keys double as Left/Right in menus and twin-stick movement, hence
+    /// the A/D alternates; Up/Down take W/S the same way.
+    fn default_keys(&self) -> &'static [KeyCode] {
+        match self {
+            Action::Thrust       => &[KeyCode::Up],
+            Action::TurnLeft     => &[KeyCode::Left, KeyCode::A],
+            Action::TurnRight    => &[KeyCode::Right, KeyCode::D],
+            Action::Fire         => &[KeyCode::Space],
+            Action::Pause        => &[KeyCode::Escape],
+            Action::Confirm      => &[KeyCode::Space, KeyCode::Return],
+            Action::Back         => &[KeyCode::R],
+            Action::Ability      => &[KeyCode::E],
+            Action::Secondary    => &[KeyCode::Q],
+            Action::Tractor      => &[KeyCode::F],
+            Action::Hyperspace   => &[KeyCode::Down],
+            Action::Up           => &[KeyCode::Up, KeyCode::W],
+            Action::Down         => &[KeyCode::Down, KeyCode::S],
+            Action::Continue     => &[KeyCode::C],
+            Action::Hangar       => &[KeyCode::H],
+            Action::Reroll       => &[KeyCode::X],
+            Action::Achievements => &[KeyCode::T],
+        }
+    }
+}
+
+/// Short on-screen name for a key.
+pub fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Return => "ENTER".to_string(),
+        KeyCode::Escape => "ESC".to_string(),
+        KeyCode::Back   => "BACKSPACE".to_string(),
+        _ => {
+            let name = format!("{key:?}");
+            name.strip_prefix("Key").unwrap_or(&name).to_uppercase()
+        }
+    }
+}
+
+// ── Action map ────────────────────────────────────────────────────────────────
+
+/// Keyboard bindings for every `Action`, up to `MAX_BINDINGS` keys each.
+/// Loaded at startup and saved after every change.
+#[derive(Resource, Clone)]
+pub struct ActionMap {
+    bindings: HashMap<Action, Vec<KeyCode>>,
+}
+
+impl Default for ActionMap {
+    fn default() -> Self {
+        ActionMap {
+            bindings: Action::ALL.iter().map(|a| (*a, a.default_keys().to_vec())).collect(),
+        }
+    }
+}
+
+impl ActionMap {
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.bindings.get(&action).map_or(&[], |keys| keys.as_slice())
+    }
+
+    /// Puts `key` in `slot`, appending if the slot is empty. A key the action
+    /// already has just swaps into `slot`.
+    pub fn bind(&mut self, action: Action, slot: usize, key: KeyCode) {
+        let keys = self.bindings.entry(action).or_default();
+        if let Some(existing) = keys.iter().position(|k| *k == key) {
+            if slot < keys.len() {
+                keys.swap(existing, slot);
+            }
+            return;
+        }
+        if slot < keys.len() {
+            keys[slot] = key;
+        } else {
+            keys.push(key);
+        }
+    }
+
+    /// Removes the key in `slot`. The last key of an action can't be removed,
+    /// so nothing ends up unreachable.
+    pub fn unbind(&mut self, action: Action, slot: usize) {
+        let keys = self.bindings.entry(action).or_default();
+        if keys.len() > 1 && slot < keys.len() {
+            keys.remove(slot);
+        }
+    }
+
+    /// Every key bound to `action`, e.g. `SPACE/ENTER`.
+    pub fn describe(&self, action: Action) -> String {
+        self.keys(action).iter().map(|k| key_name(*k)).collect::<Vec<_>>().join("/")
+    }
+
+    /// Replaces `{Action}` placeholders with the keys currently bound.
+    pub fn fill(&self, text: &str) -> String {
+        Action::ALL.iter().fold(text.to_string(), |text, action| {
+            text.replace(&format!("{{{}}}", action.id()), &self.describe(*action))
+        })
+    }
+
+    /// Saved bindings on top of the defaults; unknown lines are skipped.
+    pub fn load() -> Self {
+        let mut map = ActionMap::default();
+        let Some(text) = storage::load(BINDINGS_FILE) else { return map };
+
+        for line in text.lines() {
+            let Some((id, keys)) = line.split_once('=') else { continue };
+            let Some(action) = Action::ALL.iter().find(|a| a.id() == id.trim()) else { continue };
+            let keys: Vec<KeyCode> = keys
+                .split(',')
+                .filter_map(|name| BINDABLE_KEYS.iter().find(|k| format!("{k:?}") == name.trim()).copied())
+                .take(MAX_BINDINGS)
+                .collect();
+            if !keys.is_empty() {
+                map.bindings.insert(*action, keys);
+            }
+        }
+        map
+    }
+
+    pub fn save(&self) {
+        let text: String = Action::ALL
+            .iter()
+            .map(|a| {
+                let keys: Vec<String> = self.keys(*a).iter().map(|k| format!("{k:?}")).collect();
+                format!("{}={}\n", a.id(), keys.join(","))
+            })
+            .collect();
+        storage::save(BINDINGS_FILE, &text);
+    }
+}
+
+/// Keyboard input read through the `ActionMap`.
+#[derive(SystemParam)]
+pub struct Actions<'w> {
+    keyboard: Res<'w, Input<KeyCode>>,
+    map:      Res<'w, ActionMap>,
+}
+
+impl Actions<'_> {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.keyboard.any_pressed(self.map.keys(action).iter().copied())
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.keyboard.any_just_pressed(self.map.keys(action).iter().copied())
+    }
+}
+
+// ── Rebinding screen ──────────────────────────────────────────────────────────
+
+/// State of the rebinding screen opened from the pause menu. Its own keys are
+/// fixed (arrows, Enter, Delete, Esc) so a bad binding can't lock it.
+#[derive(Resource, Default)]
+pub struct RebindState {
+    pub open:      bool,
+    /// One row per action, then "reset to defaults".
+    pub row:       usize,
+    /// Which of the focused action's keys Left/Right points at.
+    pub slot:      usize,
+    /// Waiting for the next key press to bind.
+    pub listening: bool,
+}
+
+impl RebindState {
+    pub fn rows() -> usize {
+        Action::ALL.len() + 1
+    }
+
+    /// The action on the focused row, or `None` on the reset row.
+    pub fn action(&self) -> Option<Action> {
+        Action::ALL.get(self.row).copied()
+    }
+}
+
+pub struct ActionMapPlugin;
+
+impl Plugin for ActionMapPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RebindState>()
+            .add_systems(
+                Update,
+                rebind_input_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| p.0)
+                    .run_if(|o: Res<UpgradeSelectionState>| !o.mid_run),
+            )
+            .add_systems(OnExit(GameStates::InGame), close_rebind);
+    }
+}
+
+pub fn rebind_input_system(
+    kb:         Res<Input<KeyCode>>,
+    pad:        PadInput,
+    mut map:    ResMut<ActionMap>,
+    mut rebind: ResMut<RebindState>,
+) {
+    if !rebind.open {
+        if kb.just_pressed(REBIND_KEY) {
+            *rebind = RebindState { open: true, ..default() };
+        }
+        return;
+    }
+
+    if rebind.listening {
+        let Some(key) = kb.get_just_pressed().next().copied() else { return };
+        rebind.listening = false;
+        if key != KeyCode::Escape && BINDABLE_KEYS.contains(&key) {
+            if let Some(action) = rebind.action() {
+                map.bind(action, rebind.slot, key);
+                map.save();
+            }
+        }
+        return;
+    }
+
+    if kb.just_pressed(KeyCode::Escape) || kb.just_pressed(REBIND_KEY) || pad.just_pressed(BACK_BUTTON) {
+        rebind.open = false;
+        return;
+    }
+
+    let rows = RebindState::rows();
+    if kb.just_pressed(KeyCode::Up) || pad.just_pressed(GamepadButtonType::DPadUp) {
+        rebind.row  = (rebind.row + rows - 1) % rows;
+        rebind.slot = 0;
+    }
+    if kb.just_pressed(KeyCode::Down) || pad.just_pressed(GamepadButtonType::DPadDown) {
+        rebind.row  = (rebind.row + 1) % rows;
+        rebind.slot = 0;
+    }
+    if kb.just_pressed(KeyCode::Left) || pad.just_pressed(GamepadButtonType::DPadLeft) {
+        rebind.slot = rebind.slot.saturating_sub(1);
+    }
+    if kb.just_pressed(KeyCode::Right) || pad.just_pressed(GamepadButtonType::DPadRight) {
+        rebind.slot = (rebind.slot + 1).min(MAX_BINDINGS - 1);
+    }
+
+    match rebind.action() {
+        Some(action) => {
+            if kb.just_pressed(KeyCode::Return) {
+                rebind.listening = true;
+            }
+            if kb.just_pressed(KeyCode::Delete) {
+                map.unbind(action, rebind.slot);
+                map.save();
+            }
+        }
+        None => {
+            if kb.just_pressed(KeyCode::Return) {
+                *map = ActionMap::default();
+                map.save();
+            }
+        }
+    }
+}
+
+fn close_rebind(mut rebind: ResMut<RebindState>) {
+    rebind.open      = false;
+    rebind.listening = false;
+}
@@ -0,0 +1,195 @@
+use bevy::{
+    ecs::system::SystemParam,
+    input::gamepad::GamepadConnectionEvent,
+    prelude::*,
+};
+
+use lib::GAMEPAD_DEADZONE;
+use super::actions::ActionMap;
+use crate::{resources::IsPaused, state::states::GameStates};
+
+/// Fires the ship's guns.
+pub const FIRE_BUTTON:    GamepadButtonType = GamepadButtonType::RightTrigger2;
+/// Confirms a menu choice (A on Xbox layouts).
+pub const CONFIRM_BUTTON: GamepadButtonType = GamepadButtonType::South;
+/// Backs out of a menu or resumes from pause (B on Xbox layouts).
+pub const BACK_BUTTON:    GamepadButtonType = GamepadButtonType::East;
+pub const PAUSE_BUTTON:   GamepadButtonType = GamepadButtonType::Start;
+
+// ── Resources / components ────────────────────────────────────────────────────
+
+/// The pad that drives the game: the first one connected, replaced by the
+/// next available pad if it is unplugged.
+#[derive(Resource, Default)]
+pub struct ActiveGamepad(pub Option<Gamepad>);
+
+/// Whichever device was touched last. UI prompts follow it, and `Touch`
+/// brings up the on-screen controls.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InputDevice {
+    #[default]
+    Keyboard,
+    Gamepad,
+    Touch,
+}
+
+/// UI text that names a control. Rewritten whenever `InputDevice` or the
+/// `ActionMap` changes; `{Action}` placeholders in the keyboard text become
+/// the keys bound to that action.
+#[derive(Component)]
+pub struct PromptText {
+    pub keyboard: &'static str,
+    pub gamepad:  &'static str,
+    /// Empty for prompts with nothing to tap.
+    pub touch:    &'static str,
+}
+
+impl PromptText {
+    pub fn new(keyboard: &'static str, gamepad: &'static str) -> Self {
+        PromptText { keyboard, gamepad, touch: "" }
+    }
+
+    pub fn with_touch(mut self, touch: &'static str) -> Self {
+        self.touch = touch;
+        self
+    }
+
+    pub fn text(&self, device: InputDevice, map: &ActionMap) -> String {
+        match device {
+            InputDevice::Keyboard => map.fill(self.keyboard),
+            InputDevice::Gamepad  => self.gamepad.to_string(),
+            InputDevice::Touch    => self.touch.to_string(),
+        }
+    }
+}
+
+/// Read access to the active pad. Every query returns "nothing pressed" when
+/// no pad is connected, so callers can check it unconditionally.
+#[derive(SystemParam)]
+pub struct PadInput<'w> {
+    active:  Res<'w, ActiveGamepad>,
+    buttons: Res<'w, Input<GamepadButton>>,
+    axes:    Res<'w, Axis<GamepadAxis>>,
+}
+
+impl PadInput<'_> {
+    pub fn pressed(&self, button: GamepadButtonType) -> bool {
+        self.active.0.is_some_and(|pad| self.buttons.pressed(GamepadButton::new(pad, button)))
+    }
+
+    pub fn just_pressed(&self, button: GamepadButtonType) -> bool {
+        self.active.0.is_some_and(|pad| self.buttons.just_pressed(GamepadButton::new(pad, button)))
+    }
+
+    pub fn left_stick(&self) -> Vec2 {
+        self.stick(GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY)
+    }
+
+    pub fn right_stick(&self) -> Vec2 {
+        self.stick(GamepadAxisType::RightStickX, GamepadAxisType::RightStickY)
+    }
+
+    /// Radial deadzone: anything inside `GAMEPAD_DEADZONE` is zero and the
+    /// remaining travel is stretched back to a 0–1 length.
+    fn stick(&self, x: GamepadAxisType, y: GamepadAxisType) -> Vec2 {
+        let Some(pad) = self.active.0 else { return Vec2::ZERO };
+        let raw = Vec2::new(
+            self.axes.get(GamepadAxis::new(pad, x)).unwrap_or(0.0),
+            self.axes.get(GamepadAxis::new(pad, y)).unwrap_or(0.0),
+        );
+        let len = raw.length();
+        if len <= GAMEPAD_DEADZONE {
+            return Vec2::ZERO;
+        }
+        raw / len * ((len - GAMEPAD_DEADZONE) / (1.0 - GAMEPAD_DEADZONE)).min(1.0)
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct GamepadPlugin;
+
+impl Plugin for GamepadPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ActiveGamepad>()
+            .init_resource::<InputDevice>()
+            .add_systems(
+                Update,
+                (
+                    gamepad_connection_system,
+                    input_device_system,
+                    prompt_text_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// Picks up pads as they are plugged in. Losing the active pad mid-run pauses
+/// the game so the player isn't left drifting into a meteor.
+fn gamepad_connection_system(
+    mut events: EventReader<GamepadConnectionEvent>,
+    gamepads:   Res<Gamepads>,
+    state:      Res<State<GameStates>>,
+    mut active: ResMut<ActiveGamepad>,
+    mut device: ResMut<InputDevice>,
+    mut paused: ResMut<IsPaused>,
+) {
+    for event in events.iter() {
+        if event.connected() {
+            if active.0.is_none() {
+                active.0 = Some(event.gamepad);
+            }
+        } else if active.0 == Some(event.gamepad) {
+            active.0 = gamepads.iter().find(|pad| *pad != event.gamepad);
+            if active.0.is_none() {
+                *device = InputDevice::Keyboard;
+                if *state.get() == GameStates::InGame {
+                    paused.0 = true;
+                }
+            }
+        }
+    }
+}
+
+/// Switches `InputDevice` to whichever device saw input this frame. Touch is
+/// checked first since some browsers also report a tap as a mouse click.
+fn input_device_system(
+    keyboard:   Res<Input<KeyCode>>,
+    mouse:      Res<Input<MouseButton>>,
+    touches:    Res<Touches>,
+    pad:        PadInput,
+    mut device: ResMut<InputDevice>,
+) {
+    let used = if touches.any_just_pressed() {
+        InputDevice::Touch
+    } else if keyboard.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some() {
+        InputDevice::Keyboard
+    } else if pad.active.0.is_some_and(|p| pad.buttons.get_just_pressed().any(|b| b.gamepad == p))
+        || pad.left_stick() != Vec2::ZERO
+        || pad.right_stick() != Vec2::ZERO
+    {
+        InputDevice::Gamepad
+    } else {
+        return;
+    };
+    // Only write on a real change so `is_changed` means something downstream.
+    if *device != used {
+        *device = used;
+    }
+}
+
+fn prompt_text_system(
+    device:    Res<InputDevice>,
+    map:       Res<ActionMap>,
+    mut query: Query<(Ref<PromptText>, &mut Text)>,
+) {
+    for (prompt, mut text) in &mut query {
+        if device.is_changed() || map.is_changed() || prompt.is_added() {
+            text.sections[0].value = prompt.text(*device, &map);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod actions;
+pub mod gamepad;
+pub mod touch;
+pub mod twin_stick;
\ No newline at end of file
@@ -0,0 +1,437 @@
+use bevy::{input::InputSystem, prelude::*};
+
+use super::gamepad::InputDevice;
+use crate::{
+    objects::secondary::secondary_name,
+    player::{ability::ShipAbility, player::PlayerComponent, skin::SkinProgress},
+    resources::{
+        GameMode, GameSettings, GameSprites, IsPaused, LevelResource, Life, PlayerUpgrades, RunRng, Score, SecondaryAmmo,
+        ShipSelectState, UpgradeSelectionState, WindowSize,
+    },
+    state::states::{game_over_input_system, GameStates},
+    ui::ui::{
+        AchievementsBackButton, AchievementsButton, ContinueBanner, HangarBackButton, HangarButton, HangarRow,
+        InitialsButton, ModeButton, ModeCard, RerollButton, ShipSelectCard, UpgradeCard,
+    },
+    upgrades::{
+        hangar::{HangarState, MetaProgress},
+        upgrades::{confirm_upgrade, reroll_upgrades, UpgradesRerolled},
+    },
+    utils::{
+        highscores::{submit_initials, HighScores, InitialsEntry, INITIALS_LEN},
+        run_stats::RunStats,
+        savegame::{continue_run, SavedRun},
+    },
+};
+
+/// Furthest the joystick knob travels from where the thumb first landed.
+const STICK_RADIUS:            f32 = 70.0;
+/// Fraction of `STICK_RADIUS` that still reads as centred.
+const STICK_DEADZONE:          f32 = 0.15;
+const FIRE_BUTTON_RADIUS:      f32 = 58.0;
+const ABILITY_BUTTON_RADIUS:   f32 = 42.0;
+const SECONDARY_BUTTON_RADIUS: f32 = 42.0;
+/// Button centres, measured in from the bottom-right corner of the screen.
+const FIRE_BUTTON_INSET:       Vec2 = Vec2::new(110.0, 110.0);
+const ABILITY_BUTTON_INSET:    Vec2 = Vec2::new(240.0, 80.0);
+const SECONDARY_BUTTON_INSET:  Vec2 = Vec2::new(80.0, 240.0);
+
+const CONTROL_COLOR:           Color = Color::rgba(1.0, 1.0, 1.0, 0.35);
+const CONTROL_ACTIVE_COLOR:    Color = Color::rgba(0.4, 0.9, 1.0, 0.8);
+
+// ── Resources / components ────────────────────────────────────────────────────
+
+/// On-screen controls, read from `Touches` once per frame. Everything stays
+/// at rest unless a finger is on the matching control, so gameplay systems
+/// can check these unconditionally.
+#[derive(Resource, Default)]
+pub struct TouchControls {
+    /// Joystick deflection, +Y up, length 0–1.
+    pub stick:     Vec2,
+    /// A finger is on the fire button.
+    pub fire:      bool,
+    /// The ability button was tapped this frame.
+    pub ability:   bool,
+    /// The secondary weapon button was tapped this frame.
+    pub secondary: bool,
+    /// Finger driving the joystick and where it first touched down.
+    stick_touch: Option<(u64, Vec2)>,
+}
+
+#[derive(Component)]
+struct TouchControlsRoot;
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Virtual joystick on the left half of the screen, fire, ability and
+/// secondary buttons on the right, tap-to-select on the ship and upgrade
+/// cards, and tappable initials on the game over screen. The on-screen
+/// controls appear once `InputDevice` switches to `Touch`.
+pub struct TouchPlugin;
+
+impl Plugin for TouchPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TouchControls>()
+            .add_systems(PreUpdate, touch_controls_system.after(InputSystem))
+            .add_systems(
+                Update,
+                (touch_overlay_system, touch_labels_system).run_if(in_state(GameStates::InGame)),
+            )
+            .add_systems(OnExit(GameStates::InGame), despawn_touch_labels)
+            .add_systems(Update, mode_select_tap_system.run_if(in_state(GameStates::ModeSelect)))
+            .add_systems(Update, ship_select_tap_system.run_if(in_state(GameStates::ShipSelect)))
+            .add_systems(Update, hangar_tap_system.run_if(in_state(GameStates::Hangar)))
+            .add_systems(Update, achievements_tap_system.run_if(in_state(GameStates::Achievements)))
+            .add_systems(Update, upgrade_tap_system.run_if(in_state(GameStates::UpgradeSelection)))
+            .add_systems(
+                Update,
+                upgrade_tap_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|s: Res<UpgradeSelectionState>| s.mid_run),
+            )
+            // After the play-again check, so the DONE tap doesn't also restart.
+            .add_systems(
+                Update,
+                initials_tap_system.after(game_over_input_system).run_if(in_state(GameStates::GameOver)),
+            );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+fn touch_controls_system(
+    touches:      Res<Touches>,
+    wdw_size:     Res<WindowSize>,
+    mut controls: ResMut<TouchControls>,
+) {
+    let (fire_center, ability_center, secondary_center) = button_centers(&wdw_size);
+
+    // A new finger on the left half becomes the joystick, centred where it landed.
+    if controls.stick_touch.is_none() {
+        controls.stick_touch = touches
+            .iter_just_pressed()
+            .find(|t| t.position().x < wdw_size.w * 0.5)
+            .map(|t| (t.id(), t.position()));
+    }
+
+    controls.stick = Vec2::ZERO;
+    if let Some((id, origin)) = controls.stick_touch {
+        match touches.get_pressed(id) {
+            Some(touch) => {
+                // Screen space runs +Y down.
+                let offset = (touch.position() - origin) / STICK_RADIUS;
+                let stick  = Vec2::new(offset.x, -offset.y).clamp_length_max(1.0);
+                if stick.length() > STICK_DEADZONE {
+                    controls.stick = stick;
+                }
+            }
+            None => controls.stick_touch = None,
+        }
+    }
+
+    controls.fire = touches
+        .iter()
+        .any(|t| t.position().distance(fire_center) <= FIRE_BUTTON_RADIUS);
+    controls.ability = touches
+        .iter_just_pressed()
+        .any(|t| t.position().distance(ability_center) <= ABILITY_BUTTON_RADIUS);
+    controls.secondary = touches
+        .iter_just_pressed()
+        .any(|t| t.position().distance(secondary_center) <= SECONDARY_BUTTON_RADIUS);
+}
+
+/// Draws the joystick and buttons while touch is the active device.
+fn touch_overlay_system(
+    mut gizmos: Gizmos,
+    device:     Res<InputDevice>,
+    paused:     Res<IsPaused>,
+    wdw_size:   Res<WindowSize>,
+    controls:   Res<TouchControls>,
+    camera_q:   Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    ammo:       Res<SecondaryAmmo>,
+    ability_q:  Query<&ShipAbility, With<PlayerComponent>>,
+) {
+    if *device != InputDevice::Touch || paused.0 {
+        return;
+    }
+    let Ok((camera, cam_tf)) = camera_q.get_single() else { return };
+    let to_world = |p: Vec2| camera.viewport_to_world_2d(cam_tf, p);
+    let (fire_center, ability_center, secondary_center) = button_centers(&wdw_size);
+
+    if let Some(center) = controls.stick_touch.and_then(|(_, origin)| to_world(origin)) {
+        let knob = center + controls.stick * STICK_RADIUS;
+        gizmos.circle_2d(center, STICK_RADIUS, CONTROL_COLOR);
+        gizmos.circle_2d(knob, STICK_RADIUS * 0.35, CONTROL_ACTIVE_COLOR);
+    }
+    if let Some(center) = to_world(fire_center) {
+        let color = if controls.fire { CONTROL_ACTIVE_COLOR } else { CONTROL_COLOR };
+        gizmos.circle_2d(center, FIRE_BUTTON_RADIUS, color);
+    }
+    if let Some(center) = to_world(ability_center) {
+        let ready = ability_q.get_single().is_ok_and(|a| a.ready());
+        let color = if ready { CONTROL_ACTIVE_COLOR } else { CONTROL_COLOR };
+        gizmos.circle_2d(center, ABILITY_BUTTON_RADIUS, color);
+    }
+    if let Some(center) = to_world(secondary_center) {
+        let color = if ammo.ready() { CONTROL_ACTIVE_COLOR } else { CONTROL_COLOR };
+        gizmos.circle_2d(center, SECONDARY_BUTTON_RADIUS, color);
+    }
+}
+
+/// Spawns the button captions while touch is active, removes them otherwise.
+fn touch_labels_system(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    device:       Res<InputDevice>,
+    paused:       Res<IsPaused>,
+    wdw_size:     Res<WindowSize>,
+    ammo:         Res<SecondaryAmmo>,
+    query:        Query<Entity, With<TouchControlsRoot>>,
+) {
+    let show   = *device == InputDevice::Touch && !paused.0;
+    let exists = !query.is_empty();
+
+    if show && !exists {
+        let (fire_center, ability_center, secondary_center) = button_centers(&wdw_size);
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        width:         Val::Percent(100.0),
+                        height:        Val::Percent(100.0),
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    z_index: ZIndex::Local(5),
+                    ..default()
+                },
+                TouchControlsRoot,
+            ))
+            .with_children(|root| {
+                for (label, center, radius) in [
+                    ("FIRE",    fire_center,    FIRE_BUTTON_RADIUS),
+                    ("ABILITY", ability_center, ABILITY_BUTTON_RADIUS),
+                    (secondary_name(ammo.weapon), secondary_center, SECONDARY_BUTTON_RADIUS),
+                ] {
+                    root.spawn(NodeBundle {
+                        style: Style {
+                            position_type:   PositionType::Absolute,
+                            left:            Val::Px(center.x - radius),
+                            top:             Val::Px(center.y - radius),
+                            width:           Val::Px(radius * 2.0),
+                            height:          Val::Px(radius * 2.0),
+                            justify_content: JustifyContent::Center,
+                            align_items:     AlignItems::Center,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_section(
+                            label,
+                            TextStyle {
+                                font:      game_sprites.font.clone(),
+                                font_size: 16.0,
+                                color:     Color::rgba(1.0, 1.0, 1.0, 0.7),
+                            },
+                        ));
+                    });
+                }
+            });
+    } else if !show && exists {
+        for e in &query { commands.entity(e).despawn_recursive(); }
+    }
+}
+
+fn despawn_touch_labels(mut commands: Commands, query: Query<Entity, With<TouchControlsRoot>>) {
+    for e in &query { commands.entity(e).despawn_recursive(); }
+}
+
+/// Tapping a mode card selects it; tapping the selected card moves on to
+/// ship select.
+fn mode_select_tap_system(
+    touches:        Res<Touches>,
+    cards:          Query<(&ModeCard, &Node, &GlobalTransform)>,
+    mut settings:   ResMut<GameSettings>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    for touch in touches.iter_just_pressed() {
+        let Some(index) = tapped(&cards, touch.position(), |card| card.0) else { continue };
+        if index == settings.mode.index() {
+            next_state.set(GameStates::ShipSelect);
+            return;
+        }
+        settings.mode = GameMode::ALL[index];
+    }
+}
+
+/// Tapping a ship card selects it; tapping the selected card starts the run,
+/// unless the ship is still locked. Tapping the continue banner resumes the
+/// saved run, the title goes back to mode select, and the corner buttons open
+/// the hangar and the achievements.
+fn ship_select_tap_system(
+    touches:        Res<Touches>,
+    cards:          Query<(&ShipSelectCard, &Node, &GlobalTransform)>,
+    banner:         Query<(&ContinueBanner, &Node, &GlobalTransform)>,
+    mode:           Query<(&ModeButton, &Node, &GlobalTransform)>,
+    hangar:         Query<(&HangarButton, &Node, &GlobalTransform)>,
+    achievements:   Query<(&AchievementsButton, &Node, &GlobalTransform)>,
+    meta:           Res<MetaProgress>,
+    mut selection:  ResMut<ShipSelectState>,
+    mut settings:   ResMut<GameSettings>,
+    mut skins:      ResMut<SkinProgress>,
+    mut saved:      ResMut<SavedRun>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    for touch in touches.iter_just_pressed() {
+        if tapped(&banner, touch.position(), |_| 0).is_some() {
+            continue_run(&mut saved, &mut selection, &mut settings, &mut skins, &mut next_state);
+            return;
+        }
+        if tapped(&mode, touch.position(), |_| 0).is_some() {
+            next_state.set(GameStates::ModeSelect);
+            return;
+        }
+        if tapped(&hangar, touch.position(), |_| 0).is_some() {
+            next_state.set(GameStates::Hangar);
+            return;
+        }
+        if tapped(&achievements, touch.position(), |_| 0).is_some() {
+            next_state.set(GameStates::Achievements);
+            return;
+        }
+        let Some(index) = tapped(&cards, touch.position(), |card| card.0) else { continue };
+        if index == selection.selected && selection.row == 0 {
+            if meta.ship_unlocked(index) {
+                next_state.set(GameStates::StartGame);
+            }
+        } else {
+            selection.selected = index;
+            selection.row      = 0;
+        }
+    }
+}
+
+/// Tapping an upgrade card selects it; tapping the selected card takes it.
+/// Tapping the reroll button spends a free reroll.
+fn upgrade_tap_system(
+    touches:        Res<Touches>,
+    cards:          Query<(&UpgradeCard, &Node, &GlobalTransform)>,
+    reroll:         Query<(&RerollButton, &Node, &GlobalTransform)>,
+    meta:           Res<MetaProgress>,
+    mut rng:        ResMut<RunRng>,
+    mut selection:  ResMut<UpgradeSelectionState>,
+    mut upgrades:   ResMut<PlayerUpgrades>,
+    mut life:       ResMut<Life>,
+    mut stats:      ResMut<RunStats>,
+    mut paused:     ResMut<IsPaused>,
+    mut rerolled:   EventWriter<UpgradesRerolled>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    for touch in touches.iter_just_pressed() {
+        if tapped(&reroll, touch.position(), |_| 0).is_some() {
+            if reroll_upgrades(&mut selection, &upgrades, &meta, &mut rng) {
+                rerolled.send(UpgradesRerolled);
+            }
+            return;
+        }
+        let Some(index) = tapped(&cards, touch.position(), |card| card.index) else { continue };
+        if index >= selection.choices.len() {
+            continue;
+        }
+        if index == selection.selected {
+            confirm_upgrade(&mut selection, &mut upgrades, &mut life, &mut stats, &mut paused, &mut next_state);
+            return;
+        }
+        selection.selected = index;
+    }
+}
+
+/// Tapping an item selects it; tapping the selected item buys it. Tapping the
+/// back button returns to ship select.
+fn hangar_tap_system(
+    touches:        Res<Touches>,
+    rows:           Query<(&HangarRow, &Node, &GlobalTransform)>,
+    back:           Query<(&HangarBackButton, &Node, &GlobalTransform)>,
+    mut hangar:     ResMut<HangarState>,
+    mut meta:       ResMut<MetaProgress>,
+    mut skins:      ResMut<SkinProgress>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    for touch in touches.iter_just_pressed() {
+        if tapped(&back, touch.position(), |_| 0).is_some() {
+            next_state.set(GameStates::ShipSelect);
+            return;
+        }
+        let Some(index) = tapped(&rows, touch.position(), |row| row.0) else { continue };
+        if index == hangar.selected {
+            meta.buy(index, &mut skins);
+        } else {
+            hangar.selected = index;
+        }
+    }
+}
+
+/// Tapping the back button returns to ship select.
+fn achievements_tap_system(
+    touches:        Res<Touches>,
+    back:           Query<(&AchievementsBackButton, &Node, &GlobalTransform)>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    for touch in touches.iter_just_pressed() {
+        if tapped(&back, touch.position(), |_| 0).is_some() {
+            next_state.set(GameStates::ShipSelect);
+            return;
+        }
+    }
+}
+
+/// Tapping a letter moves the cursor there, or steps it on to the next letter
+/// if the cursor is already on it. Tapping DONE saves the entry.
+fn initials_tap_system(
+    touches:    Res<Touches>,
+    buttons:    Query<(&InitialsButton, &Node, &GlobalTransform)>,
+    settings:   Res<GameSettings>,
+    selection:  Res<ShipSelectState>,
+    score:      Res<Score>,
+    level:      Res<LevelResource>,
+    mut scores: ResMut<HighScores>,
+    mut entry:  ResMut<InitialsEntry>,
+) {
+    if !entry.active {
+        return;
+    }
+    for touch in touches.iter_just_pressed() {
+        let Some(slot) = tapped(&buttons, touch.position(), |button| button.0) else { continue };
+        if slot == INITIALS_LEN {
+            submit_initials(&mut entry, &mut scores, &settings, &selection, &score, &level);
+            return;
+        }
+        if slot == entry.slot {
+            entry.cycle(1);
+        } else {
+            entry.slot = slot;
+        }
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Fire, ability and secondary button centres in screen space.
+fn button_centers(wdw_size: &WindowSize) -> (Vec2, Vec2, Vec2) {
+    let corner = Vec2::new(wdw_size.w, wdw_size.h);
+    (corner - FIRE_BUTTON_INSET, corner - ABILITY_BUTTON_INSET, corner - SECONDARY_BUTTON_INSET)
+}
+
+/// Index of the UI card under `position`, if any.
+fn tapped<C: Component>(
+    cards:    &Query<(&C, &Node, &GlobalTransform)>,
+    position: Vec2,
+    index:    impl Fn(&C) -> usize,
+) -> Option<usize> {
+    cards
+        .iter()
+        .find(|(_, node, tf)| node.logical_rect(tf).contains(position))
+        .map(|(card, _, _)| index(card))
+}
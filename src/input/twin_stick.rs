@@ -0,0 +1,186 @@
+use std::f32::consts::PI;
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use lib::{PLAYER_ACCELERATION, PLAYER_DECELERATION, ICE_SLOW_MULT};
+use super::{
+    actions::{Action, Actions},
+    gamepad::{InputDevice, PadInput},
+    touch::TouchControls,
+};
+use crate::{
+    common::common_components::{Position, RotationAngle, Velocity},
+    player::{hyperspace::InHyperspace, player::PlayerComponent, ship::ShipComponent},
+    resources::{GameSettings, GameSprites, IsPaused, PlayerBuff, PlayerUpgrades},
+    state::states::GameStates,
+};
+
+// ── Resources / components ────────────────────────────────────────────────────
+
+/// Mouse cursor in world space, or `None` while it is outside the window.
+#[derive(Resource, Default)]
+pub struct CursorWorld(pub Option<Vec2>);
+
+#[derive(Component)]
+pub struct Crosshair;
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Twin-stick scheme: the Up/Down and turn keys (WASD or the arrows by
+/// default) move in screen directions, the ship faces the cursor and
+/// left-click fires. On a pad the left stick moves and the right stick
+/// aims. Active when `GameSettings::control_scheme` is `TwinStick`.
+pub struct TwinStickPlugin;
+
+impl Plugin for TwinStickPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CursorWorld>()
+            .add_systems(Update, cursor_world_system)
+            .add_systems(OnEnter(GameStates::InGame), spawn_crosshair)
+            .add_systems(OnExit(GameStates::InGame),  despawn_crosshair)
+            .add_systems(
+                Update,
+                crosshair_follow_system.run_if(in_state(GameStates::InGame)),
+            )
+            .add_systems(
+                Update,
+                twin_stick_movement_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0)
+                    .run_if(|s: Res<GameSettings>| s.twin_stick()),
+            );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// Converts the window cursor to world space through the 2D camera.
+fn cursor_world_system(
+    window_q:   Query<&Window, With<PrimaryWindow>>,
+    camera_q:   Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut cursor: ResMut<CursorWorld>,
+) {
+    let (Ok(window), Ok((camera, cam_tf))) = (window_q.get_single(), camera_q.get_single()) else { return };
+    cursor.0 = window
+        .cursor_position()
+        .and_then(|p| camera.viewport_to_world_2d(cam_tf, p));
+}
+
+fn twin_stick_movement_system(
+    actions:   Actions,
+    pad:       PadInput,
+    touch:     Res<TouchControls>,
+    device:    Res<InputDevice>,
+    upgrades:  Res<PlayerUpgrades>,
+    buff:      Res<PlayerBuff>,
+    cursor:    Res<CursorWorld>,
+    mut query: Query<(&Position, &ShipComponent, &mut Velocity, &mut RotationAngle), (With<PlayerComponent>, Without<InHyperspace>)>,
+) {
+    let Ok((pos, ship, mut velocity, mut angle)) = query.get_single_mut() else { return };
+
+    // Ice meteors leave the ship sluggish for a moment.
+    let slow      = if buff.slow_timer > 0.0 { ICE_SLOW_MULT } else { 1.0 };
+    let max_speed = upgrades.effective_max_speed(ship.def()) * slow;
+
+    let mut dir = Vec2::ZERO;
+    if actions.pressed(Action::Up)        { dir.y += 1.0; }
+    if actions.pressed(Action::Down)      { dir.y -= 1.0; }
+    if actions.pressed(Action::TurnLeft)  { dir.x -= 1.0; }
+    if actions.pressed(Action::TurnRight) { dir.x += 1.0; }
+    // Keys give full thrust; sticks scale with how far they are pushed.
+    let dir = if dir != Vec2::ZERO {
+        dir.normalize()
+    } else if touch.stick != Vec2::ZERO {
+        touch.stick
+    } else {
+        pad.left_stick()
+    };
+
+    if dir != Vec2::ZERO {
+        velocity.0 += dir * PLAYER_ACCELERATION;
+        if velocity.0.length() > max_speed {
+            velocity.0 = velocity.0.normalize_or_zero() * max_speed;
+        }
+    } else {
+        velocity.0 *= 1.0 - PLAYER_DECELERATION;
+    }
+
+    // The ship's nose points along +Y at angle 0. A centred right stick
+    // leaves the ship facing wherever it last aimed; on touch there is no
+    // aim control, so the ship faces the way it's flying.
+    let aim = match *device {
+        InputDevice::Gamepad  => Some(pad.right_stick()).filter(|a| *a != Vec2::ZERO),
+        InputDevice::Touch    => Some(touch.stick).filter(|a| *a != Vec2::ZERO),
+        InputDevice::Keyboard => cursor.0.map(|target| target - pos.0).filter(|a| a.length_squared() > 1.0),
+    };
+    if let Some(aim) = aim {
+        angle.0 = aim.y.atan2(aim.x) - PI / 2.0;
+    }
+}
+
+fn spawn_crosshair(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    settings:     Res<GameSettings>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !settings.twin_stick() {
+        return;
+    }
+    if let Ok(mut window) = window_q.get_single_mut() {
+        window.cursor.visible = false;
+    }
+    commands.spawn((
+        SpriteBundle {
+            texture: game_sprites.crosshair.clone(),
+            transform: Transform::from_xyz(0.0, 0.0, 20.0),
+            sprite: Sprite {
+                color: Color::rgba(1.0, 1.0, 1.0, 0.85),
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        Crosshair,
+        Name::new("Crosshair"),
+    ));
+}
+
+fn despawn_crosshair(
+    mut commands: Commands,
+    query:        Query<Entity, With<Crosshair>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    for e in &query {
+        commands.entity(e).despawn();
+    }
+    if let Ok(mut window) = window_q.get_single_mut() {
+        window.cursor.visible = true;
+    }
+}
+
+/// Keeps the crosshair on the cursor. The OS cursor comes back while paused
+/// so the pause menu stays usable; the crosshair hides while a pad is in use.
+fn crosshair_follow_system(
+    cursor:       Res<CursorWorld>,
+    paused:       Res<IsPaused>,
+    device:       Res<InputDevice>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut query:    Query<(&mut Transform, &mut Visibility), With<Crosshair>>,
+) {
+    let Ok((mut tf, mut vis)) = query.get_single_mut() else { return };
+
+    match cursor.0 {
+        Some(p) if !paused.0 && *device == InputDevice::Keyboard => {
+            tf.translation = p.extend(tf.translation.z);
+            *vis = Visibility::Inherited;
+        }
+        _ => *vis = Visibility::Hidden,
+    }
+
+    if let Ok(mut window) = window_q.get_single_mut() {
+        if window.cursor.visible != paused.0 {
+            window.cursor.visible = paused.0;
+        }
+    }
+}
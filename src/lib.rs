@@ -1,6 +1,6 @@
 use std::f32::consts::PI;
 use bevy::prelude::*;
-use rand::{prelude::*, distributions::Standard};
+use rand::prelude::*;
 
 // ── Frame / window ──────────────────────────────────────────────────────────
 pub const MAX_FRAMERATE: f64 = 60.0;
@@ -17,6 +17,13 @@ pub const PLAYER_SHOOT_COOLDOWN: f32 = 0.18;
 pub const PLAYER_START_HP: i32 = 3;
 pub const PLAYER_START_SCORE: i32 = 0;
 pub const PLAYER_HP_ADD: i32 = 1;
+/// Post-hit invulnerability choices (seconds) offered on the ship select screen.
+pub const HIT_INVULN_OPTIONS: [f32; 4] = [0.5, 1.0, 1.5, 2.0];
+pub const DEFAULT_HIT_INVULN: f32 = 1.0;
+/// Extra invulnerability seconds per Extra Armor level.
+pub const HIT_INVULN_PER_ARMOR: f32 = 0.25;
+/// Visibility toggles per second while the ship is invulnerable after a hit.
+pub const HIT_INVULN_BLINK_RATE: f32 = 12.0;
 
 // ── Projectile ───────────────────────────────────────────────────────────────
 pub const PROJECTILE_SIZE: Vec2 = Vec2::new(9., 54.);
@@ -24,12 +31,6 @@ pub const PROJECTILE_DESPAWN_TIME: f32 = 3.5;
 pub const PROJECTILE_SPEED: f32 = 10.0;
 
 // ── Meteor / asteroid ────────────────────────────────────────────────────────
-pub const METEOR_SIZE: [(MeteorSizeType, Vec2); 3] = [
-    (MeteorSizeType::Large,  Vec2::new(120.0, 98.0)),
-    (MeteorSizeType::Medium, Vec2::new(43.0,  43.0)),
-    (MeteorSizeType::Small,  Vec2::new(28.0,  28.0)),
-];
-
 pub const METEOR_DMG: [(MeteorSizeType, i32); 3] = [
     (MeteorSizeType::Small,  1),
     (MeteorSizeType::Medium, 2),
@@ -42,6 +43,62 @@ pub const METEOR_SCORE: [(MeteorSizeType, i32); 3] = [
     (MeteorSizeType::Large,  50),
 ];
 
+/// Outline radius range `(min, max)` for procedurally generated meteors.
+pub const METEOR_RADIUS_RANGE: [(MeteorSizeType, (f32, f32)); 3] = [
+    (MeteorSizeType::Large,  (48.0, 62.0)),
+    (MeteorSizeType::Medium, (18.0, 24.0)),
+    (MeteorSizeType::Small,  (11.0, 15.0)),
+];
+
+// ── Meteor physics (optional setting) ───────────────────────────────────────
+pub const METEOR_MASS: [(MeteorSizeType, f32); 3] = [
+    (MeteorSizeType::Small,  1.0),
+    (MeteorSizeType::Medium, 3.0),
+    (MeteorSizeType::Large,  8.0),
+];
+/// Velocity (units/frame) a bullet adds to a mass-1 meteor; heavier ones move less.
+pub const BULLET_IMPULSE: f32 = 0.8;
+/// Seconds a UFO ignores further meteor impacts after taking one.
+pub const UFO_IMPACT_COOLDOWN: f32 = 0.5;
+
+// ── Meteor materials ─────────────────────────────────────────────────────────
+pub const METEOR_MATERIAL_TINT: [(MeteorMaterial, Color); 5] = [
+    (MeteorMaterial::Rock,      Color::WHITE),
+    (MeteorMaterial::Explosive, Color::rgb(1.0, 0.45, 0.3)),
+    (MeteorMaterial::Ice,       Color::rgb(0.6, 0.9, 1.0)),
+    (MeteorMaterial::Metallic,  Color::rgb(0.65, 0.7, 0.8)),
+    (MeteorMaterial::Gold,      Color::rgb(1.0, 0.85, 0.25)),
+];
+
+/// Spawn weights `[Rock, Explosive, Ice, Metallic, Gold]` per level band.
+/// The last row whose `from_level` is <= the current level applies.
+pub const METEOR_MATERIAL_WEIGHTS: [(u32, [u32; 5]); 4] = [
+    (1,  [100, 0,  0,  0,  0]),
+    (3,  [80,  8,  8,  0,  4]),
+    (6,  [64,  12, 10, 10, 4]),
+    (10, [50,  16, 12, 18, 4]),
+];
+
+/// Fragment behaviour on split: `(material, child count, child material, speed mult)`.
+/// Explosive meteors blow up instead of splitting.
+pub const METEOR_MATERIAL_FRAGMENTS: [(MeteorMaterial, u32, MeteorMaterial, f32); 5] = [
+    (MeteorMaterial::Rock,      3, MeteorMaterial::Rock,     1.0),
+    (MeteorMaterial::Explosive, 0, MeteorMaterial::Rock,     1.0),
+    (MeteorMaterial::Ice,       4, MeteorMaterial::Ice,      1.5),
+    (MeteorMaterial::Metallic,  2, MeteorMaterial::Metallic, 0.8),
+    (MeteorMaterial::Gold,      3, MeteorMaterial::Rock,     1.0),
+];
+
+pub const EXPLOSIVE_METEOR_BLAST_RADIUS: f32 = 140.0;
+pub const EXPLOSIVE_METEOR_BLAST_DAMAGE: i32 = 2;
+/// Seconds the player stays slowed after touching an ice meteor.
+pub const ICE_SLOW_DURATION: f32 = 2.0;
+/// Speed and turn multiplier while slowed.
+pub const ICE_SLOW_MULT: f32 = 0.5;
+/// Damage fraction taken by metallic meteors without Heavy Rounds.
+pub const METALLIC_DAMAGE_MULT: f32 = 0.5;
+pub const GOLD_SCORE_MULT: i32 = 3;
+
 // ── Level scaling ────────────────────────────────────────────────────────────
 /// Asteroids spawned on level 1.
 pub const BASE_LEVEL_ASTEROID_COUNT: u32 = 4;
@@ -54,6 +111,50 @@ pub const BASE_ASTEROID_HP: i32 = 1;
 /// Every this many levels, large asteroids gain +1 HP.
 pub const ASTEROID_HP_SCALING_INTERVAL: u32 = 2;
 
+// ── Boss encounters ──────────────────────────────────────────────────────────
+/// Every Nth level is a boss fight instead of a meteor field.
+pub const BOSS_LEVEL_INTERVAL: u32 = 5;
+/// Boss HP on the first encounter (level 5).
+pub const BOSS_BASE_HP: i32 = 60;
+/// Extra boss HP for every later encounter.
+pub const BOSS_HP_PER_ENCOUNTER: i32 = 30;
+/// HP fractions at which the boss enters phase 2 and phase 3.
+pub const BOSS_PHASE_THRESHOLDS: [f32; 2] = [0.66, 0.33];
+/// HP of each weak point before it breaks off.
+pub const BOSS_WEAK_POINT_HP: i32 = 8;
+/// Damage multiplier applied to the boss when a weak point is hit.
+pub const BOSS_WEAK_POINT_DAMAGE_MULT: i32 = 3;
+/// Damage dealt to the player when ramming the boss hull.
+pub const BOSS_CONTACT_DAMAGE: i32 = 2;
+pub const BOSS_SCORE: i32 = 2000;
+/// Caps on live adds so the arena doesn't flood.
+pub const BOSS_MAX_UFO_ADDS: usize = 3;
+pub const BOSS_MAX_METEOR_ADDS: usize = 10;
+
+// ── Elite affixes ────────────────────────────────────────────────────────────
+/// Per-level elite roll table: `(from_level, chance, max_affixes)`.
+/// The last row whose `from_level` is <= the current level applies; below the
+/// first row enemies never roll affixes.
+pub const ELITE_ROLL_TABLE: [(u32, f32, usize); 4] = [
+    (4,  0.10, 1),
+    (7,  0.18, 1),
+    (10, 0.28, 2),
+    (14, 0.40, 3),
+];
+/// Seconds between +1 HP ticks on Regenerating elites.
+pub const ELITE_REGEN_INTERVAL: f32 = 2.5;
+/// Velocity multiplier applied to Fast elites.
+pub const ELITE_FAST_SPEED_MULT: f32 = 1.6;
+/// Fragments added to the split of a Splitting elite.
+pub const ELITE_EXTRA_FRAGMENTS: u32 = 2;
+/// Cosine of the shield half-angle; bullets arriving inside this cone are blocked.
+pub const ELITE_SHIELD_COS: f32 = 0.35;
+/// Volatile elites explode on death with this radius and damage.
+pub const ELITE_BLAST_RADIUS: f32 = 120.0;
+pub const ELITE_BLAST_DAMAGE: i32 = 2;
+/// Score multiplier for destroying any elite.
+pub const ELITE_SCORE_MULT: i32 = 2;
+
 // ── Countdown ────────────────────────────────────────────────────────────────
 /// Duration of each numeric tick (3 → 2 → 1).
 pub const COUNTDOWN_TICK_SECS: f32 = 1.0;
@@ -95,26 +196,181 @@ pub const BULWARK_HEAL_CHANCE:         f32 = 0.35;
 /// reliably happen within the visible play area.
 pub const DETONATOR_RANGE_MULT:        f32 = 0.45;
 
-// ── Per-ship projectile stats ─────────────────────────────────────────────────
-pub const ATTACK_SHIP_PROJ_SPEED_MULT: f32 = 1.45;
-pub const ATTACK_SHIP_COOLDOWN_MULT:   f32 = 0.80;
-pub const ATTACK_SHIP_RANGE_MULT:      f32 = 0.65;
-pub const SHIELD_SHIP_PROJ_SPEED_MULT: f32 = 0.80;
-pub const SHIELD_SHIP_COOLDOWN_MULT:   f32 = 1.30;
-pub const SHIELD_SHIP_RANGE_MULT:      f32 = 1.60;
-/// Base projectile travel range in world units before despawn.
-/// Screen is 720px tall; 850 ≈ just over one full screen-height.
-/// Attack ship (×0.65) → ~550px   Normal → ~850px   Shield (×1.60) → ~1360px
+// ── Projectile range ──────────────────────────────────────────────────────────
+/// Base projectile travel range in world units before despawn, scaled by each
+/// ship's `range_mult`. Screen is 720px tall; 850 ≈ just over one full screen-height.
 pub const PROJECTILE_BASE_RANGE:       f32 = 850.0;
 /// Bolt powerup: multiplies projectile speed (not movement speed).
 pub const BOLT_PROJ_SPEED_MULT:        f32 = 1.65;
 
-// ── Ship types ───────────────────────────────────────────────────────────────
-pub const DEFAULT_STATS: [(ShipType, Stats); 3] = [
-    (ShipType::Normal, Stats { shield: 75.0, power: 50.0, attack_cooldown: 1.0 }),
-    (ShipType::Shield, Stats { shield: 100.0, power: 25.0, attack_cooldown: 1.0 }),
-    (ShipType::Attack, Stats { shield: 50.0, power: 100.0, attack_cooldown: 0.5 }),
+// ── Ship abilities ───────────────────────────────────────────────────────────
+/// The energy meter holds `Stats::shield` points and regains this much per
+/// second for every point of `Stats::power`.
+pub const ABILITY_RECHARGE_PER_POWER: f32 = 0.1;
+/// Dash: distance covered, how long it takes, and the
+/// invulnerability window that starts with it.
+pub const DASH_DISTANCE: f32 = 170.0;
+pub const DASH_DURATION: f32 = 0.12;
+pub const DASH_IFRAMES: f32 = 0.4;
+/// Shockwave: reach and the velocity added to meteors at its centre.
+pub const SHOCKWAVE_RADIUS: f32 = 260.0;
+pub const SHOCKWAVE_FORCE: f32 = 6.0;
+/// Overcharge: duration and fire-rate multiplier.
+pub const OVERCHARGE_DURATION: f32 = 2.0;
+pub const OVERCHARGE_FIRE_RATE_MULT: f32 = 3.0;
+
+// ── Hyperspace ───────────────────────────────────────────────────────────────
+/// Seconds between jumps.
+pub const HYPERSPACE_COOLDOWN: f32 = 4.0;
+/// Seconds the ship takes to fade out, and to fade back in at the destination.
+pub const HYPERSPACE_VANISH_TIME: f32 = 0.25;
+pub const HYPERSPACE_APPEAR_TIME: f32 = 0.25;
+/// Minimum gap between the destination and any meteor's edge.
+pub const HYPERSPACE_CLEARANCE: f32 = 70.0;
+/// Random destinations tried before settling for the roomiest one.
+pub const HYPERSPACE_MAX_TRIES: u32 = 24;
+/// Chance a jump malfunctions, and the HP it costs. Stable Warp removes it.
+pub const HYPERSPACE_MALFUNCTION_CHANCE: f32 = 0.10;
+pub const HYPERSPACE_MALFUNCTION_DAMAGE: i32 = 1;
+
+// ── Gamepad ──────────────────────────────────────────────────────────────────
+/// Stick deflection below this reads as centred; the rest is rescaled to 0–1.
+pub const GAMEPAD_DEADZONE: f32 = 0.2;
+
+// ── Secondary weapons ────────────────────────────────────────────────────────
+/// `(starting ammo, max ammo)` for each secondary weapon.
+pub const SECONDARY_AMMO: [(SecondaryWeapon, (i32, i32)); 3] = [
+    (SecondaryWeapon::Missiles, (6, 12)),
+    (SecondaryWeapon::Mines,    (4, 8)),
+    (SecondaryWeapon::Nuke,     (1, 2)),
+];
+/// Rounds restored by a standard ammo powerup; enhanced gives double, rare fills up.
+pub const SECONDARY_REFILL: [(SecondaryWeapon, i32); 3] = [
+    (SecondaryWeapon::Missiles, 3),
+    (SecondaryWeapon::Mines,    2),
+    (SecondaryWeapon::Nuke,     1),
 ];
+/// Seconds between secondary shots.
+pub const SECONDARY_COOLDOWN: f32 = 0.35;
+/// Homing missile: damage, speed, how far it can turn per frame (radians) and range.
+pub const MISSILE_DAMAGE: i32 = 3;
+pub const MISSILE_SPEED: f32 = 7.0;
+pub const MISSILE_TURN_RATE: f32 = 0.08;
+pub const MISSILE_RANGE: f32 = 1600.0;
+/// Proximity mine: delay before it can trigger, how close a target must come,
+/// the blast it sets off, and how long an untouched mine lasts.
+pub const MINE_ARM_TIME: f32 = 0.75;
+pub const MINE_TRIGGER_RADIUS: f32 = 70.0;
+pub const MINE_BLAST_RADIUS: f32 = 150.0;
+pub const MINE_DAMAGE: i32 = 5;
+pub const MINE_LIFETIME: f32 = 25.0;
+/// Damage the nuke deals to every meteor and UFO. Bosses are unaffected.
+pub const NUKE_DAMAGE: i32 = 99;
+
+// ── Weapon archetypes ────────────────────────────────────────────────────────
+/// How each primary weapon scales the ship's base shot. Laser is the baseline;
+/// for the beam, `cooldown_mult` sets the time between damage ticks.
+pub const WEAPON_STATS: [(WeaponArchetype, WeaponStats); 5] = [
+    (WeaponArchetype::Laser,   WeaponStats { cooldown_mult: 1.0, speed_mult: 1.0,  range_mult: 1.0, damage_bonus: 0, extra_pierce: 0, scale: 1.0 }),
+    (WeaponArchetype::Beam,    WeaponStats { cooldown_mult: 0.5, speed_mult: 1.0,  range_mult: 0.6, damage_bonus: 0, extra_pierce: 0, scale: 1.0 }),
+    (WeaponArchetype::Charge,  WeaponStats { cooldown_mult: 1.0, speed_mult: 1.2,  range_mult: 1.2, damage_bonus: 0, extra_pierce: 0, scale: 1.0 }),
+    (WeaponArchetype::Shotgun, WeaponStats { cooldown_mult: 2.2, speed_mult: 0.9,  range_mult: 0.4, damage_bonus: 0, extra_pierce: 0, scale: 0.8 }),
+    (WeaponArchetype::Plasma,  WeaponStats { cooldown_mult: 1.8, speed_mult: 0.45, range_mult: 1.0, damage_bonus: 1, extra_pierce: 3, scale: 1.6 }),
+];
+/// Width of the beam; also the size of the hit pulses it deals damage through.
+pub const BEAM_WIDTH: f32 = 10.0;
+/// Seconds to fully charge a charge shot, and what a full charge multiplies
+/// damage and sprite size by. A full charge also pierces one extra target.
+pub const CHARGE_TIME: f32 = 1.2;
+pub const CHARGE_MAX_DAMAGE_MULT: f32 = 4.0;
+pub const CHARGE_MAX_SCALE: f32 = 2.5;
+/// Pellets per shotgun blast and the total cone they spread over (radians).
+pub const SHOTGUN_PELLETS: usize = 5;
+pub const SHOTGUN_SPREAD: f32 = 0.5;
+/// Unscaled hitbox of a plasma orb.
+pub const PLASMA_SIZE: Vec2 = Vec2::new(24., 24.);
+
+// ── Weapon heat ──────────────────────────────────────────────────────────────
+/// Optional heat model: every shot adds heat, heat bleeds off over time, and
+/// reaching `HEAT_MAX` locks the guns until heat drops to `HEAT_RESUME`.
+pub const HEAT_MAX: f32 = 100.0;
+pub const HEAT_RESUME: f32 = 25.0;
+/// Heat per laser shot; other weapons scale it by their `cooldown_mult`.
+pub const HEAT_PER_SHOT: f32 = 10.0;
+/// Heat shed per second.
+pub const HEAT_DISSIPATION: f32 = 35.0;
+/// Heat is what limits firing, so the shoot cooldown is shortened to this fraction.
+pub const HEAT_FIRE_RATE_MULT: f32 = 0.6;
+/// With heat on, Rapid Fire cuts heat per shot by this factor per level instead of the cooldown.
+pub const RAPID_FIRE_HEAT_MULT: f32 = 0.8;
+/// With heat on, Chain Reaction's burst multiplies dissipation instead of fire rate.
+pub const CHAIN_REACTION_DISSIPATION_MULT: f32 = 2.5;
+/// Glass Cannon's heavier shots run hotter.
+pub const GLASS_CANNON_HEAT_MULT: f32 = 1.5;
+
+// ── Drones ───────────────────────────────────────────────────────────────────
+/// Distance from the ship's centre to the drone orbit.
+pub const DRONE_ORBIT_RADIUS: f32 = 70.0;
+/// Orbit speed in radians per second.
+pub const DRONE_ORBIT_SPEED: f32 = 1.8;
+/// Collision radius of a drone.
+pub const DRONE_RADIUS: f32 = 10.0;
+/// Drones only fire at targets this close to them.
+pub const DRONE_RANGE: f32 = 450.0;
+pub const DRONE_COOLDOWN: f32 = 0.9;
+/// Cooldown multiplier applied per Drone Overdrive level.
+pub const DRONE_OVERDRIVE_COOLDOWN_MULT: f32 = 0.7;
+pub const DRONE_PROJECTILE_SPEED: f32 = 9.0;
+/// Seconds a destroyed drone takes to come back.
+pub const DRONE_RESPAWN_TIME: f32 = 6.0;
+/// Grace period after Drone Plating soaks a hit.
+pub const DRONE_HIT_INVULN: f32 = 1.0;
+
+// ── Tractor beam ─────────────────────────────────────────────────────────────
+/// Furthest a Small or Medium meteor can be and still get grabbed.
+pub const TRACTOR_RANGE: f32 = 260.0;
+/// Gap between the ship's centre and the edge of a held meteor.
+pub const TRACTOR_HOLD_GAP: f32 = 40.0;
+/// Seconds after a throw (or losing the rock) before the beam can grab again.
+pub const TRACTOR_COOLDOWN: f32 = 3.0;
+pub const TRACTOR_THROW_SPEED: f32 = 12.0;
+/// Blast dealt where a thrown meteor hits something.
+pub const TRACTOR_THROW_DAMAGE: i32 = 4;
+pub const TRACTOR_IMPACT_RADIUS: f32 = 70.0;
+
+// ── Hangar ───────────────────────────────────────────────────────────────────
+/// Scrap paid at game over for each level cleared.
+pub const SCRAP_PER_LEVEL:  u32 = 10;
+/// Scrap paid at game over for each enemy destroyed during the run.
+pub const SCRAP_PER_METEOR: u32 = 1;
+pub const SCRAP_PER_UFO:    u32 = 3;
+pub const SCRAP_PER_BOSS:   u32 = 25;
+
+// ── Achievements ─────────────────────────────────────────────────────────────
+/// Seconds each unlock notification stays on screen.
+pub const ACHIEVEMENT_TOAST_SECS: f32 = 3.0;
+
+// ── Survival ─────────────────────────────────────────────────────────────────
+/// Seconds between meteors when a survival run starts, and the floor the gap
+/// shrinks to.
+pub const SURVIVAL_METEOR_SECS:     f32 = 3.0;
+pub const SURVIVAL_METEOR_MIN_SECS: f32 = 0.6;
+/// Seconds between UFOs, likewise.
+pub const SURVIVAL_UFO_SECS:        f32 = 20.0;
+pub const SURVIVAL_UFO_MIN_SECS:    f32 = 6.0;
+/// Every this many seconds survived adds the starting spawn rate again, so
+/// after two ramps spawns come three times as often.
+pub const SURVIVAL_RAMP_SECS:       f32 = 60.0;
+/// Seconds per stage. The stage stands in for the level number, so meteor
+/// HP, materials, UFO types and elites scale as they do in classic.
+pub const SURVIVAL_STAGE_SECS:      f32 = 45.0;
+/// Caps on live enemies so the screen doesn't flood.
+pub const SURVIVAL_MAX_METEORS:     usize = 30;
+pub const SURVIVAL_MAX_UFOS:        usize = 4;
+/// Score that brings up the first upgrade offer. Each later offer needs the
+/// previous gap plus `SURVIVAL_OFFER_STEP`.
+pub const SURVIVAL_FIRST_OFFER:     i32 = 1000;
+pub const SURVIVAL_OFFER_STEP:      i32 = 500;
 
 // ── Types ─────────────────────────────────────────────────────────────────────
 #[derive(Copy, Clone, Default, Reflect, PartialEq)]
@@ -125,11 +381,50 @@ pub enum MeteorSizeType {
     Large  = 3,
 }
 
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub enum MeteorMaterial {
+    #[default]
+    Rock,
+    Explosive,
+    Ice,
+    Metallic,
+    Gold,
+}
+
+/// Signature ability a ship carries on the ability button.
 #[derive(Copy, Clone, PartialEq)]
-pub enum ShipType {
-    Normal,
-    Shield,
-    Attack,
+pub enum AbilityKind {
+    Dash,
+    Shockwave,
+    Overcharge,
+}
+
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum SecondaryWeapon {
+    #[default]
+    Missiles,
+    Mines,
+    Nuke,
+}
+
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum WeaponArchetype {
+    #[default]
+    Laser,
+    Beam,
+    Charge,
+    Shotgun,
+    Plasma,
+}
+
+#[derive(Copy, Clone)]
+pub struct WeaponStats {
+    pub cooldown_mult: f32,
+    pub speed_mult:    f32,
+    pub range_mult:    f32,
+    pub damage_bonus:  i32,
+    pub extra_pierce:  i32,
+    pub scale:         f32,
 }
 
 #[derive(Copy, Clone)]
@@ -139,42 +434,128 @@ pub struct Stats {
     pub attack_cooldown: f32,
 }
 
-impl Distribution<ShipType> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ShipType {
-        match rng.gen_range(0..3) {
-            0 => ShipType::Attack,
-            1 => ShipType::Shield,
-            _ => ShipType::Normal,
+/// Returns contact damage for a given meteor size.
+pub fn meteor_damage(size: MeteorSizeType) -> i32 {
+    for (st, d) in METEOR_DMG {
+        if st == size {
+            return d;
         }
     }
+    METEOR_DMG[0].1
 }
 
-/// Returns the hit-box size for a given meteor size.
-pub fn meteor_size(size: MeteorSizeType) -> Vec2 {
-    for (st, s) in METEOR_SIZE {
+/// Returns score awarded for destroying a given meteor size.
+pub fn meteor_score(size: MeteorSizeType) -> i32 {
+    for (st, s) in METEOR_SCORE {
         if st == size {
             return s;
         }
     }
-    METEOR_SIZE[0].1
+    METEOR_SCORE[0].1
 }
 
-/// Returns contact damage for a given meteor size.
-pub fn meteor_damage(size: MeteorSizeType) -> i32 {
-    for (st, d) in METEOR_DMG {
+/// Returns the outline radius range for a given meteor size.
+pub fn meteor_radius_range(size: MeteorSizeType) -> (f32, f32) {
+    for (st, r) in METEOR_RADIUS_RANGE {
         if st == size {
-            return d;
+            return r;
         }
     }
-    METEOR_DMG[0].1
+    METEOR_RADIUS_RANGE[0].1
 }
 
-/// Returns score awarded for destroying a given meteor size.
-pub fn meteor_score(size: MeteorSizeType) -> i32 {
-    for (st, s) in METEOR_SCORE {
+/// Returns `(chance, max_affixes)` for elite rolls on the given level.
+pub fn elite_roll_for_level(level: u32) -> (f32, usize) {
+    let mut roll = (0.0, 0);
+    for (from, chance, max) in ELITE_ROLL_TABLE {
+        if level >= from {
+            roll = (chance, max);
+        }
+    }
+    roll
+}
+
+/// Returns the sprite tint for a meteor material.
+pub fn meteor_material_tint(material: MeteorMaterial) -> Color {
+    for (m, c) in METEOR_MATERIAL_TINT {
+        if m == material {
+            return c;
+        }
+    }
+    METEOR_MATERIAL_TINT[0].1
+}
+
+/// Rolls a meteor material for the given level from `METEOR_MATERIAL_WEIGHTS`.
+pub fn roll_meteor_material<R: Rng + ?Sized>(level: u32, rng: &mut R) -> MeteorMaterial {
+    const ORDER: [MeteorMaterial; 5] = [
+        MeteorMaterial::Rock, MeteorMaterial::Explosive, MeteorMaterial::Ice,
+        MeteorMaterial::Metallic, MeteorMaterial::Gold,
+    ];
+
+    let mut weights = METEOR_MATERIAL_WEIGHTS[0].1;
+    for (from, w) in METEOR_MATERIAL_WEIGHTS {
+        if level >= from {
+            weights = w;
+        }
+    }
+
+    let total: u32 = weights.iter().sum();
+    let mut pick = rng.gen_range(0..total.max(1));
+    for (material, weight) in ORDER.into_iter().zip(weights) {
+        if pick < weight {
+            return material;
+        }
+        pick -= weight;
+    }
+    MeteorMaterial::Rock
+}
+
+/// Returns `(child count, child material, speed mult)` for a splitting meteor.
+pub fn meteor_material_fragments(material: MeteorMaterial) -> (u32, MeteorMaterial, f32) {
+    for (m, count, child, speed) in METEOR_MATERIAL_FRAGMENTS {
+        if m == material {
+            return (count, child, speed);
+        }
+    }
+    (3, MeteorMaterial::Rock, 1.0)
+}
+
+/// Returns the collision mass for a given meteor size.
+pub fn meteor_mass(size: MeteorSizeType) -> f32 {
+    for (st, m) in METEOR_MASS {
         if st == size {
+            return m;
+        }
+    }
+    METEOR_MASS[0].1
+}
+
+/// Returns `(starting ammo, max ammo)` for a secondary weapon.
+pub fn secondary_ammo(weapon: SecondaryWeapon) -> (i32, i32) {
+    for (w, a) in SECONDARY_AMMO {
+        if w == weapon {
+            return a;
+        }
+    }
+    SECONDARY_AMMO[0].1
+}
+
+/// Returns the rounds a standard ammo powerup restores for a secondary weapon.
+pub fn secondary_refill(weapon: SecondaryWeapon) -> i32 {
+    for (w, r) in SECONDARY_REFILL {
+        if w == weapon {
+            return r;
+        }
+    }
+    SECONDARY_REFILL[0].1
+}
+
+/// Returns how a primary weapon scales the ship's base shot.
+pub fn weapon_stats(weapon: WeaponArchetype) -> WeaponStats {
+    for (w, s) in WEAPON_STATS {
+        if w == weapon {
             return s;
         }
     }
-    METEOR_SCORE[0].1
+    WEAPON_STATS[0].1
 }
@@ -5,21 +5,26 @@ use bevy_tweening::TweeningPlugin;
 use lib::{BORDER_EXTRA_SPACE, PLAYER_START_HP, PLAYER_START_SCORE, MAX_FRAMERATE};
 use resources::{
     CameraShake, GameSprites, IsPaused, Life, Score, WindowSize, WindowDespawnBorder,
-    LevelResource, PlayerUpgrades, UpgradeSelectionState, ShipSelectState, PlayerBuff,
-    SHIP_NORMAL_SPRITE, SHIP_ATTACK_SPRITE, SHIP_SHIELD_SPRITE,
+    LevelResource, PlayerUpgrades, UpgradeSelectionState, ShipSelectState, PlayerBuff, GameSettings, SecondaryAmmo,
+    RunRng,
+    ShipSprites, SkinSprites,
     POWERUP_HP_SPRITE, POWERUP_HP_SPRITE_GREEN, POWERUP_HP_SPRITE_RED,
     POWERUP_BOLT_SPRITE, POWERUP_BOLT_SPRITE_GREEN, POWERUP_BOLT_SPRITE_RED,
     POWERUP_SHIELD_SPRITE, POWERUP_SHIELD_SPRITE_GREEN, POWERUP_SHIELD_SPRITE_RED,
+    POWERUP_AMMO_SPRITE, POWERUP_AMMO_SPRITE_GREEN, POWERUP_AMMO_SPRITE_RED,
     SHIELD_EFFECT_SPRITE,
-    PROJECTILE_NORMAL_SPRITE, PROJECTILE_ATTACK_SPRITE, PROJECTILE_SHIELD_SPRITE,
-    LIFE_NORMAL_SPRITE, LIFE_ATTACK_SPRITE, LIFE_SHIELD_SPRITE,
+    PROJECTILE_ATTACK_SPRITE,
     METEOR_BIG_SPRITE, METEOR_MED_SPRITE, METEOR_SML_SPRITE,
     STAR1_SPRITE, STAR2_SPRITE, STAR3_SPRITE, SPEED_SPRITE, UFO_SPRITE,
-    UFO_BLUE_SPRITE, UFO_GREEN_SPRITE, UFO_YELLOW_SPRITE,
+    UFO_BLUE_SPRITE, UFO_GREEN_SPRITE, UFO_YELLOW_SPRITE, CROSSHAIR_SPRITE,
 };
+use input::actions::ActionMap;
+use player::{ship::SHIPS, skin::{SkinProgress, SKINS}};
+use upgrades::hangar::MetaProgress;
+use utils::{achievements::AchievementProgress, highscores::HighScores, savegame::SavedRun};
 use state::states::{
-    GameStates, BaseStatePlugin, ShipSelectStatePlugin, StartGameStatePlugin, CountdownStatePlugin,
-    InGameStatePlugin, LevelCompleteStatePlugin, UpgradeSelectionStatePlugin,
+    GameStates, BaseStatePlugin, ModeSelectStatePlugin, ShipSelectStatePlugin, StartGameStatePlugin,
+    CountdownStatePlugin, InGameStatePlugin, LevelCompleteStatePlugin, UpgradeSelectionStatePlugin,
     GameOverStatePlugin,
 };
 
@@ -31,6 +36,7 @@ mod effects;
 mod resources;
 mod state;
 mod events;
+mod input;
 mod ui;
 mod upgrades;
 mod utils;
@@ -57,6 +63,7 @@ fn main() {
         .add_plugins(TweeningPlugin)
         // State machine
         .add_plugins(BaseStatePlugin)
+        .add_plugins(ModeSelectStatePlugin)
         .add_plugins(ShipSelectStatePlugin)
         .add_plugins(StartGameStatePlugin)
         .add_plugins(CountdownStatePlugin)
@@ -67,14 +74,34 @@ fn main() {
         // Game systems
         .add_plugins(player::player::PlayerPlugin)
         .add_plugins(player::ship::ShipPlugin)
+        .add_plugins(player::skin::SkinPlugin)
+        .add_plugins(player::ability::AbilityPlugin)
+        .add_plugins(player::hyperspace::HyperspacePlugin)
+        .add_plugins(player::tractor::TractorPlugin)
+        .add_plugins(input::actions::ActionMapPlugin)
+        .add_plugins(input::gamepad::GamepadPlugin)
+        .add_plugins(input::twin_stick::TwinStickPlugin)
+        .add_plugins(input::touch::TouchPlugin)
         .add_plugins(common::collision::CollisionPlugin)
+        .add_plugins(common::physics::PhysicsPlugin)
         .add_plugins(objects::meteor::MeteorPlugin)
         .add_plugins(objects::projectile::ProjectilePlugin)
+        .add_plugins(objects::weapon::WeaponPlugin)
         .add_plugins(objects::powerup::PowerUpPlugin)
+        .add_plugins(objects::secondary::SecondaryPlugin)
+        .add_plugins(objects::drone::DronePlugin)
         .add_plugins(objects::ufo::UfoPlugin)
+        .add_plugins(objects::boss::BossPlugin)
+        .add_plugins(objects::elite::ElitePlugin)
+        .add_plugins(objects::survival::SurvivalPlugin)
         .add_plugins(events::events::EventsPlugin)
+        .add_plugins(utils::highscores::HighScorePlugin)
+        .add_plugins(utils::savegame::SaveGamePlugin)
+        .add_plugins(utils::run_stats::RunStatsPlugin)
+        .add_plugins(utils::achievements::AchievementPlugin)
         .add_plugins(ui::ui::UIPlugin)
         .add_plugins(upgrades::upgrades::UpgradePlugin)
+        .add_plugins(upgrades::hangar::HangarPlugin)
         // Visual polish
         .add_plugins(background::BackgroundPlugin)
         .add_plugins(effects::particle::ParticlePlugin)
@@ -118,9 +145,21 @@ fn startup_system(
 
     // Preload all sprite assets
     commands.insert_resource(GameSprites {
-        ship_type_normal:  asset_server.load(SHIP_NORMAL_SPRITE),
-        ship_type_attack:  asset_server.load(SHIP_ATTACK_SPRITE),
-        ship_type_shield:  asset_server.load(SHIP_SHIELD_SPRITE),
+        ships:                 SHIPS
+                                   .iter()
+                                   .map(|ship| ShipSprites {
+                                       hull:       asset_server.load(ship.hull_sprite),
+                                       life:       asset_server.load(ship.life_sprite),
+                                       projectile: asset_server.load(ship.projectile_sprite),
+                                   })
+                                   .collect(),
+        skins:                 SKINS
+                                   .iter()
+                                   .map(|skin| SkinSprites {
+                                       hull:       skin.hull.map(|path| asset_server.load(path)),
+                                       projectile: skin.laser.map(|path| asset_server.load(path)),
+                                   })
+                                   .collect(),
         // HP powerup tiers
         powerup_hp:            asset_server.load(POWERUP_HP_SPRITE),
         powerup_hp_green:      asset_server.load(POWERUP_HP_SPRITE_GREEN),
@@ -133,14 +172,12 @@ fn startup_system(
         powerup_shield:        asset_server.load(POWERUP_SHIELD_SPRITE),
         powerup_shield_green:  asset_server.load(POWERUP_SHIELD_SPRITE_GREEN),
         powerup_shield_red:    asset_server.load(POWERUP_SHIELD_SPRITE_RED),
+        // Ammo powerup tiers
+        powerup_ammo:          asset_server.load(POWERUP_AMMO_SPRITE),
+        powerup_ammo_green:    asset_server.load(POWERUP_AMMO_SPRITE_GREEN),
+        powerup_ammo_red:      asset_server.load(POWERUP_AMMO_SPRITE_RED),
         // Shield visual effect
         shield_effect:         asset_server.load(SHIELD_EFFECT_SPRITE),
-        projectile_normal: asset_server.load(PROJECTILE_NORMAL_SPRITE),
-        projectile_attack: asset_server.load(PROJECTILE_ATTACK_SPRITE),
-        projectile_shield: asset_server.load(PROJECTILE_SHIELD_SPRITE),
-        life_normal:       asset_server.load(LIFE_NORMAL_SPRITE),
-        life_attack:       asset_server.load(LIFE_ATTACK_SPRITE),
-        life_shield:       asset_server.load(LIFE_SHIELD_SPRITE),
         meteor_big:        asset_server.load(METEOR_BIG_SPRITE),
         meteor_med:        asset_server.load(METEOR_MED_SPRITE),
         meteor_sml:        asset_server.load(METEOR_SML_SPRITE),
@@ -157,6 +194,9 @@ fn startup_system(
         ufo_blue:          asset_server.load(UFO_BLUE_SPRITE),
         ufo_green:         asset_server.load(UFO_GREEN_SPRITE),
         ufo_yellow:        asset_server.load(UFO_YELLOW_SPRITE),
+        ufo_projectile:    asset_server.load(PROJECTILE_ATTACK_SPRITE),
+        // UI
+        crosshair:         asset_server.load(CROSSHAIR_SPRITE),
         // Font
         font:              asset_server.load("fonts/screen-diags-font.ttf"),
     });
@@ -170,5 +210,14 @@ fn startup_system(
     commands.insert_resource(UpgradeSelectionState::default());
     commands.insert_resource(ShipSelectState::default());
     commands.insert_resource(PlayerBuff::default());
+    commands.insert_resource(SecondaryAmmo::default());
+    commands.insert_resource(RunRng::default());
     commands.insert_resource(IsPaused::default());
+    commands.insert_resource(GameSettings::default());
+    commands.insert_resource(ActionMap::load());
+    commands.insert_resource(SkinProgress::load());
+    commands.insert_resource(HighScores::load());
+    commands.insert_resource(SavedRun::load());
+    commands.insert_resource(MetaProgress::load());
+    commands.insert_resource(AchievementProgress::load());
 }
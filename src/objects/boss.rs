@@ -0,0 +1,408 @@
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+use std::f32::consts::{PI, TAU};
+
+use lib::{
+    MeteorSizeType, MeteorMaterial, BOSS_BASE_HP, BOSS_HP_PER_ENCOUNTER, BOSS_PHASE_THRESHOLDS,
+    BOSS_WEAK_POINT_HP, BOSS_MAX_UFO_ADDS, BOSS_MAX_METEOR_ADDS,
+};
+use crate::{
+    common::common_components::HitBoxSize,
+    objects::{
+        meteor::{spawn_meteor, MeteorAssets, MeteorComponent, MeteorSpawn},
+        ufo::{fire_ufo_shot, spawn_ufo_typed, UfoComponent, UfoType},
+    },
+    player::player::PlayerComponent,
+    resources::{GameSprites, IsPaused, LevelResource, WindowSize},
+    state::states::GameStates,
+    utils::cleanup::CleanUpOnLevelEnd,
+};
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BossType {
+    /// Odd encounters – a UFO carrier that sways along the top of the screen
+    /// and launches UFO adds.
+    Mothership,
+    /// Even encounters – a giant asteroid that drifts around the arena and
+    /// sheds meteor adds.
+    Colossus,
+}
+
+impl BossType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossType::Mothership => "MOTHERSHIP",
+            BossType::Colossus   => "COLOSSUS",
+        }
+    }
+}
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+#[derive(Component)]
+pub struct BossComponent {
+    pub boss_type:     BossType,
+    pub hp:            i32,
+    pub max_hp:        i32,
+    /// 1–3, advanced by HP thresholds.
+    pub phase:         u32,
+    pub shoot_timer:   Timer,
+    pub add_timer:     Timer,
+    /// Rotating emitter angle used by the spiral pattern.
+    pub pattern_angle: f32,
+    /// Counts volleys so phase 3 can interleave patterns.
+    pub volley:        u32,
+    pub move_phase:    f32,
+    pub velocity:      Vec2,
+    pub rotation:      f32,
+    pub base_color:    Color,
+}
+
+impl BossComponent {
+    pub fn hp_fraction(&self) -> f32 {
+        (self.hp.max(0) as f32 / self.max_hp as f32).clamp(0.0, 1.0)
+    }
+
+    /// Phase implied by the current HP fraction.
+    fn phase_for_hp(&self) -> u32 {
+        let frac = self.hp_fraction();
+        if frac <= BOSS_PHASE_THRESHOLDS[1] {
+            3
+        } else if frac <= BOSS_PHASE_THRESHOLDS[0] {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+/// Breakable hot-spot on the boss hull. Hits here deal multiplied damage to
+/// the boss until the weak point itself is destroyed.
+#[derive(Component)]
+pub struct BossWeakPoint {
+    pub offset: Vec2,
+    pub hp:     i32,
+}
+
+#[derive(Component)]
+pub struct BossHitFlash(pub Timer);
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(GameStates::InGame), spawn_boss_for_level)
+            .add_systems(
+                Update,
+                (
+                    boss_movement_system,
+                    boss_phase_system,
+                    boss_attack_system,
+                    boss_spawn_adds_system,
+                    boss_weak_point_follow_system,
+                    boss_hit_flash_system,
+                )
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            );
+    }
+}
+
+// ── Spawn ─────────────────────────────────────────────────────────────────────
+
+fn spawn_boss_for_level(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    wdw_size:     Res<WindowSize>,
+    level:        Res<LevelResource>,
+) {
+    if !level.is_boss_level() { return; }
+
+    let encounter = level.boss_encounter();
+    let boss_type = if encounter % 2 == 1 { BossType::Mothership } else { BossType::Colossus };
+    let max_hp    = BOSS_BASE_HP + (encounter as i32 - 1) * BOSS_HP_PER_ENCOUNTER;
+
+    let (texture, scale, hitbox, base_color, start, weak_offsets) = match boss_type {
+        BossType::Mothership => (
+            game_sprites.ufo_yellow.clone(),
+            2.4_f32,
+            Vec2::new(85.0, 60.0),
+            Color::rgb(1.0, 0.9, 0.75),
+            Vec3::new(0.0, wdw_size.h / 2.0 + 150.0, 2.0),
+            vec![Vec2::new(-72.0, -38.0), Vec2::new(0.0, -62.0), Vec2::new(72.0, -38.0)],
+        ),
+        BossType::Colossus => (
+            game_sprites.meteor_big.clone(),
+            2.6,
+            Vec2::new(90.0, 76.0),
+            Color::rgb(0.85, 0.6, 0.45),
+            Vec3::new(0.0, wdw_size.h / 2.0 + 150.0, 1.5),
+            vec![Vec2::new(-95.0, 10.0), Vec2::new(95.0, -10.0)],
+        ),
+    };
+
+    commands.spawn((
+        SpriteBundle {
+            texture,
+            transform: Transform {
+                translation: start,
+                scale: Vec3::splat(scale),
+                ..default()
+            },
+            sprite: Sprite { color: base_color, ..default() },
+            ..default()
+        },
+        Name::new(format!("Boss {:?}", boss_type)),
+        BossComponent {
+            boss_type,
+            hp: max_hp,
+            max_hp,
+            phase: 1,
+            shoot_timer:   Timer::from_seconds(2.0, TimerMode::Repeating),
+            add_timer:     Timer::from_seconds(7.0, TimerMode::Repeating),
+            pattern_angle: 0.0,
+            volley:        0,
+            move_phase:    0.0,
+            velocity:      Vec2::new(45.0, -35.0),
+            rotation:      0.0,
+            base_color,
+        },
+        HitBoxSize(hitbox),
+        CleanUpOnLevelEnd,
+    ));
+
+    for offset in weak_offsets {
+        commands.spawn((
+            SpriteBundle {
+                texture: game_sprites.star1.clone(),
+                transform: Transform::from_translation(start + offset.extend(0.5)),
+                sprite: Sprite {
+                    color:       Color::rgb(1.0, 0.3, 0.3),
+                    custom_size: Some(Vec2::splat(34.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("Boss Weak Point"),
+            BossWeakPoint { offset, hp: BOSS_WEAK_POINT_HP },
+            HitBoxSize(Vec2::splat(34.0)),
+            CleanUpOnLevelEnd,
+        ));
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+fn boss_movement_system(
+    time:      Res<Time>,
+    wdw_size:  Res<WindowSize>,
+    mut query: Query<(&mut BossComponent, &mut Transform)>,
+) {
+    let dt = time.delta_seconds();
+    let hw = wdw_size.w / 2.0;
+    let hh = wdw_size.h / 2.0;
+
+    for (mut boss, mut tf) in &mut query {
+        boss.move_phase += dt;
+        // Later phases move faster.
+        let speed_mult = 1.0 + (boss.phase - 1) as f32 * 0.35;
+
+        match boss.boss_type {
+            BossType::Mothership => {
+                // Descend to the hover line, then sway left/right.
+                let hover_y = hh - 150.0;
+                if tf.translation.y > hover_y {
+                    tf.translation.y = (tf.translation.y - 90.0 * dt).max(hover_y);
+                }
+                tf.translation.x = (boss.move_phase * 0.45 * speed_mult).sin() * hw * 0.55;
+            }
+            BossType::Colossus => {
+                // Drift and bounce inside the play area while slowly spinning.
+                let margin = 140.0;
+                let vel    = boss.velocity * speed_mult;
+                tf.translation.x += vel.x * dt;
+                tf.translation.y += vel.y * dt;
+                if tf.translation.x.abs() > hw - margin {
+                    tf.translation.x = tf.translation.x.clamp(-(hw - margin), hw - margin);
+                    boss.velocity.x = -boss.velocity.x;
+                }
+                if tf.translation.y < -(hh - margin) || (tf.translation.y > hh - margin && boss.velocity.y > 0.0) {
+                    boss.velocity.y = -boss.velocity.y;
+                }
+                boss.rotation += 0.25 * speed_mult * dt;
+                tf.rotation = Quat::from_rotation_z(boss.rotation);
+            }
+        }
+    }
+}
+
+/// Advances the boss phase when HP crosses a threshold and retunes its timers.
+fn boss_phase_system(mut query: Query<&mut BossComponent>) {
+    for mut boss in &mut query {
+        let phase = boss.phase_for_hp();
+        if phase <= boss.phase { continue; }
+
+        boss.phase  = phase;
+        boss.volley = 0;
+        let (shoot_secs, add_secs) = match phase {
+            2 => (0.16, 5.0),
+            _ => (0.75, 3.5),
+        };
+        boss.shoot_timer = Timer::from_seconds(shoot_secs, TimerMode::Repeating);
+        boss.add_timer   = Timer::from_seconds(add_secs,   TimerMode::Repeating);
+    }
+}
+
+/// Phase 1: radial ring.  Phase 2: rotating spiral.
+/// Phase 3: aimed spread, with a ring every third volley.
+fn boss_attack_system(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    time:         Res<Time>,
+    player_q:     Query<&Transform, With<PlayerComponent>>,
+    mut boss_q:   Query<(&Transform, &mut BossComponent)>,
+) {
+    let player_pos = player_q.get_single().map(|tf| tf.translation.truncate()).ok();
+
+    for (tf, mut boss) in &mut boss_q {
+        boss.shoot_timer.tick(time.delta());
+        if !boss.shoot_timer.just_finished() { continue; }
+
+        let origin = tf.translation;
+        boss.volley += 1;
+
+        match boss.phase {
+            1 => {
+                let count = 10;
+                let start = boss.pattern_angle;
+                for i in 0..count {
+                    let a = start + i as f32 * TAU / count as f32;
+                    fire_ufo_shot(&mut commands, &game_sprites, origin, Vec2::new(a.cos(), a.sin()), 3.5, Color::rgb(1.0, 0.8, 0.3));
+                }
+                boss.pattern_angle += PI / count as f32;
+            }
+            2 => {
+                for arm in 0..2 {
+                    let a = boss.pattern_angle + arm as f32 * PI;
+                    fire_ufo_shot(&mut commands, &game_sprites, origin, Vec2::new(a.cos(), a.sin()), 4.0, Color::rgb(1.0, 0.5, 0.2));
+                }
+                boss.pattern_angle += 0.35;
+            }
+            _ => {
+                if let Some(target) = player_pos {
+                    let to_player = (target - origin.truncate()).normalize_or_zero();
+                    if to_player != Vec2::ZERO {
+                        for spread in [-0.4_f32, -0.2, 0.0, 0.2, 0.4] {
+                            let dir = Vec2::from_angle(spread).rotate(to_player);
+                            fire_ufo_shot(&mut commands, &game_sprites, origin, dir, 5.5, Color::rgb(1.0, 0.25, 0.25));
+                        }
+                    }
+                }
+                if boss.volley % 3 == 0 {
+                    let count = 14;
+                    for i in 0..count {
+                        let a = boss.pattern_angle + i as f32 * TAU / count as f32;
+                        fire_ufo_shot(&mut commands, &game_sprites, origin, Vec2::new(a.cos(), a.sin()), 3.0, Color::rgb(1.0, 0.4, 0.8));
+                    }
+                    boss.pattern_angle += 0.2;
+                }
+            }
+        }
+    }
+}
+
+/// Mothership launches UFOs, Colossus sheds meteors. Capped so adds never flood the arena.
+fn boss_spawn_adds_system(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    mut assets:   MeteorAssets,
+    wdw_size:     Res<WindowSize>,
+    time:         Res<Time>,
+    ufo_q:        Query<(), With<UfoComponent>>,
+    meteor_q:     Query<(), With<MeteorComponent>>,
+    mut boss_q:   Query<(&Transform, &mut BossComponent)>,
+) {
+    let mut rng = thread_rng();
+
+    for (tf, mut boss) in &mut boss_q {
+        boss.add_timer.tick(time.delta());
+        if !boss.add_timer.just_finished() { continue; }
+
+        match boss.boss_type {
+            BossType::Mothership => {
+                if ufo_q.iter().count() >= BOSS_MAX_UFO_ADDS { continue; }
+                let ufo_type = if boss.phase >= 3 && rng.gen_bool(0.5) { UfoType::Bomber } else { UfoType::Scout };
+                spawn_ufo_typed(&mut commands, &game_sprites, &wdw_size, ufo_type);
+            }
+            BossType::Colossus => {
+                if meteor_q.iter().count() >= BOSS_MAX_METEOR_ADDS { continue; }
+                let count = boss.phase + 1;
+                for _ in 0..count {
+                    let angle = rng.gen_range(0.0..TAU);
+                    let dir   = Vec2::new(angle.cos(), angle.sin());
+                    let pos   = tf.translation.truncate() + dir * 110.0;
+                    spawn_meteor(
+                        &mut commands,
+                        &game_sprites,
+                        &mut assets,
+                        MeteorSpawn {
+                            size:           MeteorSizeType::Medium,
+                            material:       MeteorMaterial::Rock,
+                            position:       pos,
+                            rotation:       rng.gen_range(-1.0_f32..1.0),
+                            rotation_speed: rng.gen_range(-0.05_f32..0.05),
+                            velocity:       dir * rng.gen_range(1.0_f32..2.0),
+                            hp:             1,
+                            outline:        None,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Keeps weak points pinned to the boss hull (rotating with it).
+fn boss_weak_point_follow_system(
+    time:       Res<Time>,
+    boss_q:     Query<(&Transform, &BossComponent), Without<BossWeakPoint>>,
+    mut weak_q: Query<(&BossWeakPoint, &mut Transform, &mut Sprite), Without<BossComponent>>,
+) {
+    let Ok((boss_tf, boss)) = boss_q.get_single() else { return };
+    let pulse = 0.65 + 0.35 * (time.elapsed_seconds() * 6.0).sin();
+
+    for (weak, mut tf, mut sprite) in &mut weak_q {
+        let offset = Vec2::from_angle(boss.rotation).rotate(weak.offset);
+        tf.translation.x = boss_tf.translation.x + offset.x;
+        tf.translation.y = boss_tf.translation.y + offset.y;
+        tf.translation.z = boss_tf.translation.z + 0.5;
+        tf.rotation = Quat::from_rotation_z(time.elapsed_seconds() * 2.0);
+        sprite.color = Color::rgb(1.0, 0.3 * pulse, 0.3 * pulse);
+    }
+}
+
+fn boss_hit_flash_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    mut query:    Query<(Entity, &mut Sprite, &mut BossHitFlash, &BossComponent)>,
+) {
+    for (entity, mut sprite, mut flash, boss) in &mut query {
+        flash.0.tick(time.delta());
+        let t = flash.0.percent();
+        let [br, bg, bb, _] = boss.base_color.as_rgba_f32();
+        sprite.color = Color::rgb(
+            1.0 - t * (1.0 - br),
+            1.0 - t * (1.0 - bg),
+            1.0 - t * (1.0 - bb),
+        );
+        if flash.0.just_finished() {
+            sprite.color = boss.base_color;
+            commands.entity(entity).remove::<BossHitFlash>();
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use std::f32::consts::{PI, TAU};
+use bevy::prelude::*;
+
+use lib::{
+    DRONE_HIT_INVULN, DRONE_ORBIT_RADIUS, DRONE_ORBIT_SPEED, DRONE_PROJECTILE_SPEED, DRONE_RADIUS, DRONE_RANGE,
+    DRONE_RESPAWN_TIME, PROJECTILE_SIZE, SPRITE_SCALE,
+};
+use crate::{
+    common::common_components::{HitBoxSize, Position, Velocity},
+    objects::{
+        boss::BossComponent,
+        meteor::MeteorComponent,
+        projectile::{ProjectileComponent, ProjectileDespawnComponent},
+        ufo::UfoComponent,
+    },
+    player::{player::PlayerComponent, ship::ShipComponent},
+    resources::{GameSprites, IsPaused, PlayerUpgrades},
+    state::states::GameStates,
+    utils::cleanup::{CleanUpOnGameOver, CleanUpOnLevelEnd},
+};
+
+const DRONE_COLOR:        Color = Color::rgb(0.75, 0.85, 1.0);
+const DRONE_SHIELD_COLOR: Color = Color::rgba(0.4, 0.8, 1.0, 0.6);
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// A drone orbiting the player, one per Drone upgrade level. Drawn with
+/// gizmos. A destroyed drone keeps its entity and slot and sits out
+/// `DRONE_RESPAWN_TIME` before coming back.
+#[derive(Component)]
+pub struct Drone {
+    /// Position in the orbit, 0 up to the drone count.
+    slot:           u32,
+    /// Seconds until the next shot.
+    cooldown:       f32,
+    /// Seconds until it respawns; 0 while it's up.
+    down:           f32,
+    /// Seconds of grace left after the plating soaks a hit.
+    invuln:         f32,
+    /// Drone Plating has absorbed a hit this life.
+    plating_broken: bool,
+}
+
+impl Drone {
+    fn new(slot: u32) -> Self {
+        Drone { slot, cooldown: 0.0, down: 0.0, invuln: 0.0, plating_broken: false }
+    }
+
+    fn active(&self) -> bool {
+        self.down <= 0.0
+    }
+
+    fn shielded(&self, upgrades: &PlayerUpgrades) -> bool {
+        upgrades.drone_plating && !self.plating_broken
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Drones from the Drone upgrade line: they orbit the ship, fire at the
+/// nearest target in range on their own cooldown, and drop out for a while
+/// when a meteor hits them.
+pub struct DronePlugin;
+
+impl Plugin for DronePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    drone_sync_system,
+                    drone_orbit_system,
+                    drone_fire_system,
+                    drone_hit_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            // Drones stay visible behind the pause overlay.
+            .add_systems(Update, drone_draw_system.run_if(in_state(GameStates::InGame)));
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// Spawns a drone for each Drone level the player doesn't have one for yet.
+fn drone_sync_system(
+    mut commands: Commands,
+    upgrades:     Res<PlayerUpgrades>,
+    player_q:     Query<&Position, With<PlayerComponent>>,
+    drone_q:      Query<&Drone>,
+) {
+    let Ok(player_pos) = player_q.get_single() else { return };
+    let count = drone_q.iter().count() as u32;
+    for slot in count..upgrades.drones {
+        commands.spawn((
+            Drone::new(slot),
+            Position(player_pos.0),
+            CleanUpOnGameOver,
+            Name::new("Drone"),
+        ));
+    }
+}
+
+/// Spaces the drones evenly around the ship and turns the ring over time.
+fn drone_orbit_system(
+    time:        Res<Time>,
+    upgrades:    Res<PlayerUpgrades>,
+    player_q:    Query<&Position, (With<PlayerComponent>, Without<Drone>)>,
+    mut drone_q: Query<(&mut Drone, &mut Position)>,
+) {
+    let Ok(player_pos) = player_q.get_single() else { return };
+    let spin = time.elapsed_seconds() * DRONE_ORBIT_SPEED;
+    let step = TAU / upgrades.drones.max(1) as f32;
+
+    for (mut drone, mut pos) in &mut drone_q {
+        drone.down   = (drone.down - time.delta_seconds()).max(0.0);
+        drone.invuln = (drone.invuln - time.delta_seconds()).max(0.0);
+        pos.0 = player_pos.0 + Vec2::from_angle(spin + drone.slot as f32 * step) * DRONE_ORBIT_RADIUS;
+    }
+}
+
+/// Each active drone fires at the nearest meteor, UFO or boss within
+/// `DRONE_RANGE`. Shots carry Heavy Rounds and Piercing Rounds.
+fn drone_fire_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    game_sprites: Res<GameSprites>,
+    upgrades:     Res<PlayerUpgrades>,
+    ship_q:       Query<&ShipComponent, With<PlayerComponent>>,
+    mut drone_q:  Query<(&mut Drone, &Position)>,
+    target_q:     Query<&Position, Or<(With<MeteorComponent>, With<UfoComponent>, With<BossComponent>)>>,
+) {
+    let Ok(ship) = ship_q.get_single() else { return };
+
+    for (mut drone, pos) in &mut drone_q {
+        drone.cooldown = (drone.cooldown - time.delta_seconds()).max(0.0);
+        if !drone.active() || drone.cooldown > 0.0 {
+            continue;
+        }
+        let nearest = target_q
+            .iter()
+            .map(|t| t.0)
+            .filter(|t| t.distance(pos.0) <= DRONE_RANGE)
+            .min_by(|a, b| a.distance_squared(pos.0).total_cmp(&b.distance_squared(pos.0)));
+        let Some(target) = nearest else { continue };
+
+        let direction = (target - pos.0).normalize_or_zero();
+        commands.spawn((
+            SpriteBundle {
+                texture: game_sprites.projectile(ship),
+                sprite: Sprite { color: ship.def().color, ..default() },
+                transform: Transform {
+                    translation: pos.0.extend(5.0),
+                    scale:       Vec3::splat(SPRITE_SCALE * 0.7),
+                    // Sprites point along +Y.
+                    rotation:    Quat::from_rotation_z(direction.y.atan2(direction.x) - PI / 2.0),
+                },
+                ..default()
+            },
+            ProjectileComponent {
+                damage:           upgrades.drone_damage(),
+                origin:           pos.0,
+                max_range:        DRONE_RANGE,
+                pierce_remaining: upgrades.pierce_count(),
+                hit_meteors:      Vec::new(),
+                kills:            0,
+            },
+            ProjectileDespawnComponent::default(),
+            HitBoxSize(PROJECTILE_SIZE * 0.7),
+            Velocity(direction * DRONE_PROJECTILE_SPEED),
+            Position(pos.0),
+            CleanUpOnLevelEnd,
+            Name::new("DroneProjectile"),
+        ));
+        drone.cooldown = upgrades.drone_cooldown();
+    }
+}
+
+/// A meteor touching an active drone knocks it out, unless Drone Plating
+/// takes the hit first.
+fn drone_hit_system(
+    upgrades:    Res<PlayerUpgrades>,
+    mut drone_q: Query<(&mut Drone, &Position)>,
+    meteor_q:    Query<(&Transform, &HitBoxSize), With<MeteorComponent>>,
+) {
+    for (mut drone, pos) in &mut drone_q {
+        if !drone.active() || drone.invuln > 0.0 {
+            continue;
+        }
+        let hit = meteor_q.iter().any(|(tf, hitbox)| {
+            let radius = (hitbox.0 * tf.scale.truncate()).min_element() / 2.0;
+            tf.translation.truncate().distance(pos.0) <= radius + DRONE_RADIUS
+        });
+        if !hit {
+            continue;
+        }
+        if drone.shielded(&upgrades) {
+            drone.plating_broken = true;
+            drone.invuln         = DRONE_HIT_INVULN;
+        } else {
+            drone.down           = DRONE_RESPAWN_TIME;
+            drone.cooldown       = 0.0;
+            drone.plating_broken = false;
+        }
+    }
+}
+
+/// Draws active drones as a diamond with a ring for intact plating. Downed
+/// drones show as a faint dot that fills back in as they respawn.
+fn drone_draw_system(
+    mut gizmos: Gizmos,
+    upgrades:   Res<PlayerUpgrades>,
+    drone_q:    Query<(&Drone, &Position)>,
+) {
+    for (drone, pos) in &drone_q {
+        if !drone.active() {
+            let progress = 1.0 - drone.down / DRONE_RESPAWN_TIME;
+            gizmos.circle_2d(pos.0, DRONE_RADIUS * 0.3 * (1.0 + progress), DRONE_COLOR.with_a(0.25));
+            continue;
+        }
+        let corners = [Vec2::Y, Vec2::X, Vec2::NEG_Y, Vec2::NEG_X].map(|c| pos.0 + c * DRONE_RADIUS);
+        for i in 0..corners.len() {
+            gizmos.line_2d(corners[i], corners[(i + 1) % corners.len()], DRONE_COLOR);
+        }
+        if drone.shielded(&upgrades) {
+            gizmos.circle_2d(pos.0, DRONE_RADIUS * 1.6, DRONE_SHIELD_COLOR);
+        } else if drone.invuln > 0.0 && (drone.invuln * 10.0).fract() < 0.5 {
+            gizmos.circle_2d(pos.0, DRONE_RADIUS * 1.6, DRONE_SHIELD_COLOR.with_a(0.2));
+        }
+    }
+}
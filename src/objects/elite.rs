@@ -0,0 +1,222 @@
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use lib::{elite_roll_for_level, ELITE_FAST_SPEED_MULT, ELITE_REGEN_INTERVAL, ELITE_SHIELD_COS};
+use crate::{
+    common::common_components::{HitBoxSize, Velocity},
+    objects::{meteor::MeteorComponent, ufo::UfoComponent},
+    resources::IsPaused,
+    state::states::GameStates,
+};
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EliteAffix {
+    /// Blocks bullets arriving from the front (direction of travel).
+    Shielded,
+    /// Heals 1 HP every `ELITE_REGEN_INTERVAL` seconds, up to its spawn HP.
+    Regenerating,
+    /// Meteor only – splits into extra fragments.
+    Splitting,
+    /// Moves at `ELITE_FAST_SPEED_MULT` times normal speed.
+    Fast,
+    /// Explodes on death, damaging the player and nearby enemies.
+    Volatile,
+}
+
+impl EliteAffix {
+    const METEOR: [EliteAffix; 5] = [
+        EliteAffix::Shielded, EliteAffix::Regenerating, EliteAffix::Splitting,
+        EliteAffix::Fast,     EliteAffix::Volatile,
+    ];
+    const UFO: [EliteAffix; 4] = [
+        EliteAffix::Shielded, EliteAffix::Regenerating, EliteAffix::Fast, EliteAffix::Volatile,
+    ];
+
+    /// Outline colour drawn around elites carrying this affix.
+    pub fn color(&self) -> Color {
+        match self {
+            EliteAffix::Shielded     => Color::rgb(0.4, 0.8, 1.0),
+            EliteAffix::Regenerating => Color::rgb(0.3, 1.0, 0.4),
+            EliteAffix::Splitting    => Color::rgb(0.8, 0.4, 1.0),
+            EliteAffix::Fast         => Color::rgb(1.0, 0.95, 0.3),
+            EliteAffix::Volatile     => Color::rgb(1.0, 0.35, 0.2),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EliteTarget {
+    Meteor,
+    Ufo,
+}
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+#[derive(Component)]
+pub struct EliteComponent {
+    pub affixes:     Vec<EliteAffix>,
+    /// HP at spawn; regeneration never heals past this.
+    pub max_hp:      i32,
+    pub regen_timer: Timer,
+    /// Unit vector the frontal shield faces; follows the direction of travel.
+    pub facing:      Vec2,
+}
+
+impl EliteComponent {
+    /// Rolls affixes for an enemy spawned on `level` using `ELITE_ROLL_TABLE`.
+    /// Returns `None` when the enemy is not elite.
+    pub fn roll(level: u32, target: EliteTarget, hp: i32, rng: &mut impl Rng) -> Option<Self> {
+        let (chance, max_affixes) = elite_roll_for_level(level);
+        if max_affixes == 0 || rng.gen::<f32>() >= chance {
+            return None;
+        }
+
+        let pool: &[EliteAffix] = match target {
+            EliteTarget::Meteor => &EliteAffix::METEOR,
+            EliteTarget::Ufo    => &EliteAffix::UFO,
+        };
+        let count   = rng.gen_range(1..=max_affixes.min(pool.len()));
+        let affixes = pool.choose_multiple(rng, count).copied().collect();
+
+        Some(EliteComponent {
+            affixes,
+            max_hp:      hp,
+            regen_timer: Timer::from_seconds(ELITE_REGEN_INTERVAL, TimerMode::Repeating),
+            facing:      Vec2::Y,
+        })
+    }
+
+    pub fn has(&self, affix: EliteAffix) -> bool {
+        self.affixes.contains(&affix)
+    }
+
+    /// True when a hit arriving from `hit_pos` lands on the frontal shield.
+    pub fn shield_blocks(&self, elite_pos: Vec2, hit_pos: Vec2) -> bool {
+        if !self.has(EliteAffix::Shielded) {
+            return false;
+        }
+        let from = (hit_pos - elite_pos).normalize_or_zero();
+        from.dot(self.facing) > ELITE_SHIELD_COS
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct ElitePlugin;
+
+impl Plugin for ElitePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    elite_fast_affix_system,
+                    elite_facing_system,
+                    elite_regen_system,
+                )
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            .add_systems(Update, elite_outline_system.run_if(in_state(GameStates::InGame)));
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// Applies the Fast speed boost once, when the affix is first attached.
+fn elite_fast_affix_system(
+    mut meteor_q: Query<(&EliteComponent, &mut Velocity), Added<EliteComponent>>,
+    mut ufo_q:    Query<(&EliteComponent, &mut UfoComponent), Added<EliteComponent>>,
+) {
+    for (elite, mut vel) in &mut meteor_q {
+        if elite.has(EliteAffix::Fast) {
+            vel.0 *= ELITE_FAST_SPEED_MULT;
+        }
+    }
+    for (elite, mut ufo) in &mut ufo_q {
+        if elite.has(EliteAffix::Fast) {
+            ufo.horizontal_vel *= ELITE_FAST_SPEED_MULT;
+            ufo.phase_speed    *= ELITE_FAST_SPEED_MULT;
+        }
+    }
+}
+
+/// Points the shield along the direction of travel.
+fn elite_facing_system(
+    mut query: Query<(&mut EliteComponent, Option<&Velocity>, Option<&UfoComponent>)>,
+) {
+    for (mut elite, vel, ufo) in &mut query {
+        let dir = match (vel, ufo) {
+            (Some(v), _)    => v.0.normalize_or_zero(),
+            (None, Some(u)) => Vec2::new(u.horizontal_vel.signum(), 0.0),
+            _               => Vec2::ZERO,
+        };
+        if dir != Vec2::ZERO {
+            elite.facing = dir;
+        }
+    }
+}
+
+fn elite_regen_system(
+    time:      Res<Time>,
+    mut query: Query<(&mut EliteComponent, Option<&mut MeteorComponent>, Option<&mut UfoComponent>)>,
+) {
+    for (mut elite, meteor, ufo) in &mut query {
+        if !elite.has(EliteAffix::Regenerating) { continue; }
+        elite.regen_timer.tick(time.delta());
+        if !elite.regen_timer.just_finished() { continue; }
+
+        let max_hp = elite.max_hp;
+        if let Some(mut m) = meteor {
+            m.health = (m.health + 1).min(max_hp);
+        }
+        if let Some(mut u) = ufo {
+            u.hp = (u.hp + 1).min(max_hp);
+        }
+    }
+}
+
+/// One pulsing ring per affix, plus an arc marking the shielded side.
+fn elite_outline_system(
+    mut gizmos: Gizmos,
+    time:       Res<Time>,
+    query:      Query<(&EliteComponent, &Transform, &HitBoxSize)>,
+) {
+    let pulse = 1.0 + 0.08 * (time.elapsed_seconds() * 5.0).sin();
+
+    for (elite, tf, hitbox) in &query {
+        let pos    = tf.translation.truncate();
+        let radius = (hitbox.0 * tf.scale.truncate()).max_element() * 0.5 + 6.0;
+
+        for (i, affix) in elite.affixes.iter().enumerate() {
+            gizmos.circle_2d(pos, (radius + i as f32 * 4.0) * pulse, affix.color());
+        }
+
+        if elite.has(EliteAffix::Shielded) {
+            let arc_radius = radius + elite.affixes.len() as f32 * 4.0 + 4.0;
+            let half_angle = ELITE_SHIELD_COS.acos();
+            let center     = elite.facing.y.atan2(elite.facing.x);
+            let points     = (0..=12).map(|i| {
+                let a = center - half_angle + (i as f32 / 12.0) * half_angle * 2.0;
+                pos + Vec2::new(a.cos(), a.sin()) * arc_radius
+            });
+            gizmos.linestrip_2d(points, EliteAffix::Shielded.color());
+        }
+    }
+}
+
+/// Spawn-site helper: rolls and attaches an elite component to `entity`.
+pub fn try_make_elite(
+    commands: &mut Commands,
+    entity:   Entity,
+    level:    u32,
+    target:   EliteTarget,
+    hp:       i32,
+    rng:      &mut impl Rng,
+) {
+    if let Some(elite) = EliteComponent::roll(level, target, hp, rng) {
+        commands.entity(entity).insert(elite);
+    }
+}
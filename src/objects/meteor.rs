@@ -1,13 +1,20 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*, sprite::MaterialMesh2dBundle};
 use rand::prelude::*;
 
-use lib::{MeteorSizeType, meteor_damage, meteor_size};
+use lib::{MeteorSizeType, MeteorMaterial, meteor_damage, meteor_material_tint, roll_meteor_material};
 use crate::{
-    common::common_components::{
-        RotationAngle, Velocity, Position, HitBoxSize,
-        CollisionDespawnableWithDamage, BoundsDespawnable,
+    common::{
+        common_components::{
+            RotationAngle, Velocity, Position, HitBoxSize,
+            CollisionDespawnableWithDamage, BoundsDespawnable,
+        },
+        convex::{convex_hull, ConvexCollider},
     },
-    resources::{GameSprites, WindowSize, LevelResource, PlayerUpgrades},
+    objects::{
+        elite::{try_make_elite, EliteTarget},
+        meteor_shape::{build_mesh, generate_outline, MeteorShape},
+    },
+    resources::{is_classic, GameSprites, WindowSize, LevelResource, PlayerUpgrades},
     state::states::GameStates,
     utils::{
         cleanup::CleanUpOnLevelEnd,
@@ -22,6 +29,7 @@ pub struct MeteorComponent {
     pub size:           MeteorSizeType,
     pub rotation_speed: f32,
     pub health: i32,
+    pub material:       MeteorMaterial,
 }
 
 /// Flashes white when a meteor is hit but not yet destroyed.
@@ -32,6 +40,29 @@ pub struct MeteorHitFlash(pub Timer);
 #[derive(Component)]
 pub struct MeteorBaseColor(pub Color);
 
+// ── Spawning ──────────────────────────────────────────────────────────────────
+
+/// Asset stores every meteor spawn needs for its mesh and tinted material.
+#[derive(SystemParam)]
+pub struct MeteorAssets<'w> {
+    pub meshes:    ResMut<'w, Assets<Mesh>>,
+    pub materials: ResMut<'w, Assets<ColorMaterial>>,
+}
+
+/// Parameters for `spawn_meteor`.
+pub struct MeteorSpawn {
+    pub size:           MeteorSizeType,
+    pub material:       MeteorMaterial,
+    pub position:       Vec2,
+    pub rotation:       f32,
+    pub rotation_speed: f32,
+    pub velocity:       Vec2,
+    pub hp:             i32,
+    /// Outline to reuse, e.g. a piece of a split parent. `None` generates a
+    /// fresh one from a random seed.
+    pub outline:        Option<Vec<Vec2>>,
+}
+
 // ── Plugin ────────────────────────────────────────────────────────────────────
 
 pub struct MeteorPlugin;
@@ -43,7 +74,7 @@ impl Plugin for MeteorPlugin {
             .add_systems(Update, meteor_hit_flash_system)
             .add_systems(
                 Update,
-                check_level_complete_system.run_if(in_state(GameStates::InGame)),
+                check_level_complete_system.run_if(in_state(GameStates::InGame)).run_if(is_classic),
             );
     }
 }
@@ -54,48 +85,76 @@ impl Plugin for MeteorPlugin {
 pub fn spawn_level_asteroids(
     mut commands:    Commands,
     game_sprites:    Res<GameSprites>,
+    mut assets:      MeteorAssets,
     wdw_size:        Res<WindowSize>,
     mut level:       ResMut<LevelResource>,
     upgrades:        Res<PlayerUpgrades>,
 ) {
-    let count  = level.asteroids_for_level();
-    let hp     = level.asteroid_hp_for_level();
-    let center = Vec2::ZERO;
+    // Boss levels have no pre-spawned field; the boss brings its own adds.
+    if level.is_boss_level() {
+        level.total_asteroids_spawned = 0;
+        return;
+    }
 
+    let count = level.asteroids_for_level();
     let mut rng = thread_rng();
-    let max_dist = calculate_max_spawn_distance(Vec2::new(wdw_size.w, wdw_size.h));
 
     for _ in 0..count {
-        let angle   = rng.gen_range(0.0_f32..360.0).to_radians();
-        let (sy, sx) = angle.sin_cos();
-        let position = Vec2::new(sx * max_dist, sy * max_dist);
-
-        let rotation       = rng.gen_range(-0.05_f32..0.05);
-        let rotation_speed = rng.gen_range(-0.03_f32..0.03);
-        let base_velocity  = get_angle_to_target(center, position);
-
-        // Speed scales with level (+8% per level, capped at 2.0x)
-        let speed_scale = (1.0 + (level.current as f32 - 1.0) * 0.08).min(2.0);
-        let velocity = if upgrades.overclock {
-            base_velocity * lib::OVERCLOCK_SPEED_MULT
-        } else {
-            base_velocity * speed_scale
-        };
-
-        spawn_meteor(
-            &mut commands,
-            &game_sprites,
-            MeteorSizeType::Large,
-            Vec3::new(position.x, position.y, 1.0),
+        spawn_incoming_meteor(&mut commands, &game_sprites, &mut assets, &wdw_size, &level, &upgrades, &mut rng);
+    }
+
+    level.total_asteroids_spawned = count;
+}
+
+/// Spawns one large meteor just off-screen, heading for the centre, with the
+/// HP, speed, material and elite roll of the current level. Survival streams
+/// these in for as long as the run lasts.
+pub fn spawn_incoming_meteor(
+    commands:     &mut Commands,
+    game_sprites: &GameSprites,
+    assets:       &mut MeteorAssets,
+    wdw_size:     &WindowSize,
+    level:        &LevelResource,
+    upgrades:     &PlayerUpgrades,
+    rng:          &mut ThreadRng,
+) {
+    let hp       = level.asteroid_hp_for_level();
+    let max_dist = calculate_max_spawn_distance(Vec2::new(wdw_size.w, wdw_size.h));
+
+    let angle    = rng.gen_range(0.0_f32..360.0).to_radians();
+    let (sy, sx) = angle.sin_cos();
+    let position = Vec2::new(sx * max_dist, sy * max_dist);
+
+    let rotation       = rng.gen_range(-0.05_f32..0.05);
+    let rotation_speed = rng.gen_range(-0.03_f32..0.03);
+    let base_velocity  = get_angle_to_target(Vec2::ZERO, position);
+
+    // Speed scales with level (+8% per level, capped at 2.0x)
+    let speed_scale = (1.0 + (level.current as f32 - 1.0) * 0.08).min(2.0);
+    let velocity = if upgrades.overclock {
+        base_velocity * lib::OVERCLOCK_SPEED_MULT
+    } else {
+        base_velocity * speed_scale
+    };
+
+    let material = roll_meteor_material(level.current, rng);
+
+    let meteor = spawn_meteor(
+        commands,
+        game_sprites,
+        assets,
+        MeteorSpawn {
+            size: MeteorSizeType::Large,
+            material,
             position,
             rotation,
             rotation_speed,
             velocity,
             hp,
-        );
-    }
-
-    level.total_asteroids_spawned = count;
+            outline: None,
+        },
+    );
+    try_make_elite(commands, meteor, level.current, EliteTarget::Meteor, hp, rng);
 }
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
@@ -109,57 +168,68 @@ fn hp_color(hp: i32) -> Color {
     }
 }
 
-/// Spawns a single meteor entity.
+/// HP colour multiplied by the material tint, so both stay readable.
+fn meteor_color(hp: i32, material: MeteorMaterial) -> Color {
+    let [hr, hg, hb, _] = hp_color(hp).as_rgba_f32();
+    let [mr, mg, mb, _] = meteor_material_tint(material).as_rgba_f32();
+    Color::rgb(hr * mr, hg * mg, hb * mb)
+}
+
+/// Spawns a single meteor entity and returns it.
+/// The meteor is a textured polygon mesh; its convex hull doubles as the
+/// collider, and `HitBoxSize` is the outline's bounding square.
 /// `hp` is only meaningful for Large meteors at level start; fragment children
 /// always receive `hp = 1` from the collision system.
 pub fn spawn_meteor(
-    commands:       &mut Commands,
-    game_sprites:   &GameSprites,
-    size:           MeteorSizeType,
-    spawn_position: Vec3,
-    position:       Vec2,
-    rotation:       f32,
-    rotation_speed: f32,
-    velocity:       Vec2,
-    hp:             i32,
-) {
-    let (name, sprite) = match size {
+    commands:     &mut Commands,
+    game_sprites: &GameSprites,
+    assets:       &mut MeteorAssets,
+    spawn:        MeteorSpawn,
+) -> Entity {
+    let (name, texture) = match spawn.size {
         MeteorSizeType::Large  => ("Meteor Large",  game_sprites.meteor_big.clone()),
         MeteorSizeType::Medium => ("Meteor Medium", game_sprites.meteor_med.clone()),
         MeteorSizeType::Small  => ("Meteor Small",  game_sprites.meteor_sml.clone()),
     };
 
-    let base_color = hp_color(hp);
+    let outline = spawn
+        .outline
+        .unwrap_or_else(|| generate_outline(thread_rng().gen(), spawn.size));
+    let shape      = MeteorShape { outline };
+    let base_color = meteor_color(spawn.hp, spawn.material);
 
     commands
-        .spawn(SpriteBundle {
-            texture: sprite,
+        .spawn(MaterialMesh2dBundle {
+            mesh:      assets.meshes.add(build_mesh(&shape.outline)).into(),
+            material:  assets.materials.add(ColorMaterial {
+                color:   base_color,
+                texture: Some(texture),
+            }),
             transform: Transform {
-                translation: spawn_position,
-                rotation:    Quat::from_rotation_z(rotation),
+                translation: spawn.position.extend(1.0),
+                rotation:    Quat::from_rotation_z(spawn.rotation),
                 scale:       Vec3::ONE,
-                ..default()
-            },
-            sprite: Sprite {
-                color: base_color,
-                ..default()
             },
             ..default()
         })
         .insert(Name::new(name))
         .insert(MeteorComponent {
-            size,
-            rotation_speed,
-            health: hp,
+            size:           spawn.size,
+            rotation_speed: spawn.rotation_speed,
+            health:         spawn.hp,
+            material:       spawn.material,
         })
         .insert(MeteorBaseColor(base_color))
-        .insert(HitBoxSize(meteor_size(size)))
-        .insert(Velocity(velocity))
-        .insert(Position(position))
-        .insert(RotationAngle(rotation))
+        .insert(HitBoxSize(Vec2::splat(shape.radius() * 2.0)))
+        .insert(ConvexCollider::new(convex_hull(&shape.outline)))
+        .insert(shape)
+        .insert(Velocity(spawn.velocity))
+        .insert(Position(spawn.position))
+        .insert(RotationAngle(spawn.rotation))
         .insert(BoundsDespawnable(Vec2::new(200.0, 200.0)))
-        .insert(CollisionDespawnableWithDamage::new(true, meteor_damage(size)))
-        .insert(CleanUpOnLevelEnd);
+        .insert(CollisionDespawnableWithDamage::new(true, meteor_damage(spawn.size)))
+        .insert(CleanUpOnLevelEnd)
+        .id()
 }
 
 // ── Systems ───────────────────────────────────────────────────────────────────
@@ -171,22 +241,24 @@ fn meteor_rotation_system(mut query: Query<(&MeteorComponent, &mut RotationAngle
 }
 
 fn meteor_hit_flash_system(
-    mut commands: Commands,
-    time:         Res<Time>,
-    mut query:    Query<(Entity, &mut Sprite, &mut MeteorHitFlash, &MeteorBaseColor)>,
+    mut commands:  Commands,
+    time:          Res<Time>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut query:     Query<(Entity, &Handle<ColorMaterial>, &mut MeteorHitFlash, &MeteorBaseColor)>,
 ) {
-    for (entity, mut sprite, mut flash, base_color) in &mut query {
+    for (entity, handle, mut flash, base_color) in &mut query {
+        let Some(material) = materials.get_mut(handle) else { continue };
         flash.0.tick(time.delta());
         let t = flash.0.percent();
         // Lerp from white back to base color as t goes 0→1
         let [br, bg, bb, _] = base_color.0.as_rgba_f32();
-        sprite.color = Color::rgb(
+        material.color = Color::rgb(
             1.0 - t * (1.0 - br),
             1.0 - t * (1.0 - bg),
             1.0 - t * (1.0 - bb),
         );
         if flash.0.just_finished() {
-            sprite.color = base_color.0;
+            material.color = base_color.0;
             commands.entity(entity).remove::<MeteorHitFlash>();
         }
     }
@@ -195,12 +267,21 @@ fn meteor_hit_flash_system(
 /// Transitions to LevelComplete when no meteors or UFOs remain.
 /// The guard on `total_asteroids_spawned` prevents a false trigger on the
 /// very first frame before asteroids have been fully spawned.
+/// On boss levels the level is won by killing the boss; leftover adds are
+/// cleaned up on InGame exit.
 fn check_level_complete_system(
     level:          Res<LevelResource>,
     meteor_query:   Query<&MeteorComponent>,
     ufo_query:      Query<&crate::objects::ufo::UfoComponent>,
     mut next_state: ResMut<NextState<GameStates>>,
 ) {
+    if level.is_boss_level() {
+        if level.boss_defeated {
+            next_state.set(GameStates::LevelComplete);
+        }
+        return;
+    }
+
     if level.total_asteroids_spawned > 0 && meteor_query.is_empty() && ufo_query.is_empty() {
         next_state.set(GameStates::LevelComplete);
     }
@@ -0,0 +1,148 @@
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::f32::consts::TAU;
+
+use lib::{meteor_radius_range, MeteorSizeType};
+
+// ── Component ─────────────────────────────────────────────────────────────────
+
+/// Jagged outline of a procedural meteor, in local space around its centroid.
+/// Kept on the entity so a split can carve fragments out of the same outline.
+#[derive(Component, Clone)]
+pub struct MeteorShape {
+    pub outline: Vec<Vec2>,
+}
+
+impl MeteorShape {
+    /// Distance from the centre to the furthest outline vertex.
+    pub fn radius(&self) -> f32 {
+        self.outline.iter().map(|p| p.length()).fold(0.0, f32::max)
+    }
+}
+
+// ── Generation ────────────────────────────────────────────────────────────────
+
+/// Builds a jagged, star-shaped outline from `seed`. The base radius is picked
+/// from the size's range in `METEOR_RADIUS_RANGE`, then every vertex is pulled
+/// in by a random amount to roughen the silhouette.
+pub fn generate_outline(seed: u64, size: MeteorSizeType) -> Vec<Vec2> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (min_r, max_r) = meteor_radius_range(size);
+    let base_radius = rng.gen_range(min_r..max_r);
+    let vertices = rng.gen_range(9..15);
+
+    (0..vertices)
+        .map(|i| {
+            let jitter = rng.gen_range(-0.35..0.35) / vertices as f32;
+            let angle  = (i as f32 / vertices as f32 + jitter) * TAU;
+            let radius = base_radius * rng.gen_range(0.72..1.0);
+            Vec2::from_angle(angle) * radius
+        })
+        .collect()
+}
+
+/// Triangle-fan mesh for an outline centred on the origin. UVs sample the
+/// middle of the meteor texture so the rock detail carries over to the new
+/// silhouette without picking up its transparent corners.
+pub fn build_mesh(outline: &[Vec2]) -> Mesh {
+    let radius = outline.iter().map(|p| p.length()).fold(1.0, f32::max);
+    let uv_of  = |p: Vec2| [0.5 + p.x / radius * 0.3, 0.5 - p.y / radius * 0.3];
+
+    let mut positions = vec![[0.0, 0.0, 0.0]];
+    let mut uvs       = vec![[0.5, 0.5]];
+    for p in outline {
+        positions.push([p.x, p.y, 0.0]);
+        uvs.push(uv_of(*p));
+    }
+    let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+
+    let n = outline.len() as u32;
+    let mut indices = Vec::with_capacity(outline.len() * 3);
+    for i in 0..n {
+        indices.extend_from_slice(&[0, i + 1, (i + 1) % n + 1]);
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL,   normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0,     uvs);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+// ── Splitting ─────────────────────────────────────────────────────────────────
+
+/// Cuts `outline` into `pieces` wedges radiating from its centre, starting at
+/// `start_angle`. Each wedge keeps the parent's jagged edge along its outer
+/// side. Returns `(outline, offset)` pairs: the wedge recentred on its own
+/// centroid, and where that centroid sits in the parent's local space.
+pub fn split_outline(outline: &[Vec2], pieces: u32, start_angle: f32) -> Vec<(Vec<Vec2>, Vec2)> {
+    let pieces = pieces.max(1);
+    let step   = TAU / pieces as f32;
+
+    (0..pieces)
+        .map(|i| {
+            let a0 = start_angle + i as f32 * step;
+            let wedge = wedge(outline, a0, step);
+            let center = polygon_centroid(&wedge);
+            (wedge.into_iter().map(|p| p - center).collect(), center)
+        })
+        .collect()
+}
+
+/// Polygon bounded by the centre, the two cut rays and the outline between them.
+fn wedge(outline: &[Vec2], a0: f32, span: f32) -> Vec<Vec2> {
+    let mut inside: Vec<(f32, Vec2)> = outline
+        .iter()
+        .map(|p| ((p.y.atan2(p.x) - a0).rem_euclid(TAU), *p))
+        .filter(|(rel, _)| *rel > 0.0 && *rel < span)
+        .collect();
+    inside.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut points = vec![Vec2::ZERO, ray_hit(outline, a0)];
+    points.extend(inside.into_iter().map(|(_, p)| p));
+    points.push(ray_hit(outline, a0 + span));
+    points
+}
+
+/// First point where a ray from the origin at `angle` crosses the outline.
+fn ray_hit(outline: &[Vec2], angle: f32) -> Vec2 {
+    let dir = Vec2::from_angle(angle);
+    let mut best = f32::MAX;
+
+    for i in 0..outline.len() {
+        let a = outline[i];
+        let e = outline[(i + 1) % outline.len()] - a;
+        let denom = dir.perp_dot(e);
+        if denom.abs() < 1e-6 {
+            continue;
+        }
+        let t = a.perp_dot(e) / denom;
+        let u = a.perp_dot(dir) / denom;
+        if t > 0.0 && (0.0..=1.0).contains(&u) {
+            best = best.min(t);
+        }
+    }
+
+    if best == f32::MAX { Vec2::ZERO } else { dir * best }
+}
+
+/// Area-weighted centroid of a simple polygon.
+fn polygon_centroid(points: &[Vec2]) -> Vec2 {
+    let mut area = 0.0;
+    let mut c    = Vec2::ZERO;
+    for i in 0..points.len() {
+        let p = points[i];
+        let q = points[(i + 1) % points.len()];
+        let cross = p.perp_dot(q);
+        area += cross;
+        c    += (p + q) * cross;
+    }
+    if area.abs() < 1e-4 {
+        return points.iter().copied().sum::<Vec2>() / points.len().max(1) as f32;
+    }
+    c / (3.0 * area)
+}
@@ -1,4 +1,11 @@
+pub mod boss;
+pub mod drone;
+pub mod elite;
 pub mod meteor;
+pub mod meteor_shape;
 pub mod powerup;
 pub mod projectile;
-pub mod ufo;
\ No newline at end of file
+pub mod secondary;
+pub mod survival;
+pub mod ufo;
+pub mod weapon;
\ No newline at end of file
@@ -2,11 +2,11 @@ use std::time::Duration;
 use bevy::{prelude::*, time::common_conditions::on_timer};
 use rand::prelude::*;
 
-use lib::{POWER_UP_SIZE, POWERUP_MAX_COUNT, POWERUP_SPAWN_TIME, MAGNET_STRENGTH};
+use lib::{POWER_UP_SIZE, POWERUP_MAX_COUNT, POWERUP_SPAWN_TIME, MAGNET_STRENGTH, secondary_refill};
 use crate::{
     common::common_components::{RotationAngle, Velocity, Position, HitBoxSize, BoundsDespawnable},
     player::player::PlayerComponent,
-    resources::{GameSprites, PlayerBuff, Life, PlayerUpgrades, WindowSize},
+    resources::{GameSprites, PlayerBuff, Life, PlayerUpgrades, SecondaryAmmo, WindowSize},
     state::states::GameStates,
     utils::cleanup::CleanUpOnLevelEnd,
 };
@@ -29,6 +29,8 @@ pub enum PowerUpKind {
     Hp,
     Bolt,
     Shield,
+    /// Rounds for the secondary weapon.
+    Ammo,
 }
 
 // ── Component ─────────────────────────────────────────────────────────────────
@@ -41,7 +43,7 @@ pub struct PowerUpComponent {
 }
 
 impl PowerUpComponent {
-    pub fn apply(&self, life: &mut Life, buff: &mut PlayerBuff) {
+    pub fn apply(&self, life: &mut Life, buff: &mut PlayerBuff, ammo: &mut SecondaryAmmo) {
         match (self.kind, self.tier) {
             // ── HP restore ───────────────────────────────────────────────────
             (PowerUpKind::Hp, PowerUpTier::Standard) => {
@@ -75,6 +77,17 @@ impl PowerUpComponent {
             (PowerUpKind::Shield, PowerUpTier::Rare) => {
                 buff.shield_timer = (buff.shield_timer + 11.0).min(20.0);
             }
+
+            // ── Secondary ammo ───────────────────────────────────────────────
+            (PowerUpKind::Ammo, PowerUpTier::Standard) => {
+                ammo.refill(secondary_refill(ammo.weapon));
+            }
+            (PowerUpKind::Ammo, PowerUpTier::Enhanced) => {
+                ammo.refill(secondary_refill(ammo.weapon) * 2);
+            }
+            (PowerUpKind::Ammo, PowerUpTier::Rare) => {
+                ammo.ammo = ammo.max_ammo; // full reload
+            }
         }
     }
 
@@ -98,6 +111,9 @@ impl PowerUpComponent {
             (PowerUpKind::Shield, PowerUpTier::Standard) => "Shield",
             (PowerUpKind::Shield, PowerUpTier::Enhanced) => "Shield+",
             (PowerUpKind::Shield, PowerUpTier::Rare)     => "Mega Shield!",
+            (PowerUpKind::Ammo,   PowerUpTier::Standard) => "Ammo",
+            (PowerUpKind::Ammo,   PowerUpTier::Enhanced) => "Ammo+",
+            (PowerUpKind::Ammo,   PowerUpTier::Rare)     => "Full Ammo!",
         }
     }
 }
@@ -135,8 +151,12 @@ fn powerup_rotation_system(mut query: Query<(&PowerUpComponent, &mut RotationAng
 /// Ticks active buff timers every frame.
 pub fn player_buff_tick_system(time: Res<Time>, mut buff: ResMut<PlayerBuff>) {
     let dt = time.delta_seconds();
-    buff.bolt_timer   = (buff.bolt_timer   - dt).max(0.0);
-    buff.shield_timer = (buff.shield_timer - dt).max(0.0);
+    buff.bolt_timer       = (buff.bolt_timer       - dt).max(0.0);
+    buff.shield_timer     = (buff.shield_timer     - dt).max(0.0);
+    buff.slow_timer       = (buff.slow_timer       - dt).max(0.0);
+    buff.dash_timer       = (buff.dash_timer       - dt).max(0.0);
+    buff.overcharge_timer = (buff.overcharge_timer - dt).max(0.0);
+    buff.invuln_timer     = (buff.invuln_timer     - dt).max(0.0);
 }
 
 fn powerup_magnet_system(
@@ -176,9 +196,13 @@ fn spawn_powerup_system(
 
     let speed_y   = if from_top { rng.gen_range(-1.5..-0.8) } else { rng.gen_range(0.8..1.5) };
     let speed_x   = rng.gen_range(-1.0..1.0);
-    let rotation  = rng.gen_range(-0.1_f32..0.1);
-    let rot_speed = rng.gen_range(-0.08_f32..0.08);
 
+    let (kind, tier) = roll_powerup(&mut rng);
+    spawn_powerup_at(&mut commands, &game_sprites, Vec2::new(x, y), Vec2::new(speed_x, speed_y), kind, tier);
+}
+
+/// Rolls a random kind + tier with the standard drop odds.
+pub fn roll_powerup(rng: &mut impl Rng) -> (PowerUpKind, PowerUpTier) {
     // Tier: Standard 55%, Enhanced 30%, Rare 15%
     let tier = match rng.gen_range(0u8..20) {
         0..=2 => PowerUpTier::Rare,
@@ -186,20 +210,38 @@ fn spawn_powerup_system(
         _     => PowerUpTier::Standard,
     };
 
-    // Kind: HP 50%, Bolt 25%, Shield 25%
-    let kind = match rng.gen_range(0u8..4) {
+    // Kind: HP 40%, Bolt 20%, Shield 20%, Ammo 20%
+    let kind = match rng.gen_range(0u8..5) {
         0 => PowerUpKind::Bolt,
         1 => PowerUpKind::Shield,
+        2 => PowerUpKind::Ammo,
         _ => PowerUpKind::Hp,
     };
 
-    let texture = powerup_sprite(&game_sprites, kind, tier);
+    (kind, tier)
+}
+
+/// Spawns a powerup at `position`. Used by the timed spawner and by drops
+/// such as gold meteors.
+pub fn spawn_powerup_at(
+    commands:     &mut Commands,
+    game_sprites: &GameSprites,
+    position:     Vec2,
+    velocity:     Vec2,
+    kind:         PowerUpKind,
+    tier:         PowerUpTier,
+) {
+    let mut rng = thread_rng();
+    let rotation  = rng.gen_range(-0.1_f32..0.1);
+    let rot_speed = rng.gen_range(-0.08_f32..0.08);
+
+    let texture = powerup_sprite(game_sprites, kind, tier);
 
     commands
         .spawn(SpriteBundle {
             texture,
             transform: Transform {
-                translation: Vec3::new(x, y, 1.0),
+                translation: Vec3::new(position.x, position.y, 1.0),
                 rotation:    Quat::from_rotation_z(rotation),
                 ..default()
             },
@@ -208,8 +250,8 @@ fn spawn_powerup_system(
         .insert(Name::new("PowerUp"))
         .insert(PowerUpComponent { kind, tier, rotation_speed: rot_speed })
         .insert(HitBoxSize(POWER_UP_SIZE))
-        .insert(Velocity(Vec2::new(speed_x, speed_y)))
-        .insert(Position(Vec2::new(x, y)))
+        .insert(Velocity(velocity))
+        .insert(Position(position))
         .insert(RotationAngle(rotation))
         .insert(BoundsDespawnable(Vec2::new(50.0, 50.0)))
         .insert(CleanUpOnLevelEnd);
@@ -227,5 +269,8 @@ pub fn powerup_sprite(gs: &GameSprites, kind: PowerUpKind, tier: PowerUpTier) ->
         (PowerUpKind::Shield, PowerUpTier::Standard) => gs.powerup_shield.clone(),
         (PowerUpKind::Shield, PowerUpTier::Enhanced) => gs.powerup_shield_green.clone(),
         (PowerUpKind::Shield, PowerUpTier::Rare)     => gs.powerup_shield_red.clone(),
+        (PowerUpKind::Ammo,   PowerUpTier::Standard) => gs.powerup_ammo.clone(),
+        (PowerUpKind::Ammo,   PowerUpTier::Enhanced) => gs.powerup_ammo_green.clone(),
+        (PowerUpKind::Ammo,   PowerUpTier::Rare)     => gs.powerup_ammo_red.clone(),
     }
 }
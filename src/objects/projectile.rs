@@ -1,15 +1,26 @@
-use bevy::prelude::*;
-use lib::{PROJECTILE_SIZE, SPRITE_SCALE, ShipType};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use rand::{thread_rng, Rng};
+use lib::{
+    weapon_stats, PROJECTILE_SIZE, SPRITE_SCALE, OVERCHARGE_FIRE_RATE_MULT, WeaponArchetype,
+    CHARGE_MAX_DAMAGE_MULT, CHARGE_MAX_SCALE, CHARGE_TIME, PLASMA_SIZE, SHOTGUN_PELLETS, SHOTGUN_SPREAD,
+};
 
 use crate::{
     common::common_components::{RotationAngle, Position, HitBoxSize, Velocity},
+    input::{
+        actions::{Action, Actions},
+        gamepad::{PadInput, FIRE_BUTTON},
+        touch::TouchControls,
+    },
     player::{
+        hyperspace::InHyperspace,
         player::{PlayerComponent, PlayerShootCooldownComponent},
-        ship::ShipComponent,
+        ship::{ShipComponent, ShipDef},
     },
-    resources::{GameSprites, IsPaused, PlayerBuff, PlayerUpgrades},
+    objects::weapon::PrimaryWeapon,
+    resources::{GameSettings, GameSprites, IsPaused, PlayerBuff, PlayerUpgrades},
     state::states::GameStates,
-    utils::cleanup::CleanUpOnLevelEnd,
+    utils::{cleanup::CleanUpOnLevelEnd, run_stats::RunStats},
 };
 
 // ── Components ────────────────────────────────────────────────────────────────
@@ -25,6 +36,8 @@ pub struct ProjectileComponent {
     pub pierce_remaining: i32,
     /// Entities already hit by this projectile — prevents re-collision across frames.
     pub hit_meteors:     Vec<Entity>,
+    /// Meteors destroyed by this projectile so far.
+    pub kills:           u32,
 }
 
 /// Tiny shrapnel spawned by Explosive Rounds on asteroid kill.
@@ -32,6 +45,11 @@ pub struct ProjectileComponent {
 #[derive(Component)]
 pub struct ShrapnelComponent;
 
+/// A bullet from the ship's guns, as opposed to drones, shrapnel or the
+/// secondary weapon. Only these count toward accuracy.
+#[derive(Component)]
+pub struct PrimaryShot;
+
 /// Marks bullets that explode at max range (Detonator Rounds upgrade).
 #[derive(Component)]
 pub struct DetonatorComponent;
@@ -81,11 +99,11 @@ fn projectile_range_despawn_system(
     ship_q:        Query<&crate::player::ship::ShipComponent, With<crate::player::player::PlayerComponent>>,
     query:         Query<(Entity, &ProjectileComponent, &Position, Option<&DetonatorComponent>)>,
 ) {
-    let ship_type = ship_q.get_single().map(|s| s.ship_type).unwrap_or(lib::ShipType::Normal);
+    let bolt = ship_q.get_single().map_or_else(|_| game_sprites.ships[0].projectile.clone(), |s| game_sprites.projectile(s));
     for (entity, proj, pos, detonator) in &query {
         if (pos.0 - proj.origin).length() >= proj.max_range {
             if detonator.is_some() {
-                spawn_shrapnel(&mut commands, &game_sprites, pos.0, ship_type);
+                spawn_shrapnel(&mut commands, &bolt, pos.0);
             }
             commands.entity(entity).despawn();
         }
@@ -150,105 +168,199 @@ fn chain_reaction_tick_system(
 
 // ── Shoot system ─────────────────────────────────────────────────────────────
 
+/// Whether the fire control is held on any device. Twin-stick fires with the
+/// left mouse button instead of the Fire key.
+#[derive(SystemParam)]
+pub struct FireInput<'w> {
+    actions:  Actions<'w>,
+    mouse:    Res<'w, Input<MouseButton>>,
+    pad:      PadInput<'w>,
+    touch:    Res<'w, TouchControls>,
+    settings: Res<'w, GameSettings>,
+}
+
+impl FireInput<'_> {
+    pub fn held(&self) -> bool {
+        self.pad.pressed(FIRE_BUTTON) || self.touch.fire
+            || if self.settings.twin_stick() {
+                self.mouse.pressed(MouseButton::Left)
+            } else {
+                self.actions.pressed(Action::Fire)
+            }
+    }
+}
+
+/// Seconds between shots (or beam ticks) for the current weapon, upgrades
+/// and overcharge. `heat` picks the heat model's shorter base cooldown.
+pub fn weapon_cooldown(
+    upgrades:  &PlayerUpgrades,
+    buff:      &PlayerBuff,
+    ship:      &ShipDef,
+    weapon:    WeaponArchetype,
+    heat:      bool,
+) -> f32 {
+    let base = if heat { upgrades.heat_shoot_cooldown(ship) } else { upgrades.effective_shoot_cooldown(ship) };
+    let mut cd = base * weapon_stats(weapon).cooldown_mult;
+    if buff.overcharge_timer > 0.0 {
+        cd /= OVERCHARGE_FIRE_RATE_MULT;
+    }
+    cd
+}
+
 pub fn projectile_shoot_system(
     mut commands:  Commands,
-    kb:            Res<Input<KeyCode>>,
+    fire:          FireInput,
     game_sprites:  Res<GameSprites>,
     time:          Res<Time>,
     buff:          Res<PlayerBuff>,
     paused:        Res<IsPaused>,
+    settings:      Res<GameSettings>,
     mut upgrades:  ResMut<PlayerUpgrades>,
+    mut stats:     ResMut<RunStats>,
     ship_q:        Query<&ShipComponent, With<PlayerComponent>>,
-    mut player_q:  Query<(&PlayerComponent, &RotationAngle, &Position, &mut PlayerShootCooldownComponent)>,
+    mut player_q:  Query<(&RotationAngle, &Position, &mut PlayerShootCooldownComponent, &mut PrimaryWeapon), Without<InHyperspace>>,
 ) {
     if paused.0 { return; }
     let Ok(ship) = ship_q.get_single() else { return };
-    let ship_type = ship.ship_type;
-
-    let texture: Handle<Image> = match ship_type {
-        ShipType::Attack => game_sprites.projectile_attack.clone(),
-        ShipType::Normal => game_sprites.projectile_normal.clone(),
-        ShipType::Shield => game_sprites.projectile_shield.clone(),
-    };
+    let def = ship.def();
 
-    let proj_speed = upgrades.effective_projectile_speed(ship_type, buff.bolt_timer > 0.0);
-    let proj_range = upgrades.effective_projectile_range(ship_type);
+    let proj_speed = upgrades.effective_projectile_speed(def, buff.bolt_timer > 0.0);
+    let proj_range = upgrades.effective_projectile_range(def);
     let damage     = upgrades.bullet_damage();
     let pierce     = upgrades.pierce_count();
+    let firing     = fire.held();
+    let heat       = settings.weapon_heat;
+    let mut rng    = thread_rng();
 
-    for (player, angle, position, mut cooldown) in player_q.iter_mut() {
+    for (angle, position, mut cooldown, mut weapon) in player_q.iter_mut() {
         cooldown.0.tick(time.delta());
-
-        if !cooldown.0.finished() || !kb.pressed(KeyCode::Space) {
+        // Overheated guns stay locked until they cool to `HEAT_RESUME`.
+        if heat && weapon.overheated {
             continue;
         }
 
+        // 0 for everything but the charge shot, where it's how full the charge was.
+        let charge = match weapon.archetype {
+            // The beam deals its damage in `beam_system`.
+            WeaponArchetype::Beam => continue,
+            // Charge builds while the trigger is held; the shot leaves on release.
+            WeaponArchetype::Charge => {
+                if !cooldown.0.finished() {
+                    continue;
+                }
+                if firing {
+                    weapon.charge = (weapon.charge + time.delta_seconds()).min(CHARGE_TIME);
+                    continue;
+                }
+                if weapon.charge <= 0.0 {
+                    continue;
+                }
+                let charge = weapon.charge / CHARGE_TIME;
+                weapon.charge = 0.0;
+                charge
+            }
+            _ => {
+                if !cooldown.0.finished() || !firing {
+                    continue;
+                }
+                0.0
+            }
+        };
+
+        let wstats = weapon_stats(weapon.archetype);
+        let plasma = weapon.archetype == WeaponArchetype::Plasma;
+        let (texture, color) = if plasma {
+            (game_sprites.star1.clone(), def.color)
+        } else {
+            (game_sprites.projectile(ship), Color::WHITE)
+        };
+        let hitbox      = if plasma { PLASMA_SIZE } else { PROJECTILE_SIZE };
+        let scale       = SPRITE_SCALE * wstats.scale * (1.0 + (CHARGE_MAX_SCALE - 1.0) * charge);
+        let damage_mult = 1.0 + (CHARGE_MAX_DAMAGE_MULT - 1.0) * charge;
+        let shot_damage = ((damage + wstats.damage_bonus) as f32 * damage_mult).round() as i32;
+        let shot_pierce = pierce + wstats.extra_pierce + (charge >= 1.0) as i32;
+
+        // The shotgun fans a handful of pellets around each shot direction.
+        let pellets: Vec<f32> = if weapon.archetype == WeaponArchetype::Shotgun {
+            let step = SHOTGUN_SPREAD / (SHOTGUN_PELLETS - 1) as f32;
+            (0..SHOTGUN_PELLETS).map(|i| i as f32 * step - SHOTGUN_SPREAD / 2.0).collect()
+        } else {
+            vec![0.0]
+        };
+
         let offsets = upgrades.shot_offsets();
         let ricochet_enabled = upgrades.ricochet;
 
         for &offset in &offsets {
-            let shot_angle = angle.0 + offset;
-            let direction  = {
-                let (y, x) = (shot_angle + std::f32::consts::PI / 2.0).sin_cos();
-                Vec2::new(x, y).normalize()
-            };
-
-            let mut entity_cmds = commands.spawn(SpriteBundle {
-                texture: texture.clone(),
-                transform: Transform {
-                    translation: Vec3::new(position.0.x, position.0.y, 5.0),
-                    scale:       Vec3::splat(SPRITE_SCALE),
-                    rotation:    Quat::from_rotation_z(shot_angle),
+            for &pellet in &pellets {
+                let shot_angle = angle.0 + offset + pellet;
+                let direction  = {
+                    let (y, x) = (shot_angle + std::f32::consts::PI / 2.0).sin_cos();
+                    Vec2::new(x, y).normalize()
+                };
+                // Pellets travel at slightly different speeds so the blast spreads out.
+                let speed = proj_speed * wstats.speed_mult * if pellets.len() > 1 { rng.gen_range(0.85..1.15) } else { 1.0 };
+
+                let mut entity_cmds = commands.spawn(SpriteBundle {
+                    texture: texture.clone(),
+                    sprite: Sprite { color, ..default() },
+                    transform: Transform {
+                        translation: Vec3::new(position.0.x, position.0.y, 5.0),
+                        scale:       Vec3::splat(scale),
+                        rotation:    Quat::from_rotation_z(shot_angle),
+                        ..default()
+                    },
                     ..default()
-                },
-                ..default()
-            });
-
-            entity_cmds
-                .insert(Name::new("Projectile"))
-                .insert(ProjectileComponent {
-                    damage,
-                    origin: position.0,
-                    max_range: proj_range,
-                    pierce_remaining: pierce,
-                    hit_meteors: Vec::new(),
-                })
-                .insert(ProjectileDespawnComponent::default())
-                .insert(HitBoxSize(PROJECTILE_SIZE))
-                .insert(Velocity(direction * proj_speed))
-                .insert(Position(position.0))
-                .insert(CleanUpOnLevelEnd);
-
-            if ricochet_enabled {
-                entity_cmds.insert(ProjectileRicochet { bounced: false });
-            }
-            if upgrades.detonator_rounds {
-                entity_cmds.insert(DetonatorComponent);
+                });
+
+                entity_cmds
+                    .insert(Name::new("Projectile"))
+                    .insert(ProjectileComponent {
+                        damage: shot_damage,
+                        origin: position.0,
+                        max_range: proj_range * wstats.range_mult,
+                        pierce_remaining: shot_pierce,
+                        hit_meteors: Vec::new(),
+                        kills: 0,
+                    })
+                    .insert(ProjectileDespawnComponent::default())
+                    .insert(PrimaryShot)
+                    .insert(HitBoxSize(hitbox))
+                    .insert(Velocity(direction * speed))
+                    .insert(Position(position.0))
+                    .insert(CleanUpOnLevelEnd);
+
+                if ricochet_enabled {
+                    entity_cmds.insert(ProjectileRicochet { bounced: false });
+                }
+                if upgrades.detonator_rounds {
+                    entity_cmds.insert(DetonatorComponent);
+                }
+                // No BoundsDespawnable — range system handles despawn so upgrades visibly affect bullet reach.
             }
-            // No BoundsDespawnable — range system handles despawn so upgrades visibly affect bullet reach.
         }
+        stats.shots_fired += (offsets.len() * pellets.len()) as u32;
 
-        let cd = upgrades.effective_shoot_cooldown(ship_type);
+        // Overcharge fires without building heat; a charged shot builds it in
+        // proportion to its damage.
+        if heat && buff.overcharge_timer <= 0.0 {
+            let amount = upgrades.heat_per_shot(weapon.archetype) * damage_mult;
+            weapon.add_heat(amount);
+        }
+
+        let cd = weapon_cooldown(&upgrades, &buff, def, weapon.archetype, heat);
         cooldown.0 = Timer::from_seconds(cd, TimerMode::Once);
     }
 }
 
 /// Called by collision to spawn 4 shrapnel fragments at the given position.
 pub fn spawn_shrapnel(
-    commands:     &mut Commands,
-    game_sprites: &GameSprites,
-    origin:       Vec2,
-    ship_type:    ShipType,
+    commands: &mut Commands,
+    texture:  &Handle<Image>,
+    origin:   Vec2,
 ) {
-    use rand::{thread_rng, Rng};
     use std::f32::consts::TAU;
 
-    let texture = match ship_type {
-        ShipType::Attack => game_sprites.projectile_attack.clone(),
-        ShipType::Normal => game_sprites.projectile_normal.clone(),
-        ShipType::Shield => game_sprites.projectile_shield.clone(),
-    };
-
     let mut rng = thread_rng();
     for i in 0..3 {
         let angle = rng.gen_range(0.0..TAU) + (i as f32) * (TAU / 3.0);
@@ -276,6 +388,7 @@ pub fn spawn_shrapnel(
                 max_range:       140.0,
                 pierce_remaining: 0,
                 hit_meteors:     Vec::new(),
+                kills:           0,
             },
             ProjectileDespawnComponent::default(),
             HitBoxSize(Vec2::new(6.0, 20.0)),
@@ -0,0 +1,252 @@
+use std::f32::consts::{PI, TAU};
+use bevy::prelude::*;
+
+use lib::{
+    SecondaryWeapon, MINE_ARM_TIME, MINE_BLAST_RADIUS, MINE_DAMAGE, MINE_LIFETIME, MINE_TRIGGER_RADIUS,
+    MISSILE_DAMAGE, MISSILE_RANGE, MISSILE_SPEED, MISSILE_TURN_RATE, NUKE_DAMAGE, PROJECTILE_SIZE,
+    SECONDARY_COOLDOWN, SPRITE_SCALE,
+};
+use crate::{
+    common::{
+        collision::spawn_area_blast,
+        common_components::{HitBoxSize, Position, RotationAngle, Velocity},
+    },
+    input::{actions::{Action, Actions}, gamepad::PadInput, touch::TouchControls},
+    objects::{
+        meteor::MeteorComponent,
+        projectile::{ProjectileComponent, ProjectileDespawnComponent},
+        ufo::{UfoComponent, UfoProjectileComponent},
+    },
+    player::{hyperspace::InHyperspace, player::PlayerComponent, ship::ShipComponent},
+    resources::{CameraShake, GameSprites, IsPaused, SecondaryAmmo, WindowSize},
+    state::states::GameStates,
+    utils::cleanup::CleanUpOnLevelEnd,
+};
+
+/// Pad button for the secondary weapon.
+pub const SECONDARY_BUTTON: GamepadButtonType = GamepadButtonType::LeftTrigger2;
+
+const MISSILE_COLOR:     Color = Color::rgb(1.0, 0.65, 0.25);
+const MINE_IDLE_COLOR:   Color = Color::rgba(0.7, 0.7, 0.7, 0.8);
+const MINE_ARMED_COLOR:  Color = Color::rgba(1.0, 0.3, 0.2, 0.9);
+const NUKE_FLASH_SECS:   f32   = 0.5;
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// Turns a projectile toward the nearest meteor or UFO every frame.
+#[derive(Component)]
+pub struct Homing;
+
+/// Proximity mine dropped where the ship was. Drawn with gizmos.
+#[derive(Component)]
+pub struct Mine {
+    /// Seconds since it was dropped.
+    age: f32,
+}
+
+impl Mine {
+    fn armed(&self) -> bool {
+        self.age >= MINE_ARM_TIME
+    }
+}
+
+/// Full-screen white flash left by the nuke.
+#[derive(Component)]
+struct NukeFlash(Timer);
+
+/// HUD and menu name of each secondary weapon.
+pub fn secondary_name(weapon: SecondaryWeapon) -> &'static str {
+    match weapon {
+        SecondaryWeapon::Missiles => "MISSILES",
+        SecondaryWeapon::Mines    => "MINES",
+        SecondaryWeapon::Nuke     => "NUKE",
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Limited-ammo loadout weapon: homing missiles, proximity mines or a
+/// screen-clearing nuke, picked on the ship select screen.
+pub struct SecondaryPlugin;
+
+impl Plugin for SecondaryPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    secondary_fire_system,
+                    missile_homing_system,
+                    mine_trigger_system,
+                    nuke_flash_system,
+                )
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            // Mines stay visible behind the pause overlay.
+            .add_systems(Update, mine_draw_system.run_if(in_state(GameStates::InGame)));
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+fn secondary_fire_system(
+    mut commands: Commands,
+    actions:      Actions,
+    pad:          PadInput,
+    touch:        Res<TouchControls>,
+    time:         Res<Time>,
+    game_sprites: Res<GameSprites>,
+    wdw_size:     Res<WindowSize>,
+    mut ammo:     ResMut<SecondaryAmmo>,
+    mut shake:    ResMut<CameraShake>,
+    player_q:     Query<(&PlayerComponent, &ShipComponent, &RotationAngle, &Position), Without<InHyperspace>>,
+    ufo_proj_q:   Query<Entity, With<UfoProjectileComponent>>,
+) {
+    ammo.cooldown = (ammo.cooldown - time.delta_seconds()).max(0.0);
+
+    if !actions.just_pressed(Action::Secondary) && !pad.just_pressed(SECONDARY_BUTTON) && !touch.secondary {
+        return;
+    }
+    let Ok((player, ship, angle, pos)) = player_q.get_single() else { return };
+    if !ammo.ready() {
+        return;
+    }
+    ammo.ammo     -= 1;
+    ammo.cooldown  = SECONDARY_COOLDOWN;
+
+    match ammo.weapon {
+        SecondaryWeapon::Missiles => {
+            commands.spawn((
+                SpriteBundle {
+                    texture: game_sprites.projectile(ship),
+                    sprite: Sprite { color: MISSILE_COLOR, ..default() },
+                    transform: Transform {
+                        translation: pos.0.extend(5.0),
+                        scale:       Vec3::splat(SPRITE_SCALE * 1.3),
+                        rotation:    Quat::from_rotation_z(angle.0),
+                    },
+                    ..default()
+                },
+                ProjectileComponent {
+                    damage:           MISSILE_DAMAGE,
+                    origin:           pos.0,
+                    max_range:        MISSILE_RANGE,
+                    pierce_remaining: 0,
+                    hit_meteors:      Vec::new(),
+                    kills:            0,
+                },
+                ProjectileDespawnComponent::default(),
+                HitBoxSize(PROJECTILE_SIZE),
+                Velocity(player.direction(angle.0) * MISSILE_SPEED),
+                Position(pos.0),
+                Homing,
+                CleanUpOnLevelEnd,
+                Name::new("Missile"),
+            ));
+        }
+        SecondaryWeapon::Mines => {
+            commands.spawn((
+                Mine { age: 0.0 },
+                Position(pos.0),
+                CleanUpOnLevelEnd,
+                Name::new("Mine"),
+            ));
+        }
+        SecondaryWeapon::Nuke => {
+            // Centred on the screen and big enough to reach every corner.
+            let radius = Vec2::new(wdw_size.w, wdw_size.h).length() * 0.5;
+            spawn_area_blast(&mut commands, Vec3::ZERO, radius, NUKE_DAMAGE, false);
+            for e in &ufo_proj_q {
+                commands.entity(e).despawn();
+            }
+            shake.trigger(15.0);
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color:       Color::WHITE,
+                        custom_size: Some(Vec2::new(wdw_size.w, wdw_size.h) * 1.2),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(0.0, 0.0, 40.0),
+                    ..default()
+                },
+                NukeFlash(Timer::from_seconds(NUKE_FLASH_SECS, TimerMode::Once)),
+                CleanUpOnLevelEnd,
+                Name::new("NukeFlash"),
+            ));
+        }
+    }
+}
+
+/// Turns each missile toward the nearest meteor or UFO, at most
+/// `MISSILE_TURN_RATE` per frame. With nothing to chase it flies straight.
+fn missile_homing_system(
+    mut missile_q: Query<(&Position, &mut Velocity, &mut Transform), With<Homing>>,
+    target_q:      Query<&Position, Or<(With<MeteorComponent>, With<UfoComponent>)>>,
+) {
+    for (pos, mut vel, mut tf) in &mut missile_q {
+        let nearest = target_q
+            .iter()
+            .map(|t| t.0)
+            .min_by(|a, b| a.distance_squared(pos.0).total_cmp(&b.distance_squared(pos.0)));
+
+        if let Some(target) = nearest {
+            let to_target = target - pos.0;
+            let heading   = vel.0.y.atan2(vel.0.x);
+            let wanted    = to_target.y.atan2(to_target.x);
+            let turn      = (wanted - heading + PI).rem_euclid(TAU) - PI;
+            let heading   = heading + turn.clamp(-MISSILE_TURN_RATE, MISSILE_TURN_RATE);
+            vel.0 = Vec2::from_angle(heading) * MISSILE_SPEED;
+        }
+        // Sprites point along +Y.
+        tf.rotation = Quat::from_rotation_z(vel.0.y.atan2(vel.0.x) - PI / 2.0);
+    }
+}
+
+/// Ages mines and sets off any armed mine with a meteor or UFO in range.
+/// Untouched mines fizzle out after `MINE_LIFETIME`.
+fn mine_trigger_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    mut mine_q:   Query<(Entity, &Position, &mut Mine)>,
+    target_q:     Query<&Position, Or<(With<MeteorComponent>, With<UfoComponent>)>>,
+) {
+    for (entity, pos, mut mine) in &mut mine_q {
+        mine.age += time.delta_seconds();
+        if mine.age >= MINE_LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if mine.armed() && target_q.iter().any(|t| t.0.distance(pos.0) <= MINE_TRIGGER_RADIUS) {
+            spawn_area_blast(&mut commands, pos.0.extend(1.0), MINE_BLAST_RADIUS, MINE_DAMAGE, false);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Draws each mine as a small core and its trigger ring; armed mines blink red.
+fn mine_draw_system(
+    mut gizmos: Gizmos,
+    mine_q:     Query<(&Position, &Mine)>,
+) {
+    for (pos, mine) in &mine_q {
+        let color = if mine.armed() && (mine.age * 4.0).fract() < 0.5 { MINE_ARMED_COLOR } else { MINE_IDLE_COLOR };
+        gizmos.circle_2d(pos.0, 8.0, color);
+        gizmos.circle_2d(pos.0, MINE_TRIGGER_RADIUS, color.with_a(0.25));
+    }
+}
+
+fn nuke_flash_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    mut query:    Query<(Entity, &mut NukeFlash, &mut Sprite)>,
+) {
+    for (entity, mut flash, mut sprite) in &mut query {
+        flash.0.tick(time.delta());
+        sprite.color.set_a(0.9 * (1.0 - flash.0.percent()));
+        if flash.0.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
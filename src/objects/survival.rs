@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+use rand::thread_rng;
+
+use lib::{
+    SURVIVAL_FIRST_OFFER, SURVIVAL_MAX_METEORS, SURVIVAL_MAX_UFOS, SURVIVAL_METEOR_MIN_SECS, SURVIVAL_METEOR_SECS,
+    SURVIVAL_OFFER_STEP, SURVIVAL_RAMP_SECS, SURVIVAL_STAGE_SECS, SURVIVAL_UFO_MIN_SECS, SURVIVAL_UFO_SECS,
+    UPGRADE_CHOICES,
+};
+use crate::{
+    objects::{
+        elite::{try_make_elite, EliteTarget},
+        meteor::{spawn_incoming_meteor, MeteorAssets, MeteorComponent},
+        ufo::{roll_ufo_type, spawn_ufo_typed, UfoComponent},
+    },
+    resources::{
+        is_survival, GameSprites, IsPaused, LevelResource, PlayerUpgrades, RunRng, Score, UpgradeSelectionState,
+        WindowSize,
+    },
+    state::states::GameStates,
+    upgrades::{hangar::MetaProgress, upgrades::generate_choices},
+};
+
+// ── Resource ──────────────────────────────────────────────────────────────────
+
+/// Clock, spawn timers and upgrade thresholds of a survival run. Reset when
+/// any run starts; only ticks while a survival run is in play.
+#[derive(Resource)]
+pub struct SurvivalState {
+    /// Seconds of unpaused play this run.
+    pub elapsed:    f32,
+    /// Seconds until the next meteor and the next UFO.
+    meteor_timer:   f32,
+    ufo_timer:      f32,
+    /// Score that brings up the next upgrade offer.
+    pub next_offer: i32,
+    /// Offers made so far; each one widens the gap to the next.
+    offers:         i32,
+}
+
+impl Default for SurvivalState {
+    fn default() -> Self {
+        SurvivalState {
+            elapsed:      0.0,
+            meteor_timer: SURVIVAL_METEOR_SECS,
+            ufo_timer:    SURVIVAL_UFO_SECS,
+            next_offer:   SURVIVAL_FIRST_OFFER,
+            offers:       0,
+        }
+    }
+}
+
+impl SurvivalState {
+    /// 1 for the first `SURVIVAL_STAGE_SECS`, then one more for each after.
+    pub fn stage(&self) -> u32 {
+        1 + (self.elapsed / SURVIVAL_STAGE_SECS) as u32
+    }
+
+    /// Gap between spawns that start `base` seconds apart, shrinking with the
+    /// time survived but never below `min`.
+    fn interval(&self, base: f32, min: f32) -> f32 {
+        (base / (1.0 + self.elapsed / SURVIVAL_RAMP_SECS)).max(min)
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Endless mode: streams enemies in and offers upgrades by score. The
+/// level-complete check only runs in classic, so a survival run stays in
+/// InGame until the player dies, with the level number following the stage.
+pub struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SurvivalState>()
+            .add_systems(OnEnter(GameStates::StartGame), reset_survival)
+            .add_systems(
+                Update,
+                (survival_clock_system, survival_spawn_system, survival_offer_system)
+                    .chain()
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(is_survival)
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            );
+    }
+}
+
+fn reset_survival(mut survival: ResMut<SurvivalState>, mut offer: ResMut<UpgradeSelectionState>) {
+    *survival     = SurvivalState::default();
+    offer.mid_run = false;
+}
+
+/// Advances the clock and raises the level to the current stage.
+fn survival_clock_system(
+    time:         Res<Time>,
+    mut survival: ResMut<SurvivalState>,
+    mut level:    ResMut<LevelResource>,
+) {
+    survival.elapsed += time.delta_seconds();
+    let stage = survival.stage();
+    if level.current != stage {
+        level.current = stage;
+    }
+}
+
+/// Streams meteors and UFOs in from off-screen, more often the longer the
+/// run lasts. A spawn that would go over the caps is skipped, not queued.
+fn survival_spawn_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    game_sprites: Res<GameSprites>,
+    mut assets:   MeteorAssets,
+    wdw_size:     Res<WindowSize>,
+    level:        Res<LevelResource>,
+    upgrades:     Res<PlayerUpgrades>,
+    meteors:      Query<(), With<MeteorComponent>>,
+    ufos:         Query<(), With<UfoComponent>>,
+    mut survival: ResMut<SurvivalState>,
+) {
+    let dt      = time.delta_seconds();
+    let mut rng = thread_rng();
+
+    survival.meteor_timer -= dt;
+    if survival.meteor_timer <= 0.0 {
+        survival.meteor_timer = survival.interval(SURVIVAL_METEOR_SECS, SURVIVAL_METEOR_MIN_SECS);
+        if meteors.iter().count() < SURVIVAL_MAX_METEORS {
+            spawn_incoming_meteor(&mut commands, &game_sprites, &mut assets, &wdw_size, &level, &upgrades, &mut rng);
+        }
+    }
+
+    survival.ufo_timer -= dt;
+    if survival.ufo_timer <= 0.0 {
+        survival.ufo_timer = survival.interval(SURVIVAL_UFO_SECS, SURVIVAL_UFO_MIN_SECS);
+        if ufos.iter().count() < SURVIVAL_MAX_UFOS {
+            let ufo_type = roll_ufo_type(level.current, &mut rng);
+            let ufo      = spawn_ufo_typed(&mut commands, &game_sprites, &wdw_size, ufo_type);
+            try_make_elite(&mut commands, ufo, level.current, EliteTarget::Ufo, ufo_type.max_hp(), &mut rng);
+        }
+    }
+}
+
+/// Once the score reaches the next threshold, rolls an offer and pauses
+/// play. The upgrade screen then shows over the frozen field, and taking a
+/// card resumes (see `confirm_upgrade`).
+fn survival_offer_system(
+    score:         Res<Score>,
+    upgrades:      Res<PlayerUpgrades>,
+    meta:          Res<MetaProgress>,
+    mut rng:       ResMut<RunRng>,
+    mut survival:  ResMut<SurvivalState>,
+    mut selection: ResMut<UpgradeSelectionState>,
+    mut paused:    ResMut<IsPaused>,
+) {
+    if score.current < survival.next_offer {
+        return;
+    }
+    survival.offers     += 1;
+    survival.next_offer += SURVIVAL_FIRST_OFFER + survival.offers * SURVIVAL_OFFER_STEP;
+
+    let choices = generate_choices(&upgrades, &meta, UPGRADE_CHOICES, false, &mut *rng);
+    // Everything maxed out: nothing to offer, so play on.
+    if choices.is_empty() {
+        return;
+    }
+    selection.choices  = choices;
+    selection.selected = 0;
+    selection.mid_run  = true;
+    paused.0           = true;
+}
@@ -5,9 +5,12 @@ use std::f32::consts::PI;
 use lib::PROJECTILE_SIZE;
 use crate::{
     common::common_components::{HitBoxSize, Position, Velocity, BoundsDespawnable},
-    objects::projectile::ProjectileDespawnComponent,
+    objects::{
+        elite::{try_make_elite, EliteTarget},
+        projectile::ProjectileDespawnComponent,
+    },
     player::player::PlayerComponent,
-    resources::{GameSprites, IsPaused, LevelResource, WindowSize},
+    resources::{is_classic, GameSprites, IsPaused, LevelResource, WindowSize},
     state::states::GameStates,
     utils::cleanup::CleanUpOnLevelEnd,
 };
@@ -26,6 +29,17 @@ pub enum UfoType {
     Sniper,
 }
 
+impl UfoType {
+    pub fn max_hp(&self) -> i32 {
+        match self {
+            UfoType::Scout   => 3,
+            UfoType::Gunship => 6,
+            UfoType::Bomber  => 2,
+            UfoType::Sniper  => 2,
+        }
+    }
+}
+
 // ── Components ────────────────────────────────────────────────────────────────
 
 #[derive(Component)]
@@ -52,7 +66,7 @@ pub struct UfoPlugin;
 impl Plugin for UfoPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(OnEnter(GameStates::InGame), spawn_ufo_for_level)
+            .add_systems(OnEnter(GameStates::InGame), spawn_ufo_for_level.run_if(is_classic))
             .add_systems(
                 Update,
                 (ufo_movement_system, ufo_shoot_system, ufo_hit_flash_system)
@@ -70,23 +84,28 @@ fn spawn_ufo_for_level(
     wdw_size:     Res<WindowSize>,
     level:        Res<LevelResource>,
 ) {
-    if level.current < 3 { return; }
+    if level.current < 3 || level.is_boss_level() { return; }
 
     let mut rng = thread_rng();
     let ufo_count = ((level.current - 2) as usize).min(4);
 
     for _ in 0..ufo_count {
-        // Pick type based on level with weighted random
-        let ufo_type = if level.current >= 9 {
-            match rng.gen_range(0..4) { 0 => UfoType::Gunship, 1 => UfoType::Bomber, 2 => UfoType::Sniper, _ => UfoType::Scout }
-        } else if level.current >= 7 {
-            match rng.gen_range(0..3) { 0 => UfoType::Gunship, 1 => UfoType::Bomber, _ => UfoType::Scout }
-        } else if level.current >= 5 {
-            if rng.gen_bool(0.4) { UfoType::Gunship } else { UfoType::Scout }
-        } else {
-            UfoType::Scout
-        };
-        spawn_ufo_typed(&mut commands, &game_sprites, &wdw_size, ufo_type);
+        let ufo_type = roll_ufo_type(level.current, &mut rng);
+        let ufo = spawn_ufo_typed(&mut commands, &game_sprites, &wdw_size, ufo_type);
+        try_make_elite(&mut commands, ufo, level.current, EliteTarget::Ufo, ufo_type.max_hp(), &mut rng);
+    }
+}
+
+/// Weighted random UFO type; tougher types join the mix as the level rises.
+pub fn roll_ufo_type(level: u32, rng: &mut impl Rng) -> UfoType {
+    if level >= 9 {
+        match rng.gen_range(0..4) { 0 => UfoType::Gunship, 1 => UfoType::Bomber, 2 => UfoType::Sniper, _ => UfoType::Scout }
+    } else if level >= 7 {
+        match rng.gen_range(0..3) { 0 => UfoType::Gunship, 1 => UfoType::Bomber, _ => UfoType::Scout }
+    } else if level >= 5 {
+        if rng.gen_bool(0.4) { UfoType::Gunship } else { UfoType::Scout }
+    } else {
+        UfoType::Scout
     }
 }
 
@@ -95,15 +114,16 @@ pub fn spawn_ufo_typed(
     game_sprites: &GameSprites,
     wdw_size:     &WindowSize,
     ufo_type:     UfoType,
-) {
+) -> Entity {
     let mut rng = thread_rng();
 
-    let (texture, hp, speed, shoot_secs, phase_spd, scale) = match ufo_type {
-        UfoType::Scout   => (game_sprites.ufo.clone(),        3, 70.0_f32,  3.0_f32, 2.0_f32, 0.6_f32),
-        UfoType::Gunship => (game_sprites.ufo_yellow.clone(), 6, 38.0,      4.5,     1.2,     0.80),
-        UfoType::Bomber  => (game_sprites.ufo_green.clone(),  2, 130.0,     1.5,     3.0,     0.55),
-        UfoType::Sniper  => (game_sprites.ufo_blue.clone(),   2, 90.0,      2.0,     1.8,     0.55),
+    let (texture, speed, shoot_secs, phase_spd, scale) = match ufo_type {
+        UfoType::Scout   => (game_sprites.ufo.clone(),        70.0_f32,  3.0_f32, 2.0_f32, 0.6_f32),
+        UfoType::Gunship => (game_sprites.ufo_yellow.clone(), 38.0,      4.5,     1.2,     0.80),
+        UfoType::Bomber  => (game_sprites.ufo_green.clone(),  130.0,     1.5,     3.0,     0.55),
+        UfoType::Sniper  => (game_sprites.ufo_blue.clone(),   90.0,      2.0,     1.8,     0.55),
     };
+    let hp = ufo_type.max_hp();
 
     let from_left     = rng.gen_bool(0.5);
     let start_x       = if from_left { -wdw_size.w / 2.0 - 60.0 } else { wdw_size.w / 2.0 + 60.0 };
@@ -132,7 +152,7 @@ pub fn spawn_ufo_typed(
         },
         HitBoxSize(Vec2::new(80.0, 55.0)),
         CleanUpOnLevelEnd,
-    ));
+    )).id()
 }
 
 // ── Systems ───────────────────────────────────────────────────────────────────
@@ -205,7 +225,7 @@ fn ufo_shoot_system(
     }
 }
 
-fn fire_ufo_shot(
+pub fn fire_ufo_shot(
     commands:     &mut Commands,
     game_sprites: &GameSprites,
     origin:       Vec3,
@@ -216,7 +236,7 @@ fn fire_ufo_shot(
     let angle = dir.y.atan2(dir.x) - PI / 2.0;
     commands.spawn((
         SpriteBundle {
-            texture: game_sprites.projectile_attack.clone(),
+            texture: game_sprites.ufo_projectile.clone(),
             transform: Transform {
                 translation: Vec3::new(origin.x, origin.y, 4.0),
                 scale:       Vec3::splat(0.5),
@@ -0,0 +1,296 @@
+use std::f32::consts::PI;
+use bevy::prelude::*;
+
+use lib::{weapon_stats, WeaponArchetype, BEAM_WIDTH, CHARGE_TIME, HEAT_MAX, HEAT_RESUME};
+use crate::{
+    common::common_components::{HitBoxSize, Position, RotationAngle, Velocity},
+    objects::{
+        boss::BossComponent,
+        meteor::MeteorComponent,
+        projectile::{
+            projectile_shoot_system, weapon_cooldown, FireInput, ProjectileComponent, ProjectileDespawnComponent,
+        },
+        ufo::UfoComponent,
+    },
+    player::{
+        hyperspace::InHyperspace,
+        player::{PlayerComponent, PlayerShootCooldownComponent},
+        ship::ShipComponent,
+    },
+    resources::{GameSettings, IsPaused, PlayerBuff, PlayerUpgrades},
+    state::states::GameStates,
+    utils::{cleanup::CleanUpOnLevelEnd, run_stats::RunStats},
+};
+
+/// Seconds a beam hit pulse waits for a collision before it's dropped.
+const BEAM_PULSE_SECS: f32 = 0.1;
+const HEAT_BAR_SIZE:   Vec2 = Vec2::new(48.0, 5.0);
+/// How far below the ship the heat bar sits.
+const HEAT_BAR_OFFSET: f32 = 45.0;
+const HEAT_COOL_COLOR: Color = Color::rgb(1.0, 0.85, 0.3);
+const HEAT_HOT_COLOR:  Color = Color::rgb(1.0, 0.25, 0.15);
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// The ship's primary weapon, picked on the ship select screen.
+#[derive(Component)]
+pub struct PrimaryWeapon {
+    pub archetype:  WeaponArchetype,
+    /// Seconds the charge shot has been held, up to `CHARGE_TIME`.
+    pub charge:     f32,
+    /// Current heat, 0 to `HEAT_MAX`. Only builds with `GameSettings::weapon_heat` on.
+    pub heat:       f32,
+    /// Set on reaching `HEAT_MAX`; cleared once heat drops to `HEAT_RESUME`.
+    pub overheated: bool,
+}
+
+impl PrimaryWeapon {
+    pub fn new(archetype: WeaponArchetype) -> Self {
+        PrimaryWeapon { archetype, charge: 0.0, heat: 0.0, overheated: false }
+    }
+
+    pub fn charge_fraction(&self) -> f32 {
+        self.charge / CHARGE_TIME
+    }
+
+    pub fn add_heat(&mut self, amount: f32) {
+        self.heat = (self.heat + amount).min(HEAT_MAX);
+        if self.heat >= HEAT_MAX {
+            self.overheated = true;
+            self.charge     = 0.0;
+        }
+    }
+}
+
+/// Menu name of each primary weapon.
+pub fn weapon_name(weapon: WeaponArchetype) -> &'static str {
+    match weapon {
+        WeaponArchetype::Laser   => "LASER",
+        WeaponArchetype::Beam    => "BEAM",
+        WeaponArchetype::Charge  => "CHARGE SHOT",
+        WeaponArchetype::Shotgun => "SHOTGUN",
+        WeaponArchetype::Plasma  => "PLASMA",
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Archetype behaviour that doesn't fit the shoot system: the beam's raycast
+/// and damage ticks, the charge shot's build-up indicator, and weapon heat.
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    beam_system.after(projectile_shoot_system),
+                    charge_indicator_system,
+                    weapon_heat_system,
+                )
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            // The heat bar stays visible behind the pause overlay.
+            .add_systems(
+                Update,
+                heat_bar_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|s: Res<GameSettings>| s.weapon_heat),
+            );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// While fire is held, casts a beam along every shot direction and stops it at
+/// the first target, or further with Piercing Rounds. Damage lands each time
+/// the shoot cooldown runs out, so fire-rate and damage upgrades carry over.
+fn beam_system(
+    mut commands: Commands,
+    mut gizmos:   Gizmos,
+    fire:         FireInput,
+    buff:         Res<PlayerBuff>,
+    settings:     Res<GameSettings>,
+    upgrades:     Res<PlayerUpgrades>,
+    mut stats:    ResMut<RunStats>,
+    mut player_q: Query<
+        (&ShipComponent, &mut PrimaryWeapon, &RotationAngle, &Position, &mut PlayerShootCooldownComponent),
+        (With<PlayerComponent>, Without<InHyperspace>),
+    >,
+    target_q:     Query<(&Transform, &HitBoxSize), Or<(With<MeteorComponent>, With<UfoComponent>, With<BossComponent>)>>,
+) {
+    let Ok((ship, mut weapon, angle, pos, mut cooldown)) = player_q.get_single_mut() else { return };
+    let heat = settings.weapon_heat;
+    if weapon.archetype != WeaponArchetype::Beam || !fire.held() || (heat && weapon.overheated) {
+        return;
+    }
+
+    // Targets as circles that fit inside their hitbox.
+    let targets: Vec<(Vec2, f32)> = target_q
+        .iter()
+        .map(|(tf, hitbox)| (tf.translation.truncate(), (hitbox.0 * tf.scale.truncate()).min_element() / 2.0))
+        .collect();
+
+    let range  = upgrades.effective_projectile_range(ship.def()) * weapon_stats(weapon.archetype).range_mult;
+    let pierce = upgrades.pierce_count().max(0) as usize;
+    let tick   = cooldown.0.finished();
+    let color  = ship.def().color;
+
+    for offset in upgrades.shot_offsets() {
+        let dir  = Vec2::from_angle(angle.0 + offset + PI / 2.0);
+        let hits = beam_hits(pos.0, dir, range, &targets);
+        let end  = hits.get(pierce).copied().unwrap_or(range);
+        draw_beam(&mut gizmos, pos.0, pos.0 + dir * end, dir, color);
+
+        if tick {
+            // Each beam's tick is one shot for accuracy.
+            stats.shots_fired += 1;
+            stats.shots_hit   += !hits.is_empty() as u32;
+            for &t in hits.iter().take(pierce + 1) {
+                spawn_beam_pulse(&mut commands, pos.0 + dir * (t + BEAM_WIDTH), upgrades.bullet_damage());
+            }
+        }
+    }
+
+    if tick {
+        if heat && buff.overcharge_timer <= 0.0 {
+            let amount = upgrades.heat_per_shot(weapon.archetype);
+            weapon.add_heat(amount);
+        }
+        let cd = weapon_cooldown(&upgrades, &buff, ship.def(), weapon.archetype, heat);
+        cooldown.0 = Timer::from_seconds(cd, TimerMode::Once);
+    }
+}
+
+/// A ring ahead of the nose grows while a charge shot builds and flickers
+/// once it's full.
+fn charge_indicator_system(
+    mut gizmos: Gizmos,
+    time:       Res<Time>,
+    player_q:   Query<(&PlayerComponent, &ShipComponent, &PrimaryWeapon, &RotationAngle, &Position), Without<InHyperspace>>,
+) {
+    for (player, ship, weapon, angle, pos) in &player_q {
+        if weapon.charge <= 0.0 {
+            continue;
+        }
+        let fraction = weapon.charge_fraction();
+        if fraction >= 1.0 && (time.elapsed_seconds() * 10.0).fract() < 0.5 {
+            continue;
+        }
+        let center = pos.0 + player.direction(angle.0) * 40.0;
+        gizmos.circle_2d(center, 4.0 + 12.0 * fraction, ship.def().color);
+    }
+}
+
+/// Bleeds heat off the guns and unlocks them once they have cooled to
+/// `HEAT_RESUME`.
+fn weapon_heat_system(
+    time:         Res<Time>,
+    upgrades:     Res<PlayerUpgrades>,
+    mut weapon_q: Query<&mut PrimaryWeapon, With<PlayerComponent>>,
+) {
+    for mut weapon in &mut weapon_q {
+        if weapon.heat <= 0.0 {
+            continue;
+        }
+        weapon.heat = (weapon.heat - upgrades.heat_dissipation() * time.delta_seconds()).max(0.0);
+        if weapon.overheated && weapon.heat <= HEAT_RESUME {
+            weapon.overheated = false;
+        }
+    }
+}
+
+/// A bar under the ship that fills yellow to red with heat and blinks red
+/// while the guns are locked.
+fn heat_bar_system(
+    mut gizmos: Gizmos,
+    time:       Res<Time>,
+    player_q:   Query<(&PrimaryWeapon, &Position), (With<PlayerComponent>, Without<InHyperspace>)>,
+) {
+    for (weapon, pos) in &player_q {
+        if weapon.heat <= 0.0 {
+            continue;
+        }
+        let center   = pos.0 - Vec2::Y * HEAT_BAR_OFFSET;
+        let fraction = weapon.heat / HEAT_MAX;
+        let color    = if weapon.overheated {
+            HEAT_HOT_COLOR.with_a(if (time.elapsed_seconds() * 6.0).fract() < 0.5 { 1.0 } else { 0.3 })
+        } else {
+            lerp_color(HEAT_COOL_COLOR, HEAT_HOT_COLOR, fraction)
+        };
+
+        gizmos.rect_2d(center, 0.0, HEAT_BAR_SIZE, color.with_a(0.6));
+        // Fill with horizontal lines, since 2D gizmos have no filled rect.
+        let left  = center.x - HEAT_BAR_SIZE.x / 2.0;
+        let right = left + HEAT_BAR_SIZE.x * fraction;
+        let rows  = HEAT_BAR_SIZE.y as i32;
+        for row in 0..rows {
+            let y = center.y - HEAT_BAR_SIZE.y / 2.0 + row as f32 + 0.5;
+            gizmos.line_2d(Vec2::new(left, y), Vec2::new(right, y), color);
+        }
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Distances along the ray at which it enters each target in range, nearest first.
+fn beam_hits(origin: Vec2, dir: Vec2, range: f32, targets: &[(Vec2, f32)]) -> Vec<f32> {
+    let mut hits: Vec<f32> = targets
+        .iter()
+        .filter_map(|&(center, radius)| {
+            let radius    = radius + BEAM_WIDTH / 2.0;
+            let to_center = center - origin;
+            let along     = to_center.dot(dir);
+            let miss_sq   = to_center.length_squared() - along * along;
+            if along < 0.0 || miss_sq > radius * radius {
+                return None;
+            }
+            let t = (along - (radius * radius - miss_sq).sqrt()).max(0.0);
+            (t <= range).then_some(t)
+        })
+        .collect();
+    hits.sort_by(|a, b| a.total_cmp(b));
+    hits
+}
+
+/// Beam damage lands through a short-lived, invisible projectile so it takes
+/// the same hit paths as bullets: elite shields, metallic armour, boss weak
+/// points and on-kill upgrades all apply.
+fn spawn_beam_pulse(commands: &mut Commands, point: Vec2, damage: i32) {
+    commands.spawn((
+        TransformBundle::from_transform(Transform::from_translation(point.extend(5.0))),
+        ProjectileComponent {
+            damage,
+            origin:           point,
+            max_range:        f32::MAX,
+            pierce_remaining: 0,
+            hit_meteors:      Vec::new(),
+            kills:            0,
+        },
+        ProjectileDespawnComponent(Timer::from_seconds(BEAM_PULSE_SECS, TimerMode::Once)),
+        HitBoxSize(Vec2::splat(BEAM_WIDTH)),
+        Velocity(Vec2::ZERO),
+        Position(point),
+        CleanUpOnLevelEnd,
+        Name::new("BeamPulse"),
+    ));
+}
+
+fn draw_beam(gizmos: &mut Gizmos, start: Vec2, end: Vec2, dir: Vec2, color: Color) {
+    let side = dir.perp() * (BEAM_WIDTH / 4.0);
+    gizmos.line_2d(start, end, color);
+    gizmos.line_2d(start + side, end + side, color.with_a(0.5));
+    gizmos.line_2d(start - side, end - side, color.with_a(0.5));
+}
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let (a, b) = (from.as_rgba_f32(), to.as_rgba_f32());
+    Color::rgba(
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    )
+}
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+
+use lib::{
+    meteor_mass, AbilityKind, ABILITY_RECHARGE_PER_POWER, DASH_DISTANCE, DASH_DURATION,
+    DASH_IFRAMES, OVERCHARGE_DURATION, SHOCKWAVE_FORCE, SHOCKWAVE_RADIUS,
+};
+use crate::{
+    common::common_components::{Position, RotationAngle, Velocity},
+    input::{actions::{Action, Actions}, gamepad::PadInput, touch::TouchControls},
+    objects::meteor::MeteorComponent,
+    player::{hyperspace::InHyperspace, player::PlayerComponent, ship::{ShipComponent, ShipDef}},
+    resources::{CameraShake, IsPaused, PlayerBuff},
+    state::states::GameStates,
+    utils::cleanup::CleanUpOnLevelEnd,
+};
+
+/// Pad button for the ability (X on Xbox layouts).
+pub const ABILITY_BUTTON: GamepadButtonType = GamepadButtonType::West;
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// Energy meter for the ship's signature ability. Capacity comes from
+/// the ship's `Stats::shield`, recharge from `Stats::power`.
+#[derive(Component)]
+pub struct ShipAbility {
+    pub energy:     f32,
+    pub max_energy: f32,
+    /// Energy regained per second.
+    pub recharge:   f32,
+    pub cost:       f32,
+    /// Remaining dash movement (seconds) and its direction.
+    dash_time:      f32,
+    dash_dir:       Vec2,
+}
+
+impl ShipAbility {
+    pub fn new(ship: &ShipDef) -> Self {
+        ShipAbility {
+            energy:     ship.stats.shield,
+            max_energy: ship.stats.shield,
+            recharge:   ship.stats.power * ABILITY_RECHARGE_PER_POWER,
+            cost:       ship.ability_cost,
+            dash_time:  0.0,
+            dash_dir:   Vec2::ZERO,
+        }
+    }
+
+    pub fn ready(&self) -> bool {
+        self.energy >= self.cost
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max_energy <= 0.0 { 0.0 } else { self.energy / self.max_energy }
+    }
+}
+
+/// HUD name of each ability.
+pub fn ability_name(ability: AbilityKind) -> &'static str {
+    match ability {
+        AbilityKind::Dash       => "DASH",
+        AbilityKind::Shockwave  => "SHOCKWAVE",
+        AbilityKind::Overcharge => "OVERCHARGE",
+    }
+}
+
+/// Expanding ring left behind by a shockwave.
+#[derive(Component)]
+pub struct ShockwaveRing {
+    center: Vec2,
+    timer:  Timer,
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct AbilityPlugin;
+
+impl Plugin for AbilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                ability_input_system,
+                ability_update_system,
+                shockwave_ring_system,
+            )
+                .run_if(in_state(GameStates::InGame))
+                .run_if(|p: Res<IsPaused>| !p.0),
+        );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+fn ability_input_system(
+    mut commands: Commands,
+    actions:      Actions,
+    pad:          PadInput,
+    touch:        Res<TouchControls>,
+    mut buff:     ResMut<PlayerBuff>,
+    mut shake:    ResMut<CameraShake>,
+    mut player_q: Query<(&PlayerComponent, &ShipComponent, &mut ShipAbility, &RotationAngle, &Position), Without<InHyperspace>>,
+    mut meteor_q: Query<(&MeteorComponent, &Position, &mut Velocity)>,
+) {
+    if !actions.just_pressed(Action::Ability) && !pad.just_pressed(ABILITY_BUTTON) && !touch.ability {
+        return;
+    }
+    let Ok((player, ship, mut ability, angle, pos)) = player_q.get_single_mut() else { return };
+    if !ability.ready() {
+        return;
+    }
+    ability.energy -= ability.cost;
+
+    match ship.def().ability {
+        AbilityKind::Dash => {
+            ability.dash_time = DASH_DURATION;
+            ability.dash_dir  = player.direction(angle.0);
+            buff.dash_timer   = DASH_IFRAMES;
+        }
+        AbilityKind::Shockwave => {
+            // Push falls off with distance; heavy rocks budge less.
+            for (meteor, meteor_pos, mut vel) in &mut meteor_q {
+                let delta = meteor_pos.0 - pos.0;
+                let dist  = delta.length();
+                if dist > SHOCKWAVE_RADIUS {
+                    continue;
+                }
+                let falloff = 1.0 - dist / SHOCKWAVE_RADIUS;
+                vel.0 += delta.normalize_or_zero() * SHOCKWAVE_FORCE * falloff / meteor_mass(meteor.size).sqrt();
+            }
+            shake.trigger(3.0);
+            commands.spawn((
+                ShockwaveRing { center: pos.0, timer: Timer::from_seconds(0.35, TimerMode::Once) },
+                CleanUpOnLevelEnd,
+                Name::new("Shockwave"),
+            ));
+        }
+        AbilityKind::Overcharge => {
+            buff.overcharge_timer = OVERCHARGE_DURATION;
+        }
+    }
+}
+
+/// Recharges energy (paused while overcharged) and carries the ship through a dash.
+fn ability_update_system(
+    time:      Res<Time>,
+    buff:      Res<PlayerBuff>,
+    mut query: Query<(&mut ShipAbility, &mut Position), With<PlayerComponent>>,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut ability, mut pos) in &mut query {
+        if buff.overcharge_timer <= 0.0 {
+            ability.energy = (ability.energy + ability.recharge * dt).min(ability.max_energy);
+        }
+
+        if ability.dash_time > 0.0 {
+            let step = dt.min(ability.dash_time);
+            pos.0 += ability.dash_dir * (DASH_DISTANCE / DASH_DURATION) * step;
+            ability.dash_time -= step;
+        }
+    }
+}
+
+fn shockwave_ring_system(
+    mut commands: Commands,
+    mut gizmos:   Gizmos,
+    time:         Res<Time>,
+    mut query:    Query<(Entity, &mut ShockwaveRing)>,
+) {
+    for (entity, mut ring) in &mut query {
+        ring.timer.tick(time.delta());
+        let t = ring.timer.percent();
+        gizmos.circle_2d(ring.center, SHOCKWAVE_RADIUS * t, Color::rgba(0.4, 1.0, 0.6, 1.0 - t));
+        if ring.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
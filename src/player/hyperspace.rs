@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+
+use lib::{
+    MeteorSizeType, HYPERSPACE_APPEAR_TIME, HYPERSPACE_CLEARANCE, HYPERSPACE_COOLDOWN,
+    HYPERSPACE_MALFUNCTION_CHANCE, HYPERSPACE_MALFUNCTION_DAMAGE, HYPERSPACE_MAX_TRIES, HYPERSPACE_VANISH_TIME,
+};
+use crate::{
+    common::common_components::{DamageCollision, DamageSource, HitBoxSize, Position, Velocity},
+    effects::particle::spawn_explosion,
+    input::{
+        actions::{Action, Actions},
+        gamepad::PadInput,
+    },
+    objects::meteor::MeteorComponent,
+    player::player::PlayerComponent,
+    resources::{CameraShake, GameSettings, GameSprites, IsPaused, PlayerUpgrades, WindowSize},
+    state::states::GameStates,
+};
+
+/// Pad button for a jump (Y on Xbox layouts), in either scheme.
+pub const HYPERSPACE_BUTTON: GamepadButtonType = GamepadButtonType::North;
+
+/// Keeps destinations away from the screen edges.
+const EDGE_MARGIN: f32 = 60.0;
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// Hyperspace drive on the player ship; `cooldown` is seconds until the next jump.
+#[derive(Component, Default)]
+pub struct Hyperspace {
+    pub cooldown: f32,
+}
+
+/// Present while the ship is mid-jump. The ship can't move, shoot or be hit
+/// until it has fully reappeared.
+#[derive(Component)]
+pub struct InHyperspace {
+    timer:       Timer,
+    destination: Vec2,
+    scale:       Vec3,
+    arrived:     bool,
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct HyperspacePlugin;
+
+impl Plugin for HyperspacePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                hyperspace_input_system,
+                hyperspace_jump_system,
+            )
+                .run_if(in_state(GameStates::InGame))
+                .run_if(|p: Res<IsPaused>| !p.0),
+        );
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+fn hyperspace_input_system(
+    mut commands: Commands,
+    actions:      Actions,
+    mouse:        Res<Input<MouseButton>>,
+    pad:          PadInput,
+    settings:     Res<GameSettings>,
+    time:         Res<Time>,
+    wdw_size:     Res<WindowSize>,
+    mut player_q: Query<(Entity, &Transform, &mut Hyperspace, &mut Velocity), (With<PlayerComponent>, Without<InHyperspace>)>,
+    meteor_q:     Query<(&Position, &HitBoxSize), With<MeteorComponent>>,
+) {
+    let Ok((entity, tf, mut drive, mut vel)) = player_q.get_single_mut() else { return };
+
+    drive.cooldown = (drive.cooldown - time.delta_seconds()).max(0.0);
+    // Twin stick jumps on right-click; the Hyperspace keys move the ship there.
+    let pressed = pad.just_pressed(HYPERSPACE_BUTTON)
+        || if settings.twin_stick() {
+            mouse.just_pressed(MouseButton::Right)
+        } else {
+            actions.just_pressed(Action::Hyperspace)
+        };
+    if drive.cooldown > 0.0 || !pressed {
+        return;
+    }
+
+    let meteors: Vec<(Vec2, f32)> = meteor_q
+        .iter()
+        .map(|(pos, hitbox)| (pos.0, hitbox.0.max_element() * 0.5))
+        .collect();
+
+    drive.cooldown = HYPERSPACE_COOLDOWN;
+    vel.0 = Vec2::ZERO;
+    commands.entity(entity).insert(InHyperspace {
+        timer:       Timer::from_seconds(HYPERSPACE_VANISH_TIME + HYPERSPACE_APPEAR_TIME, TimerMode::Once),
+        destination: pick_destination(&wdw_size, &meteors),
+        scale:       tf.scale,
+        arrived:     false,
+    });
+}
+
+/// Shrinks the ship out, moves it, grows it back in, then rolls for a malfunction.
+fn hyperspace_jump_system(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    time:         Res<Time>,
+    upgrades:     Res<PlayerUpgrades>,
+    mut shake:    ResMut<CameraShake>,
+    mut query:    Query<(Entity, &mut InHyperspace, &mut Transform, &mut Position, &mut Velocity)>,
+) {
+    for (entity, mut jump, mut tf, mut pos, mut vel) in &mut query {
+        jump.timer.tick(time.delta());
+        vel.0 = Vec2::ZERO;
+
+        let elapsed = jump.timer.elapsed_secs();
+        let size = if elapsed < HYPERSPACE_VANISH_TIME {
+            1.0 - elapsed / HYPERSPACE_VANISH_TIME
+        } else {
+            if !jump.arrived {
+                pos.0 = jump.destination;
+                jump.arrived = true;
+            }
+            ((elapsed - HYPERSPACE_VANISH_TIME) / HYPERSPACE_APPEAR_TIME).min(1.0)
+        };
+        tf.scale = (jump.scale.truncate() * size).extend(jump.scale.z);
+
+        if !jump.timer.finished() {
+            continue;
+        }
+
+        tf.scale = jump.scale;
+        commands.entity(entity).remove::<InHyperspace>();
+
+        if !upgrades.stable_warp && thread_rng().gen::<f32>() < HYPERSPACE_MALFUNCTION_CHANCE {
+            shake.trigger(6.0);
+            spawn_explosion(&mut commands, &game_sprites, pos.0.extend(4.0), MeteorSizeType::Small);
+            commands.spawn((
+                DamageCollision(HYPERSPACE_MALFUNCTION_DAMAGE, DamageSource::Hyperspace),
+                Name::new("HyperspaceMalfunction"),
+            ));
+        }
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Random on-screen point clear of every meteor by `HYPERSPACE_CLEARANCE`.
+/// If no try is clear, the one with the most room wins.
+fn pick_destination(wdw_size: &WindowSize, meteors: &[(Vec2, f32)]) -> Vec2 {
+    let mut rng  = thread_rng();
+    let half     = Vec2::new(wdw_size.w, wdw_size.h) * 0.5 - Vec2::splat(EDGE_MARGIN);
+    let mut best = (Vec2::ZERO, f32::MIN);
+
+    for _ in 0..HYPERSPACE_MAX_TRIES {
+        let candidate = Vec2::new(rng.gen_range(-half.x..half.x), rng.gen_range(-half.y..half.y));
+        let room = meteors
+            .iter()
+            .map(|(pos, radius)| pos.distance(candidate) - radius)
+            .fold(f32::MAX, f32::min);
+
+        if room >= HYPERSPACE_CLEARANCE {
+            return candidate;
+        }
+        if room > best.1 {
+            best = (candidate, room);
+        }
+    }
+    best.0
+}
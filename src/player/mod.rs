@@ -1,2 +1,6 @@
+pub mod ability;
+pub mod hyperspace;
 pub mod player;
-pub mod ship;
\ No newline at end of file
+pub mod ship;
+pub mod skin;
+pub mod tractor;
\ No newline at end of file
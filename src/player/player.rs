@@ -1,4 +1,4 @@
-use std::{f32::consts::PI, time::Duration};
+use std::{f32::consts::{PI, TAU}, time::Duration};
 use bevy::prelude::*;
 use bevy_tweening::{
     EaseFunction,
@@ -7,19 +7,19 @@ use bevy_tweening::{
 };
 use rand::{thread_rng, Rng};
 
-use lib::{PLAYER_ACCELERATION, PLAYER_DECELERATION, PLAYER_SIZE, ShipType};
+use lib::{PLAYER_ACCELERATION, PLAYER_DECELERATION, PLAYER_SIZE, ICE_SLOW_MULT, HIT_INVULN_BLINK_RATE};
 use crate::{
     common::common_components::{Velocity, RotationAngle, HitBoxSize, Position, BoundsWarpable},
     effects::particle::ParticleComponent,
-    objects::projectile::projectile_shoot_system,
+    input::{actions::{Action, Actions}, gamepad::PadInput, touch::TouchControls},
+    objects::{projectile::projectile_shoot_system, weapon::PrimaryWeapon},
     resources::{
-        SHIP_NORMAL_SPRITE, SHIP_SHIELD_SPRITE, SHIP_ATTACK_SPRITE,
-        GameSprites, WindowSize, PlayerUpgrades, Life, ShipSelectState, PlayerBuff,
+        GameSprites, WindowSize, PlayerUpgrades, Life, ShipSelectState, PlayerBuff, GameSettings,
     },
     state::states::GameStates,
     utils::cleanup::CleanUpOnGameOver,
 };
-use super::ship::ShipComponent;
+use super::{ability::ShipAbility, hyperspace::{Hyperspace, InHyperspace}, ship::ShipComponent, skin::SkinProgress, tractor::TractorBeam};
 
 // ── Components ────────────────────────────────────────────────────────────────
 
@@ -67,17 +67,20 @@ pub struct PlayerPlugin;
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app
-            // InGame – movement and shooting
+            // InGame – movement and shooting (twin-stick movement lives in `input::twin_stick`)
             .add_systems(
                 Update,
-                (player_movement_system, projectile_shoot_system)
-                    .run_if(in_state(GameStates::InGame)),
+                player_movement_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|s: Res<GameSettings>| !s.twin_stick()),
             )
+            .add_systems(Update, projectile_shoot_system.run_if(in_state(GameStates::InGame)))
             .add_systems(
                 Update,
                 player_engine_trail_system.run_if(in_state(GameStates::InGame)),
             )
             .add_systems(Update, player_damage_flash_system)
+            .add_systems(Update, player_invuln_blink_system)
             .add_systems(Update, shield_effect_system)
             // GameOver – freeze the player by removing physics components
             .add_systems(OnEnter(GameStates::GameOver), player_on_death_system)
@@ -89,22 +92,45 @@ impl Plugin for PlayerPlugin {
 // ── Systems ───────────────────────────────────────────────────────────────────
 
 fn player_movement_system(
-    keyboard:  Res<Input<KeyCode>>,
+    actions:   Actions,
+    pad:       PadInput,
+    touch:     Res<TouchControls>,
     upgrades:  Res<PlayerUpgrades>,
-    mut query: Query<(&PlayerComponent, &mut Velocity, &mut RotationAngle)>,
+    buff:      Res<PlayerBuff>,
+    mut query: Query<(&PlayerComponent, &ShipComponent, &mut Velocity, &mut RotationAngle), Without<InHyperspace>>,
 ) {
-    if let Ok((player, mut velocity, mut angle)) = query.get_single_mut() {
-        let turn_speed = upgrades.effective_turn_speed();
-        let max_speed  = upgrades.effective_max_speed();
+    if let Ok((player, ship, mut velocity, mut angle)) = query.get_single_mut() {
+        // Ice meteors leave the ship sluggish for a moment.
+        let slow = if buff.slow_timer > 0.0 { ICE_SLOW_MULT } else { 1.0 };
+        let turn_speed = upgrades.effective_turn_speed() * slow;
+        let max_speed  = upgrades.effective_max_speed(ship.def()) * slow;
 
-        if keyboard.pressed(KeyCode::Left) {
+        // The left stick turns and thrusts in proportion to how far it is pushed.
+        let stick = pad.left_stick();
+
+        if actions.pressed(Action::TurnLeft) {
             angle.0 += turn_speed;
-        } else if keyboard.pressed(KeyCode::Right) {
+        } else if actions.pressed(Action::TurnRight) {
             angle.0 -= turn_speed;
+        } else if touch.stick != Vec2::ZERO {
+            // The touch joystick points where to go: swing the nose that way
+            // at the normal turn rate.
+            let target = touch.stick.y.atan2(touch.stick.x) - PI / 2.0;
+            let diff   = (target - angle.0 + PI).rem_euclid(TAU) - PI;
+            angle.0 += diff.clamp(-turn_speed, turn_speed);
+        } else {
+            angle.0 -= stick.x * turn_speed;
         }
 
-        if keyboard.pressed(KeyCode::Up) {
-            velocity.0 += player.direction(angle.0) * PLAYER_ACCELERATION;
+        let thrust = if actions.pressed(Action::Thrust) {
+            1.0
+        } else if touch.stick != Vec2::ZERO {
+            touch.stick.length()
+        } else {
+            stick.y.max(0.0)
+        };
+        if thrust > 0.0 {
+            velocity.0 += player.direction(angle.0) * PLAYER_ACCELERATION * thrust;
             if velocity.0.length() > max_speed {
                 velocity.0 = velocity.0.normalize_or_zero() * max_speed;
             }
@@ -131,6 +157,24 @@ fn player_damage_flash_system(
     }
 }
 
+/// Blinks the ship while post-hit invulnerability lasts. Outside of play the
+/// ship is always shown, so a hit right before game over can't leave it hidden.
+fn player_invuln_blink_system(
+    buff:      Res<PlayerBuff>,
+    state:     Res<State<GameStates>>,
+    mut query: Query<&mut Visibility, With<PlayerComponent>>,
+) {
+    let blinking = *state.get() == GameStates::InGame && buff.invuln_timer > 0.0;
+    let hidden   = blinking && (buff.invuln_timer * HIT_INVULN_BLINK_RATE) as u32 % 2 == 1;
+
+    for mut vis in &mut query {
+        let wanted = if hidden { Visibility::Hidden } else { Visibility::Inherited };
+        if *vis != wanted {
+            *vis = wanted;
+        }
+    }
+}
+
 /// Spawns/despawns a rotating shield ring around the player when the shield buff is active.
 fn shield_effect_system(
     mut commands:  Commands,
@@ -192,15 +236,25 @@ fn shield_effect_system(
 
 fn player_engine_trail_system(
     mut commands: Commands,
-    keyboard:     Res<Input<KeyCode>>,
+    actions:      Actions,
+    pad:          PadInput,
+    touch:        Res<TouchControls>,
+    settings:     Res<GameSettings>,
     game_sprites: Res<GameSprites>,
-    query:        Query<(&Position, &RotationAngle), With<PlayerComponent>>,
+    query:        Query<(&Position, &RotationAngle, &ShipComponent), With<PlayerComponent>>,
 ) {
-    if !keyboard.pressed(KeyCode::Up) {
+    let stick     = pad.left_stick();
+    let thrusting = touch.stick != Vec2::ZERO || if settings.twin_stick() {
+        [Action::Up, Action::Down, Action::TurnLeft, Action::TurnRight].iter().any(|a| actions.pressed(*a))
+            || stick != Vec2::ZERO
+    } else {
+        actions.pressed(Action::Thrust) || stick.y > 0.0
+    };
+    if !thrusting {
         return;
     }
 
-    let Ok((pos, angle)) = query.get_single() else { return };
+    let Ok((pos, angle, ship)) = query.get_single() else { return };
 
     let mut rng = thread_rng();
 
@@ -240,7 +294,7 @@ fn player_engine_trail_system(
                     ..default()
                 },
                 sprite: Sprite {
-                    color: Color::rgba(0.5, 0.8, 1.0, 0.85),
+                    color: ship.skin().trail,
                     ..default()
                 },
                 ..default()
@@ -289,39 +343,29 @@ fn player_fly_out_system(
 
 pub fn player_spawn_system(
     mut commands:        Commands,
-    asset_server:        Res<AssetServer>,
+    game_sprites:        Res<GameSprites>,
     wdw_size:            Res<WindowSize>,
     mut upgrades:        ResMut<PlayerUpgrades>,
     mut life:            ResMut<Life>,
     selected:            Res<ShipSelectState>,
+    skins:               Res<SkinProgress>,
+    settings:            Res<GameSettings>,
     mut ev_player_spawn: EventWriter<crate::events::events::PlayerSpawnEvent>,
 ) {
     ev_player_spawn.send(crate::events::events::PlayerSpawnEvent);
 
-    let ship = ShipComponent::new_type(selected.ship_type());
+    let ship    = ShipComponent::new(selected.selected, skins.equipped());
+    let def     = ship.def();
+    let ability = ShipAbility::new(def);
 
-    // Apply ship-type starting bonuses/penalties
-    match ship.ship_type {
-        ShipType::Attack => {
-            // Red ship: starts with Heavy Rounds level 1, but -1 max HP
-            upgrades.heavy_rounds = upgrades.heavy_rounds.max(1);
-            life.max_life     = (life.max_life - 1).max(1);
-            life.current_life = life.max_life;
-        }
-        ShipType::Shield => {
-            // Green ship: +2 max HP, but slightly slower
-            life.max_life     += 2;
-            life.current_life  = life.max_life;
-            upgrades.shield_speed_penalty = true;
-        }
-        ShipType::Normal => {}
+    // Starting HP adjustment and upgrades from the ship definition
+    life.max_life     = (life.max_life + def.hp_bonus).max(1);
+    life.current_life = life.max_life;
+    for upgrade in def.starting_upgrades {
+        upgrade.apply(&mut upgrades, &mut life);
     }
 
-    let sprite = match ship.ship_type {
-        ShipType::Attack => asset_server.load(SHIP_ATTACK_SPRITE),
-        ShipType::Normal => asset_server.load(SHIP_NORMAL_SPRITE),
-        ShipType::Shield => asset_server.load(SHIP_SHIELD_SPRITE),
-    };
+    let sprite = game_sprites.hull(&ship);
 
     let start_pos = Vec3::new(0.0, -wdw_size.h, 0.0);
 
@@ -355,7 +399,11 @@ pub fn player_spawn_system(
         .insert(Name::new("Player"))
         .insert(PlayerComponent)
         .insert(ship)
+        .insert(ability)
+        .insert(Hyperspace::default())
+        .insert(TractorBeam::default())
         .insert(PlayerShootCooldownComponent::default())
+        .insert(PrimaryWeapon::new(settings.weapon))
         .insert(HitBoxSize(PLAYER_SIZE))
         .insert(Velocity(Vec2::ZERO))
         .insert(Position(Vec2::ZERO))
@@ -1,41 +1,175 @@
 use bevy::prelude::*;
-use rand::prelude::*;
 
-use lib::{Stats, ShipType, DEFAULT_STATS};
+use lib::{AbilityKind, Stats};
 use crate::{
-    player::player::PlayerComponent,
-    resources::{GameSprites, SHIP_NORMAL_SPRITE, SHIP_ATTACK_SPRITE, SHIP_SHIELD_SPRITE},
+    player::{player::PlayerComponent, skin::{SkinDef, SKINS}},
+    resources::{
+        GameSprites, LIFE_ATTACK_SPRITE, LIFE_NORMAL_SPRITE, LIFE_RAVAGER_SPRITE, LIFE_SHIELD_SPRITE,
+        PROJECTILE_ATTACK_SPRITE, PROJECTILE_NORMAL_SPRITE, PROJECTILE_SHIELD_SPRITE, SHIP_ATTACK_SPRITE,
+        SHIP_INTERCEPTOR_SPRITE, SHIP_NORMAL_SPRITE, SHIP_RAVAGER_SPRITE, SHIP_SHIELD_SPRITE, SHIP_WARDEN_SPRITE,
+    },
+    upgrades::upgrades::UpgradeType,
 };
 
+// ── Ship definitions ──────────────────────────────────────────────────────────
+
+/// Everything that sets one hull apart. The ship select screen lists `SHIPS`
+/// in order, and `GameSprites::ships` loads their sprites in the same order.
+pub struct ShipDef {
+    pub name:              &'static str,
+    /// Card blurb on the ship select screen.
+    pub description:       &'static str,
+    /// Card accent, and the glow of beam, plasma, charge and drone shots.
+    pub color:             Color,
+    pub hull_sprite:       &'static str,
+    pub life_sprite:       &'static str,
+    pub projectile_sprite: &'static str,
+    /// Ability meter size (`shield`) and recharge (`power`).
+    pub stats:             Stats,
+    pub ability:           AbilityKind,
+    /// Energy spent per use of the ability.
+    pub ability_cost:      f32,
+    /// Added to the starting max HP.
+    pub hp_bonus:          i32,
+    pub max_speed_mult:    f32,
+    pub proj_speed_mult:   f32,
+    pub cooldown_mult:     f32,
+    pub range_mult:        f32,
+    /// Upgrades applied at launch, on top of any picked later.
+    pub starting_upgrades: &'static [UpgradeType],
+}
+
+pub const SHIPS: [ShipDef; 6] = [
+    ShipDef {
+        name:              "STANDARD",
+        description:       "Balanced fighter. No bonus.",
+        color:             Color::rgb(0.4, 0.6, 1.0),
+        hull_sprite:       SHIP_NORMAL_SPRITE,
+        life_sprite:       LIFE_NORMAL_SPRITE,
+        projectile_sprite: PROJECTILE_NORMAL_SPRITE,
+        stats:             Stats { shield: 75.0, power: 50.0, attack_cooldown: 1.0 },
+        ability:           AbilityKind::Dash,
+        ability_cost:      30.0,
+        hp_bonus:          0,
+        max_speed_mult:    1.0,
+        proj_speed_mult:   1.0,
+        cooldown_mult:     1.0,
+        range_mult:        1.0,
+        starting_upgrades: &[],
+    },
+    ShipDef {
+        name:              "GUARDIAN",
+        description:       "Starts with 2 extra HP.\nSlightly slower.",
+        color:             Color::rgb(0.4, 1.0, 0.5),
+        hull_sprite:       SHIP_SHIELD_SPRITE,
+        life_sprite:       LIFE_SHIELD_SPRITE,
+        projectile_sprite: PROJECTILE_SHIELD_SPRITE,
+        stats:             Stats { shield: 100.0, power: 25.0, attack_cooldown: 1.0 },
+        ability:           AbilityKind::Shockwave,
+        ability_cost:      40.0,
+        hp_bonus:          2,
+        max_speed_mult:    0.85,
+        proj_speed_mult:   0.80,
+        cooldown_mult:     1.30,
+        range_mult:        1.60,
+        starting_upgrades: &[],
+    },
+    ShipDef {
+        name:              "DESTROYER",
+        description:       "Starts with Heavy Rounds.\nCosts 1 max HP.",
+        color:             Color::rgb(1.0, 0.4, 0.4),
+        hull_sprite:       SHIP_ATTACK_SPRITE,
+        life_sprite:       LIFE_ATTACK_SPRITE,
+        projectile_sprite: PROJECTILE_ATTACK_SPRITE,
+        stats:             Stats { shield: 50.0, power: 100.0, attack_cooldown: 0.5 },
+        ability:           AbilityKind::Overcharge,
+        ability_cost:      50.0,
+        hp_bonus:          -1,
+        max_speed_mult:    1.0,
+        proj_speed_mult:   1.45,
+        cooldown_mult:     0.80,
+        range_mult:        0.65,
+        starting_upgrades: &[UpgradeType::HeavyRounds],
+    },
+    ShipDef {
+        name:              "INTERCEPTOR",
+        description:       "Starts with Afterburner\nand Quick Reflexes.\nShort-range guns.",
+        color:             Color::rgb(0.45, 0.85, 1.0),
+        hull_sprite:       SHIP_INTERCEPTOR_SPRITE,
+        life_sprite:       LIFE_NORMAL_SPRITE,
+        projectile_sprite: PROJECTILE_NORMAL_SPRITE,
+        stats:             Stats { shield: 60.0, power: 80.0, attack_cooldown: 1.0 },
+        ability:           AbilityKind::Dash,
+        ability_cost:      25.0,
+        hp_bonus:          0,
+        max_speed_mult:    1.15,
+        proj_speed_mult:   1.15,
+        cooldown_mult:     1.0,
+        range_mult:        0.80,
+        starting_upgrades: &[UpgradeType::Afterburner, UpgradeType::QuickReflexes],
+    },
+    ShipDef {
+        name:              "WARDEN",
+        description:       "Starts with a drone\nand 1 extra HP.\nSlow to fire.",
+        color:             Color::rgb(0.6, 1.0, 0.7),
+        hull_sprite:       SHIP_WARDEN_SPRITE,
+        life_sprite:       LIFE_SHIELD_SPRITE,
+        projectile_sprite: PROJECTILE_SHIELD_SPRITE,
+        stats:             Stats { shield: 90.0, power: 40.0, attack_cooldown: 1.0 },
+        ability:           AbilityKind::Shockwave,
+        ability_cost:      35.0,
+        hp_bonus:          1,
+        max_speed_mult:    0.95,
+        proj_speed_mult:   0.90,
+        cooldown_mult:     1.20,
+        range_mult:        1.20,
+        starting_upgrades: &[UpgradeType::Drone],
+    },
+    ShipDef {
+        name:              "RAVAGER",
+        description:       "Starts with Split Shot.\nCosts 1 max HP.",
+        color:             Color::rgb(1.0, 0.65, 0.3),
+        hull_sprite:       SHIP_RAVAGER_SPRITE,
+        life_sprite:       LIFE_RAVAGER_SPRITE,
+        projectile_sprite: PROJECTILE_ATTACK_SPRITE,
+        stats:             Stats { shield: 55.0, power: 90.0, attack_cooldown: 0.5 },
+        ability:           AbilityKind::Overcharge,
+        ability_cost:      55.0,
+        hp_bonus:          -1,
+        max_speed_mult:    1.0,
+        proj_speed_mult:   1.10,
+        cooldown_mult:     0.95,
+        range_mult:        0.75,
+        starting_upgrades: &[UpgradeType::SplitShot],
+    },
+];
+
+// ── Components ────────────────────────────────────────────────────────────────
+
 #[derive(Component)]
 pub struct ShipComponent {
-    pub ship_type: ShipType,
-    pub stats:     Stats,
+    /// Index into `SHIPS` and `GameSprites::ships`.
+    pub index: usize,
+    /// Index into `SKINS` and `GameSprites::skins`. Cosmetic only.
+    pub skin:  usize,
 }
 
 impl ShipComponent {
-    pub fn new() -> Self {
-        let ship_type = Self::random_type();
-        ShipComponent { ship_type, stats: Self::stats_for(ship_type) }
-    }
-
-    pub fn new_type(ship_type: ShipType) -> Self {
-        ShipComponent { ship_type, stats: Self::stats_for(ship_type) }
+    pub fn new(index: usize, skin: usize) -> Self {
+        ShipComponent { index: index.min(SHIPS.len() - 1), skin: skin.min(SKINS.len() - 1) }
     }
 
-    fn random_type() -> ShipType {
-        thread_rng().gen()
+    pub fn def(&self) -> &'static ShipDef {
+        &SHIPS[self.index]
     }
 
-    fn stats_for(ship_type: ShipType) -> Stats {
-        DEFAULT_STATS
-            .iter()
-            .find(|(st, _)| *st == ship_type)
-            .map(|(_, s)| *s)
-            .unwrap_or(DEFAULT_STATS[0].1)
+    pub fn skin(&self) -> &'static SkinDef {
+        &SKINS[self.skin]
     }
 }
 
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
 pub struct ShipPlugin;
 
 impl Plugin for ShipPlugin {
@@ -44,17 +178,12 @@ impl Plugin for ShipPlugin {
     }
 }
 
-/// Keeps the ship sprite in sync with the current ShipComponent type.
+/// Keeps the ship sprite in sync with the current ShipComponent.
 fn sync_ship_texture_system(
     game_sprites:   Res<GameSprites>,
     mut query:      Query<(&mut Handle<Image>, &ShipComponent), With<PlayerComponent>>,
 ) {
     for (mut tex, ship) in &mut query {
-        let new_handle = match ship.ship_type {
-            ShipType::Attack => game_sprites.ship_type_attack.clone(),
-            ShipType::Normal => game_sprites.ship_type_normal.clone(),
-            ShipType::Shield => game_sprites.ship_type_shield.clone(),
-        };
-        *tex = new_handle;
+        *tex = game_sprites.hull(ship);
     }
 }
@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+
+use crate::{
+    resources::{
+        LevelResource, Score, PROJECTILE_ATTACK_SPRITE, PROJECTILE_NORMAL_SPRITE, PROJECTILE_SHIELD_SPRITE,
+        SHIP_ATTACK_SPRITE, SHIP_INTERCEPTOR_SPRITE, SHIP_NORMAL_SPRITE, SHIP_RAVAGER_SPRITE, SHIP_SHIELD_SPRITE,
+        SHIP_WARDEN_SPRITE, SKIN_INFERNO_SPRITE, SKIN_MANTA_SPRITE, SKIN_VIPER_SPRITE,
+    },
+    state::states::GameStates,
+    utils::storage,
+};
+
+const SKINS_FILE: &str = "skins.cfg";
+
+/// Engine trail colour of the stock skin.
+pub const STOCK_TRAIL_COLOR: Color = Color::rgba(0.5, 0.8, 1.0, 0.85);
+
+// ── Skin definitions ──────────────────────────────────────────────────────────
+
+/// Progression milestone that unlocks a skin. Milestones count across every
+/// run and session.
+#[derive(Clone, Copy)]
+pub enum Unlock {
+    Free,
+    ReachLevel(u32),
+    Score(i32),
+    BossKills(u32),
+}
+
+impl Unlock {
+    pub fn describe(&self) -> String {
+        match self {
+            Unlock::Free          => "FREE".to_string(),
+            Unlock::ReachLevel(n) => format!("REACH LEVEL {n}"),
+            Unlock::Score(n)      => format!("SCORE {n}"),
+            Unlock::BossKills(1)  => "DEFEAT A BOSS".to_string(),
+            Unlock::BossKills(n)  => format!("DEFEAT {n} BOSSES"),
+        }
+    }
+}
+
+/// A purely cosmetic look for the ship. `GameSprites::skins` loads their
+/// sprites in `SKINS` order.
+pub struct SkinDef {
+    pub name:   &'static str,
+    /// Hull sprite; `None` keeps the ship's own.
+    pub hull:   Option<&'static str>,
+    /// Bolt sprite; `None` keeps the ship's own.
+    pub laser:  Option<&'static str>,
+    pub trail:  Color,
+    pub unlock: Unlock,
+}
+
+pub const SKINS: [SkinDef; 10] = [
+    SkinDef {
+        name:   "STOCK",
+        hull:   None,
+        laser:  None,
+        trail:  STOCK_TRAIL_COLOR,
+        unlock: Unlock::Free,
+    },
+    SkinDef {
+        name:   "COBALT",
+        hull:   Some(SHIP_NORMAL_SPRITE),
+        laser:  Some(PROJECTILE_NORMAL_SPRITE),
+        trail:  Color::rgba(0.4, 0.6, 1.0, 0.85),
+        unlock: Unlock::ReachLevel(3),
+    },
+    SkinDef {
+        name:   "EMERALD",
+        hull:   Some(SHIP_SHIELD_SPRITE),
+        laser:  Some(PROJECTILE_SHIELD_SPRITE),
+        trail:  Color::rgba(0.4, 1.0, 0.5, 0.85),
+        unlock: Unlock::ReachLevel(5),
+    },
+    SkinDef {
+        name:   "CRIMSON",
+        hull:   Some(SHIP_ATTACK_SPRITE),
+        laser:  Some(PROJECTILE_ATTACK_SPRITE),
+        trail:  Color::rgba(1.0, 0.45, 0.3, 0.85),
+        unlock: Unlock::BossKills(1),
+    },
+    SkinDef {
+        name:   "ARROW",
+        hull:   Some(SHIP_INTERCEPTOR_SPRITE),
+        laser:  Some(PROJECTILE_NORMAL_SPRITE),
+        trail:  Color::rgba(0.8, 0.95, 1.0, 0.85),
+        unlock: Unlock::Score(5_000),
+    },
+    SkinDef {
+        name:   "VIPER",
+        hull:   Some(SKIN_VIPER_SPRITE),
+        laser:  Some(PROJECTILE_SHIELD_SPRITE),
+        trail:  Color::rgba(0.8, 1.0, 0.3, 0.85),
+        unlock: Unlock::ReachLevel(10),
+    },
+    SkinDef {
+        name:   "HORNET",
+        hull:   Some(SHIP_RAVAGER_SPRITE),
+        laser:  Some(PROJECTILE_ATTACK_SPRITE),
+        trail:  Color::rgba(1.0, 0.75, 0.2, 0.85),
+        unlock: Unlock::BossKills(3),
+    },
+    SkinDef {
+        name:   "MANTA",
+        hull:   Some(SKIN_MANTA_SPRITE),
+        laser:  Some(PROJECTILE_SHIELD_SPRITE),
+        trail:  Color::rgba(0.7, 0.5, 1.0, 0.85),
+        unlock: Unlock::Score(20_000),
+    },
+    SkinDef {
+        name:   "SCARAB",
+        hull:   Some(SHIP_WARDEN_SPRITE),
+        laser:  Some(PROJECTILE_SHIELD_SPRITE),
+        trail:  Color::rgba(0.3, 1.0, 0.8, 0.85),
+        unlock: Unlock::ReachLevel(15),
+    },
+    SkinDef {
+        name:   "INFERNO",
+        hull:   Some(SKIN_INFERNO_SPRITE),
+        laser:  Some(PROJECTILE_ATTACK_SPRITE),
+        trail:  Color::rgba(1.0, 0.3, 0.1, 0.85),
+        unlock: Unlock::BossKills(5),
+    },
+];
+
+// ── Progress ──────────────────────────────────────────────────────────────────
+
+/// Best results across all runs, which unlock skins, plus the skin picked on
+/// the ship select screen. Loaded at startup and saved whenever it changes.
+#[derive(Resource, Default)]
+pub struct SkinProgress {
+    /// Index into `SKINS`.
+    pub selected:   usize,
+    pub best_level: u32,
+    pub best_score: i32,
+    pub boss_kills: u32,
+    /// Skins bought in the hangar. `MetaProgress` keeps the record; not
+    /// saved here.
+    pub purchased:  Vec<usize>,
+}
+
+impl SkinProgress {
+    pub fn unlocked(&self, skin: usize) -> bool {
+        if self.purchased.contains(&skin) {
+            return true;
+        }
+        match SKINS[skin].unlock {
+            Unlock::Free          => true,
+            Unlock::ReachLevel(n) => self.best_level >= n,
+            Unlock::Score(n)      => self.best_score >= n,
+            Unlock::BossKills(n)  => self.boss_kills >= n,
+        }
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        (0..SKINS.len()).filter(|i| self.unlocked(*i)).count()
+    }
+
+    /// The selected skin, or stock if it isn't unlocked (e.g. a hand-edited save).
+    pub fn equipped(&self) -> usize {
+        if self.selected < SKINS.len() && self.unlocked(self.selected) { self.selected } else { 0 }
+    }
+
+    /// Steps to the next unlocked skin left (`-1`) or right (`+1`), wrapping.
+    pub fn cycle(&mut self, dir: i32) {
+        let len     = SKINS.len() as i32;
+        let mut idx = self.equipped() as i32;
+        for _ in 0..len {
+            idx = (idx + dir).rem_euclid(len);
+            if self.unlocked(idx as usize) {
+                break;
+            }
+        }
+        self.selected = idx as usize;
+        self.save();
+    }
+
+    /// First locked skin in `SKINS` order, if any.
+    pub fn next_locked(&self) -> Option<&'static SkinDef> {
+        (0..SKINS.len()).find(|i| !self.unlocked(*i)).map(|i| &SKINS[i])
+    }
+
+    /// Saved progress, or a fresh start; unknown lines are skipped.
+    pub fn load() -> Self {
+        let mut progress = SkinProgress::default();
+        let Some(text) = storage::load(SKINS_FILE) else { return progress };
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let value = value.trim();
+            match key.trim() {
+                "selected"   => progress.selected   = value.parse().unwrap_or(0),
+                "best_level" => progress.best_level = value.parse().unwrap_or(0),
+                "best_score" => progress.best_score = value.parse().unwrap_or(0),
+                "boss_kills" => progress.boss_kills = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        progress
+    }
+
+    pub fn save(&self) {
+        let text = format!(
+            "selected={}\nbest_level={}\nbest_score={}\nboss_kills={}\n",
+            self.selected, self.best_level, self.best_score, self.boss_kills,
+        );
+        storage::save(SKINS_FILE, &text);
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct SkinPlugin;
+
+impl Plugin for SkinPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, skin_progress_system)
+            .add_systems(OnEnter(GameStates::GameOver), save_skin_progress);
+    }
+}
+
+/// Records new bests for the unlock milestones. A new level or boss kill
+/// saves straight away; score climbs every hit, so it's saved with the next
+/// level or at game over. A boss kill is counted once, when `boss_defeated`
+/// goes up.
+fn skin_progress_system(
+    level:        Res<LevelResource>,
+    score:        Res<Score>,
+    mut progress: ResMut<SkinProgress>,
+    mut counted:  Local<bool>,
+) {
+    if !level.is_changed() && !score.is_changed() {
+        return;
+    }
+    let mut improved = false;
+
+    if score.current > progress.best_score {
+        progress.best_score = score.current;
+    }
+    if level.current > progress.best_level {
+        progress.best_level = level.current;
+        improved = true;
+    }
+    if level.boss_defeated && !*counted {
+        progress.boss_kills += 1;
+        improved = true;
+    }
+    *counted = level.boss_defeated;
+
+    if improved {
+        progress.save();
+    }
+}
+
+fn save_skin_progress(progress: Res<SkinProgress>) {
+    progress.save();
+}
@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+
+use lib::{
+    MeteorSizeType, TRACTOR_COOLDOWN, TRACTOR_HOLD_GAP, TRACTOR_IMPACT_RADIUS, TRACTOR_RANGE, TRACTOR_THROW_DAMAGE,
+    TRACTOR_THROW_SPEED,
+};
+use crate::{
+    common::{
+        collision::spawn_area_blast,
+        common_components::{Held, HitBoxSize, Position, RotationAngle, Velocity},
+    },
+    input::{actions::{Action, Actions}, gamepad::PadInput},
+    objects::{
+        meteor::MeteorComponent,
+        ufo::{UfoComponent, UfoProjectileComponent},
+    },
+    player::{hyperspace::InHyperspace, player::PlayerComponent},
+    resources::{CameraShake, IsPaused},
+    state::states::GameStates,
+};
+
+/// Pad button for the tractor beam (left bumper).
+pub const TRACTOR_BUTTON: GamepadButtonType = GamepadButtonType::LeftTrigger;
+
+const BEAM_COLOR: Color = Color::rgba(0.55, 1.0, 0.85, 0.7);
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// Tractor beam on the player ship. Holding the button grabs the nearest
+/// Small or Medium meteor and carries it in front of the ship; letting go
+/// throws it.
+#[derive(Component, Default)]
+pub struct TractorBeam {
+    /// Meteor currently in the beam.
+    pub held:     Option<Entity>,
+    /// Seconds until the beam can grab again.
+    pub cooldown: f32,
+}
+
+/// A meteor thrown by the tractor beam. It bursts on the first meteor or
+/// UFO it touches and can't hurt the ship.
+#[derive(Component)]
+pub struct Thrown;
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct TractorPlugin;
+
+impl Plugin for TractorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    tractor_system,
+                    tractor_shield_system,
+                    thrown_impact_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            // The beam stays visible behind the pause overlay.
+            .add_systems(Update, tractor_draw_system.run_if(in_state(GameStates::InGame)));
+    }
+}
+
+// ── Systems ───────────────────────────────────────────────────────────────────
+
+/// Grabs, carries and throws. The held meteor is pinned in front of the nose
+/// every frame; releasing the button (or jumping to hyperspace) throws it
+/// along the ship's heading and starts the cooldown.
+fn tractor_system(
+    mut commands: Commands,
+    actions:      Actions,
+    pad:          PadInput,
+    time:         Res<Time>,
+    mut player_q: Query<(&PlayerComponent, &RotationAngle, &Position, &mut TractorBeam, Option<&InHyperspace>)>,
+    mut meteor_q: Query<(Entity, &MeteorComponent, &Transform, &HitBoxSize, &mut Position, &mut Velocity), Without<PlayerComponent>>,
+) {
+    let Ok((player, angle, ship_pos, mut beam, jumping)) = player_q.get_single_mut() else { return };
+    beam.cooldown = (beam.cooldown - time.delta_seconds()).max(0.0);
+
+    let holding = (actions.pressed(Action::Tractor) || pad.pressed(TRACTOR_BUTTON)) && jumping.is_none();
+    let dir     = player.direction(angle.0);
+
+    let Some(held) = beam.held else {
+        let grab = actions.just_pressed(Action::Tractor) || pad.just_pressed(TRACTOR_BUTTON);
+        if !grab || !holding || beam.cooldown > 0.0 {
+            return;
+        }
+        let nearest = meteor_q
+            .iter()
+            .filter(|(_, meteor, ..)| matches!(meteor.size, MeteorSizeType::Small | MeteorSizeType::Medium))
+            .map(|(entity, _, _, _, pos, _)| (entity, pos.0.distance(ship_pos.0)))
+            .filter(|(_, dist)| *dist <= TRACTOR_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((entity, _)) = nearest {
+            commands.entity(entity).insert(Held).remove::<Thrown>();
+            beam.held = Some(entity);
+        }
+        return;
+    };
+
+    // The rock was destroyed while in the beam.
+    let Ok((entity, _, tf, hitbox, mut pos, mut vel)) = meteor_q.get_mut(held) else {
+        beam.held     = None;
+        beam.cooldown = TRACTOR_COOLDOWN;
+        return;
+    };
+
+    if holding {
+        let radius = (hitbox.0 * tf.scale.truncate()).max_element() / 2.0;
+        pos.0 = ship_pos.0 + dir * (TRACTOR_HOLD_GAP + radius);
+        vel.0 = Vec2::ZERO;
+    } else {
+        vel.0 = dir * TRACTOR_THROW_SPEED;
+        commands.entity(entity).remove::<Held>().insert(Thrown);
+        beam.held     = None;
+        beam.cooldown = TRACTOR_COOLDOWN;
+    }
+}
+
+/// The held meteor soaks up UFO shots and shoves other meteors aside.
+fn tractor_shield_system(
+    mut commands: Commands,
+    held_q:       Query<(&Transform, &HitBoxSize), (With<MeteorComponent>, With<Held>)>,
+    ufo_proj_q:   Query<(Entity, &Transform), With<UfoProjectileComponent>>,
+    mut meteor_q: Query<(&Transform, &HitBoxSize, &mut Velocity), (With<MeteorComponent>, Without<Held>)>,
+) {
+    for (held_tf, held_hitbox) in &held_q {
+        let center = held_tf.translation.truncate();
+        let radius = (held_hitbox.0 * held_tf.scale.truncate()).min_element() / 2.0;
+
+        for (entity, tf) in &ufo_proj_q {
+            if tf.translation.truncate().distance(center) <= radius {
+                commands.entity(entity).despawn();
+            }
+        }
+
+        for (tf, hitbox, mut vel) in &mut meteor_q {
+            let offset = tf.translation.truncate() - center;
+            let reach  = radius + (hitbox.0 * tf.scale.truncate()).min_element() / 2.0;
+            if offset.length() > reach {
+                continue;
+            }
+            // Bounce off only while still closing in.
+            let normal  = offset.normalize_or_zero();
+            let closing = vel.0.dot(normal);
+            if closing < 0.0 {
+                vel.0 -= normal * 2.0 * closing;
+            }
+        }
+    }
+}
+
+/// A thrown meteor bursts on the first meteor or UFO it touches, damaging
+/// everything around the impact, itself included.
+fn thrown_impact_system(
+    mut commands: Commands,
+    mut shake:    ResMut<CameraShake>,
+    thrown_q:     Query<(Entity, &Transform, &HitBoxSize), With<Thrown>>,
+    target_q:     Query<
+        (Entity, &Transform, &HitBoxSize),
+        (Or<(With<MeteorComponent>, With<UfoComponent>)>, Without<Held>),
+    >,
+) {
+    for (entity, tf, hitbox) in &thrown_q {
+        let center = tf.translation.truncate();
+        let radius = (hitbox.0 * tf.scale.truncate()).min_element() / 2.0;
+
+        let hit = target_q.iter().any(|(target, target_tf, target_hitbox)| {
+            let reach = radius + (target_hitbox.0 * target_tf.scale.truncate()).min_element() / 2.0;
+            target != entity && target_tf.translation.truncate().distance(center) <= reach
+        });
+        if hit {
+            spawn_area_blast(&mut commands, tf.translation, TRACTOR_IMPACT_RADIUS, TRACTOR_THROW_DAMAGE, false);
+            commands.entity(entity).remove::<Thrown>();
+            shake.trigger(4.0);
+        }
+    }
+}
+
+/// A wavering beam from the nose to the held meteor, plus a ring around it.
+fn tractor_draw_system(
+    mut gizmos: Gizmos,
+    time:       Res<Time>,
+    player_q:   Query<(&PlayerComponent, &RotationAngle, &Position, &TractorBeam)>,
+    meteor_q:   Query<(&Transform, &HitBoxSize), With<Held>>,
+) {
+    let Ok((player, angle, pos, beam)) = player_q.get_single() else { return };
+    let Some((tf, hitbox)) = beam.held.and_then(|e| meteor_q.get(e).ok()) else { return };
+
+    let dir    = player.direction(angle.0);
+    let start  = pos.0 + dir * 20.0;
+    let end    = tf.translation.truncate();
+    let radius = (hitbox.0 * tf.scale.truncate()).max_element() / 2.0;
+    let wobble = dir.perp() * (time.elapsed_seconds() * 20.0).sin() * 3.0;
+
+    gizmos.line_2d(start, end, BEAM_COLOR);
+    gizmos.line_2d(start + wobble, end - wobble, BEAM_COLOR.with_a(0.35));
+    gizmos.line_2d(start - wobble, end + wobble, BEAM_COLOR.with_a(0.35));
+    gizmos.circle_2d(end, radius + 6.0, BEAM_COLOR);
+}
@@ -2,23 +2,36 @@ use bevy::prelude::*;
 use lib::{
     PLAYER_START_HP, PLAYER_SHOOT_COOLDOWN, PLAYER_MAX_SPEED, PLAYER_TURN_SPEED,
     BASE_LEVEL_ASTEROID_COUNT, ASTEROID_COUNT_PER_LEVEL, MAX_ASTEROIDS_PER_LEVEL,
-    BASE_ASTEROID_HP, ASTEROID_HP_SCALING_INTERVAL,
+    BASE_ASTEROID_HP, ASTEROID_HP_SCALING_INTERVAL, BOSS_LEVEL_INTERVAL,
     RAPID_FIRE_COOLDOWN_MULT, AFTERBURNER_SPEED_BONUS_PER_LEVEL,
     QUICK_REFLEXES_TURN_BONUS_PER_LEVEL, CHAIN_REACTION_COOLDOWN_MULT,
     COUNTDOWN_TICK_SECS, COUNTDOWN_GO_SECS,
     ACCELERATOR_SPEED_BONUS, ACCELERATOR_RANGE_PENALTY,
     LONG_SHOT_RANGE_BONUS, LONG_SHOT_SPEED_PENALTY,
     PIERCING_ROUNDS_SPEED_PENALTY, GLASS_CANNON_COOLDOWN_MULT, DETONATOR_RANGE_MULT,
-    ATTACK_SHIP_PROJ_SPEED_MULT, ATTACK_SHIP_COOLDOWN_MULT, ATTACK_SHIP_RANGE_MULT,
-    SHIELD_SHIP_PROJ_SPEED_MULT, SHIELD_SHIP_COOLDOWN_MULT, SHIELD_SHIP_RANGE_MULT,
     PROJECTILE_BASE_RANGE, BOLT_PROJ_SPEED_MULT,
-    PROJECTILE_SPEED, ShipType,
+    PROJECTILE_SPEED, DEFAULT_HIT_INVULN, HIT_INVULN_OPTIONS, HIT_INVULN_PER_ARMOR,
+    SECONDARY_AMMO, SecondaryWeapon, secondary_ammo, WEAPON_STATS, WeaponArchetype, weapon_stats,
+    HEAT_PER_SHOT, HEAT_DISSIPATION, HEAT_FIRE_RATE_MULT, RAPID_FIRE_HEAT_MULT,
+    CHAIN_REACTION_DISSIPATION_MULT, GLASS_CANNON_HEAT_MULT, DRONE_COOLDOWN, DRONE_OVERDRIVE_COOLDOWN_MULT,
+};
+use rand::{thread_rng, Rng, RngCore};
+use crate::{
+    objects::{secondary::secondary_name, weapon::weapon_name},
+    player::ship::{ShipComponent, ShipDef},
 };
 
 // ── Asset path constants ──────────────────────────────────────────────────────
-pub const SHIP_NORMAL_SPRITE:  &str = "sprites/ships/playerShip1_blue.png";
-pub const SHIP_ATTACK_SPRITE:  &str = "sprites/ships/playerShip1_red.png";
-pub const SHIP_SHIELD_SPRITE:  &str = "sprites/ships/playerShip1_green.png";
+pub const SHIP_NORMAL_SPRITE:      &str = "sprites/ships/playerShip1_blue.png";
+pub const SHIP_ATTACK_SPRITE:      &str = "sprites/ships/playerShip1_red.png";
+pub const SHIP_SHIELD_SPRITE:      &str = "sprites/ships/playerShip1_green.png";
+pub const SHIP_INTERCEPTOR_SPRITE: &str = "sprites/ships/playerShip2_blue.png";
+pub const SHIP_WARDEN_SPRITE:      &str = "sprites/ships/playerShip3_green.png";
+pub const SHIP_RAVAGER_SPRITE:     &str = "sprites/ships/playerShip2_red.png";
+// Hulls only worn as skins
+pub const SKIN_VIPER_SPRITE:       &str = "sprites/ships/playerShip2_green.png";
+pub const SKIN_MANTA_SPRITE:       &str = "sprites/ships/playerShip3_blue.png";
+pub const SKIN_INFERNO_SPRITE:     &str = "sprites/ships/playerShip3_red.png";
 
 // HP restore (tiered: Blue=standard, Green=enhanced, Red=rare)
 pub const POWERUP_HP_SPRITE:          &str = "sprites/powerup/powerupBlue_star.png";
@@ -32,6 +45,10 @@ pub const POWERUP_BOLT_SPRITE_RED:    &str = "sprites/powerup/powerupRed_bolt.pn
 pub const POWERUP_SHIELD_SPRITE:      &str = "sprites/powerup/powerupBlue_shield.png";
 pub const POWERUP_SHIELD_SPRITE_GREEN:&str = "sprites/powerup/powerupGreen_shield.png";
 pub const POWERUP_SHIELD_SPRITE_RED:  &str = "sprites/powerup/powerupRed_shield.png";
+// Secondary ammo
+pub const POWERUP_AMMO_SPRITE:        &str = "sprites/powerup/powerupBlue.png";
+pub const POWERUP_AMMO_SPRITE_GREEN:  &str = "sprites/powerup/powerupGreen.png";
+pub const POWERUP_AMMO_SPRITE_RED:    &str = "sprites/powerup/powerupRed.png";
 // Shield visual effect
 pub const SHIELD_EFFECT_SPRITE:       &str = "sprites/effects/shield1.png";
 
@@ -42,6 +59,7 @@ pub const PROJECTILE_SHIELD_SPRITE: &str = "sprites/laser/laserGreen01.png";
 pub const LIFE_NORMAL_SPRITE: &str = "sprites/ui/playerLife1_blue.png";
 pub const LIFE_ATTACK_SPRITE: &str = "sprites/ui/playerLife1_red.png";
 pub const LIFE_SHIELD_SPRITE: &str = "sprites/ui/playerLife1_green.png";
+pub const LIFE_RAVAGER_SPRITE: &str = "sprites/ui/playerLife1_orange.png";
 
 pub const METEOR_BIG_SPRITE: &str = "sprites/meteor/meteorGrey_big1.png";
 pub const METEOR_MED_SPRITE: &str = "sprites/meteor/meteorGrey_med1.png";
@@ -55,13 +73,15 @@ pub const UFO_SPRITE:        &str = "sprites/ufo/ufoRed.png";
 pub const UFO_BLUE_SPRITE:   &str = "sprites/ufo/ufoBlue.png";
 pub const UFO_GREEN_SPRITE:  &str = "sprites/ufo/ufoGreen.png";
 pub const UFO_YELLOW_SPRITE: &str = "sprites/ufo/ufoYellow.png";
+pub const CROSSHAIR_SPRITE:  &str = "sprites/ui/crosshair.png";
 
 // ── Preloaded sprite handles ──────────────────────────────────────────────────
 #[derive(Resource)]
 pub struct GameSprites {
-    pub ship_type_normal:  Handle<Image>,
-    pub ship_type_attack:  Handle<Image>,
-    pub ship_type_shield:  Handle<Image>,
+    /// Per-ship sprites, in `SHIPS` order.
+    pub ships:                 Vec<ShipSprites>,
+    /// Per-skin overrides, in `SKINS` order.
+    pub skins:                 Vec<SkinSprites>,
     // HP powerup tiers
     pub powerup_hp:            Handle<Image>,
     pub powerup_hp_green:      Handle<Image>,
@@ -74,14 +94,12 @@ pub struct GameSprites {
     pub powerup_shield:        Handle<Image>,
     pub powerup_shield_green:  Handle<Image>,
     pub powerup_shield_red:    Handle<Image>,
+    // Ammo powerup tiers
+    pub powerup_ammo:          Handle<Image>,
+    pub powerup_ammo_green:    Handle<Image>,
+    pub powerup_ammo_red:      Handle<Image>,
     // Shield visual effect
     pub shield_effect:         Handle<Image>,
-    pub projectile_normal: Handle<Image>,
-    pub projectile_attack: Handle<Image>,
-    pub projectile_shield: Handle<Image>,
-    pub life_normal:       Handle<Image>,
-    pub life_attack:       Handle<Image>,
-    pub life_shield:       Handle<Image>,
     pub meteor_big:        Handle<Image>,
     pub meteor_med:        Handle<Image>,
     pub meteor_sml:        Handle<Image>,
@@ -96,10 +114,38 @@ pub struct GameSprites {
     pub ufo_blue:          Handle<Image>,
     pub ufo_green:         Handle<Image>,
     pub ufo_yellow:        Handle<Image>,
+    pub ufo_projectile:    Handle<Image>,
+    // UI
+    pub crosshair:         Handle<Image>,
     // Font
     pub font:              Handle<Font>,
 }
 
+/// Hull, life icon and bolt for one entry of `SHIPS`.
+pub struct ShipSprites {
+    pub hull:       Handle<Image>,
+    pub life:       Handle<Image>,
+    pub projectile: Handle<Image>,
+}
+
+/// Hull and bolt overrides for one entry of `SKINS`; `None` keeps the ship's own.
+pub struct SkinSprites {
+    pub hull:       Option<Handle<Image>>,
+    pub projectile: Option<Handle<Image>>,
+}
+
+impl GameSprites {
+    /// Hull of a ship as its skin dresses it.
+    pub fn hull(&self, ship: &ShipComponent) -> Handle<Image> {
+        self.skins[ship.skin].hull.clone().unwrap_or_else(|| self.ships[ship.index].hull.clone())
+    }
+
+    /// Bolt of a ship as its skin dresses it.
+    pub fn projectile(&self, ship: &ShipComponent) -> Handle<Image> {
+        self.skins[ship.skin].projectile.clone().unwrap_or_else(|| self.ships[ship.index].projectile.clone())
+    }
+}
+
 // ── Camera shake ─────────────────────────────────────────────────────────────
 #[derive(Resource, Default)]
 pub struct CameraShake {
@@ -128,19 +174,18 @@ pub struct WindowDespawnBorder {
 }
 
 // ── Core game stats ───────────────────────────────────────────────────────────
+/// Score of the current run. Best scores live in `HighScores`.
 #[derive(Resource)]
 pub struct Score {
-    pub current:    i32,
-    pub high_score: i32,
+    pub current: i32,
 }
 
 impl Score {
     pub fn new(score: i32) -> Self {
-        Score { current: score, high_score: 0 }
+        Score { current: score }
     }
 
     pub fn reset(&mut self) {
-        self.high_score = self.high_score.max(self.current);
         self.current = 0;
     }
 }
@@ -178,11 +223,23 @@ pub struct LevelResource {
     /// Set to the number of asteroids spawned when a level begins.
     /// Used to prevent an instant level-complete on the first frame.
     pub total_asteroids_spawned: u32,
+    /// Set when the boss of a boss level has been destroyed.
+    pub boss_defeated: bool,
 }
 
 impl LevelResource {
     pub fn new() -> Self {
-        LevelResource { current: 1, total_asteroids_spawned: 0 }
+        LevelResource { current: 1, total_asteroids_spawned: 0, boss_defeated: false }
+    }
+
+    /// Every `BOSS_LEVEL_INTERVAL`th level is a boss fight.
+    pub fn is_boss_level(&self) -> bool {
+        self.current.is_multiple_of(BOSS_LEVEL_INTERVAL)
+    }
+
+    /// 1 for the first boss, 2 for the second, and so on.
+    pub fn boss_encounter(&self) -> u32 {
+        self.current / BOSS_LEVEL_INTERVAL
     }
 
     pub fn asteroids_for_level(&self) -> u32 {
@@ -198,11 +255,13 @@ impl LevelResource {
     pub fn advance(&mut self) {
         self.current += 1;
         self.total_asteroids_spawned = 0;
+        self.boss_defeated = false;
     }
 
     pub fn reset(&mut self) {
         self.current = 1;
         self.total_asteroids_spawned = 0;
+        self.boss_defeated = false;
     }
 }
 
@@ -253,17 +312,20 @@ pub struct PlayerUpgrades {
     pub quick_reflexes:   u32,  // 0-2
     pub long_shot:        u32,  // 0-2  longer range, slower bullet
     pub bulwark:          bool, //       35% chance to heal 1 HP on large kill
+    pub stable_warp:      bool, //       hyperspace never malfunctions
     // Special
     pub overclock:        bool,
     pub chain_reaction:   bool,
     pub asteroid_magnet:  bool,
     pub glass_cannon:     bool, //       double damage+speed, -1 max HP, slower fire
     pub detonator_rounds: bool, //       bullets explode at max range
+    // Drones
+    pub drones:           u32,  // 0-3  orbiting drones that fire on their own
+    pub drone_overdrive:  u32,  // 0-2  faster drone fire
+    pub drone_plating:    bool, //       each drone shrugs off one hit per life
     // Runtime (managed by systems, not upgrades)
     pub chain_active:     bool,
     pub chain_timer:      f32,
-    // Ship-type bonuses applied at spawn
-    pub shield_speed_penalty: bool,
 }
 
 impl PlayerUpgrades {
@@ -271,21 +333,40 @@ impl PlayerUpgrades {
         *self = PlayerUpgrades::default();
     }
 
-    pub fn effective_shoot_cooldown(&self, ship_type: ShipType) -> f32 {
-        let ship_mult = match ship_type {
-            ShipType::Attack => ATTACK_SHIP_COOLDOWN_MULT,
-            ShipType::Shield => SHIELD_SHIP_COOLDOWN_MULT,
-            ShipType::Normal => 1.0,
-        };
+    pub fn effective_shoot_cooldown(&self, ship: &ShipDef) -> f32 {
         let rapid_mult = RAPID_FIRE_COOLDOWN_MULT.powi(self.rapid_fire as i32);
         let chain_mult = if self.chain_active { CHAIN_REACTION_COOLDOWN_MULT } else { 1.0 };
         let cannon_mult = if self.glass_cannon { GLASS_CANNON_COOLDOWN_MULT } else { 1.0 };
-        PLAYER_SHOOT_COOLDOWN * ship_mult * rapid_mult * chain_mult * cannon_mult
+        PLAYER_SHOOT_COOLDOWN * ship.cooldown_mult * rapid_mult * chain_mult * cannon_mult
     }
 
-    pub fn effective_max_speed(&self) -> f32 {
-        let base = if self.shield_speed_penalty { PLAYER_MAX_SPEED * 0.85 } else { PLAYER_MAX_SPEED };
-        base * (1.0 + self.afterburner as f32 * AFTERBURNER_SPEED_BONUS_PER_LEVEL)
+    /// Shoot cooldown under the heat model. Rapid Fire and Chain Reaction work
+    /// on heat instead, so only the ship and Glass Cannon change it.
+    pub fn heat_shoot_cooldown(&self, ship: &ShipDef) -> f32 {
+        let cannon_mult = if self.glass_cannon { GLASS_CANNON_COOLDOWN_MULT } else { 1.0 };
+        PLAYER_SHOOT_COOLDOWN * ship.cooldown_mult * cannon_mult * HEAT_FIRE_RATE_MULT
+    }
+
+    /// Heat added by one shot (or beam tick) of `weapon`.
+    pub fn heat_per_shot(&self, weapon: WeaponArchetype) -> f32 {
+        let rapid_mult  = RAPID_FIRE_HEAT_MULT.powi(self.rapid_fire as i32);
+        let cannon_mult = if self.glass_cannon { GLASS_CANNON_HEAT_MULT } else { 1.0 };
+        HEAT_PER_SHOT * weapon_stats(weapon).cooldown_mult * rapid_mult * cannon_mult
+    }
+
+    /// Heat shed per second.
+    pub fn heat_dissipation(&self) -> f32 {
+        let chain_mult = if self.chain_active { CHAIN_REACTION_DISSIPATION_MULT } else { 1.0 };
+        HEAT_DISSIPATION * chain_mult
+    }
+
+    /// Post-hit invulnerability: the chosen base window plus Extra Armor's bonus.
+    pub fn hit_invuln_window(&self, base: f32) -> f32 {
+        base + self.extra_armor as f32 * HIT_INVULN_PER_ARMOR
+    }
+
+    pub fn effective_max_speed(&self, ship: &ShipDef) -> f32 {
+        PLAYER_MAX_SPEED * ship.max_speed_mult * (1.0 + self.afterburner as f32 * AFTERBURNER_SPEED_BONUS_PER_LEVEL)
     }
 
     pub fn effective_turn_speed(&self) -> f32 {
@@ -302,34 +383,33 @@ impl PlayerUpgrades {
         self.piercing_rounds as i32
     }
 
-    /// Effective projectile speed considering ship type, upgrades, and bolt buff.
-    pub fn effective_projectile_speed(&self, ship_type: ShipType, bolt_active: bool) -> f32 {
-        let ship_mult = match ship_type {
-            ShipType::Attack => ATTACK_SHIP_PROJ_SPEED_MULT,
-            ShipType::Shield => SHIELD_SHIP_PROJ_SPEED_MULT,
-            ShipType::Normal => 1.0,
-        };
+    /// Drones carry Heavy Rounds but not Glass Cannon.
+    pub fn drone_damage(&self) -> i32 {
+        1 + self.heavy_rounds as i32
+    }
+
+    pub fn drone_cooldown(&self) -> f32 {
+        DRONE_COOLDOWN * DRONE_OVERDRIVE_COOLDOWN_MULT.powi(self.drone_overdrive as i32)
+    }
+
+    /// Effective projectile speed considering the ship, upgrades, and bolt buff.
+    pub fn effective_projectile_speed(&self, ship: &ShipDef, bolt_active: bool) -> f32 {
         let upg_mult = 1.0
             + self.accelerator as f32 * ACCELERATOR_SPEED_BONUS
             - self.long_shot   as f32 * LONG_SHOT_SPEED_PENALTY
             - self.piercing_rounds as f32 * PIERCING_ROUNDS_SPEED_PENALTY
             + if self.glass_cannon { 0.50 } else { 0.0 };
         let bolt_mult = if bolt_active { BOLT_PROJ_SPEED_MULT } else { 1.0 };
-        (PROJECTILE_SPEED * ship_mult * upg_mult * bolt_mult).max(3.0)
+        (PROJECTILE_SPEED * ship.proj_speed_mult * upg_mult * bolt_mult).max(3.0)
     }
 
     /// Effective projectile range in world units.
-    pub fn effective_projectile_range(&self, ship_type: ShipType) -> f32 {
-        let ship_mult = match ship_type {
-            ShipType::Attack => ATTACK_SHIP_RANGE_MULT,
-            ShipType::Shield => SHIELD_SHIP_RANGE_MULT,
-            ShipType::Normal => 1.0,
-        };
+    pub fn effective_projectile_range(&self, ship: &ShipDef) -> f32 {
         let upg_mult = 1.0
             + self.long_shot  as f32 * LONG_SHOT_RANGE_BONUS
             - self.accelerator as f32 * ACCELERATOR_RANGE_PENALTY;
         let detonator_mult = if self.detonator_rounds { DETONATOR_RANGE_MULT } else { 1.0 };
-        (PROJECTILE_BASE_RANGE * ship_mult * upg_mult * detonator_mult).max(200.0)
+        (PROJECTILE_BASE_RANGE * ship.range_mult * upg_mult * detonator_mult).max(200.0)
     }
 
     /// Angle offsets (radians) for multi-shot patterns.
@@ -371,37 +451,260 @@ pub fn reset_upgrades(mut upgrades: ResMut<PlayerUpgrades>) {
 pub struct UpgradeSelectionState {
     pub choices:  Vec<crate::upgrades::upgrades::UpgradeType>,
     pub selected: usize,
+    /// Set by a boss kill; the next offer is guaranteed to contain a rare upgrade.
+    pub boss_reward: bool,
+    /// Free rerolls left this run, from the hangar.
+    pub rerolls:     u32,
+    /// A survival offer is open over the paused game; taking a card resumes
+    /// play instead of starting the next level.
+    pub mid_run:     bool,
 }
 
 // ── Ship selection state ──────────────────────────────────────────────────────
-/// Which ship (0=Normal, 1=Shield, 2=Attack) the player has highlighted or confirmed.
+/// Which ship the player has highlighted or confirmed, as an index into `SHIPS`.
 #[derive(Resource, Default)]
 pub struct ShipSelectState {
-    pub selected: usize, // 0=Normal, 1=Shield, 2=Attack
+    pub selected: usize,
+    /// Focused row: 0 = ship cards, 1 = skin, 2.. = `SettingRow::ALL[row - 2]`.
+    pub row:      usize,
+}
+
+// ── Game settings ─────────────────────────────────────────────────────────────
+/// Options chosen on the ship select screen. Kept across runs.
+#[derive(Resource)]
+pub struct GameSettings {
+    /// Elastic meteor–meteor collisions, UFO impact damage and bullet knockback.
+    /// Off by default since it changes how the game feels.
+    pub meteor_physics: bool,
+    /// Base seconds of invulnerability after taking a hit; one of `HIT_INVULN_OPTIONS`.
+    pub hit_invuln:     f32,
+    pub control_scheme: ControlScheme,
+    /// Primary weapon the ship launches with.
+    pub weapon:         WeaponArchetype,
+    /// Limit firing by heat instead of a plain cooldown. See `HEAT_MAX`.
+    pub weapon_heat:    bool,
+    /// Loadout weapon on the secondary fire key.
+    pub secondary:      SecondaryWeapon,
+    /// Rules the run is played under; high scores are kept per mode.
+    pub mode:           GameMode,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        GameSettings {
+            meteor_physics: false,
+            hit_invuln:     DEFAULT_HIT_INVULN,
+            control_scheme: ControlScheme::default(),
+            weapon:         WeaponArchetype::default(),
+            weapon_heat:    false,
+            secondary:      SecondaryWeapon::default(),
+            mode:           GameMode::default(),
+        }
+    }
+}
+
+impl GameSettings {
+    pub fn twin_stick(&self) -> bool {
+        self.control_scheme == ControlScheme::TwinStick
+    }
+}
+
+/// Rules a run is played under.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GameMode {
+    /// Clear every meteor to finish a level.
+    #[default]
+    Classic,
+    /// Meteors and UFOs never stop coming; upgrades are offered at score
+    /// thresholds and the run only ends on death.
+    Survival,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 2] = [GameMode::Classic, GameMode::Survival];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Classic  => "CLASSIC",
+            GameMode::Survival => "SURVIVAL",
+        }
+    }
+
+    /// Stable name used in save files.
+    pub fn id(&self) -> &'static str {
+        match self {
+            GameMode::Classic  => "Classic",
+            GameMode::Survival => "Survival",
+        }
+    }
+
+    /// Card text on the mode select screen.
+    pub fn description(&self) -> &'static str {
+        match self {
+            GameMode::Classic  => "Clear the field, pick an upgrade, repeat. Every fifth level is a boss.",
+            GameMode::Survival => "Endless waves that grow faster over time. Upgrades come with score.",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        GameMode::ALL.iter().position(|m| m == self).unwrap_or(0)
+    }
+}
+
+/// Run condition for systems that only apply to classic runs.
+pub fn is_classic(settings: Res<GameSettings>) -> bool {
+    settings.mode == GameMode::Classic
+}
+
+/// Run condition for systems that only apply to survival runs.
+pub fn is_survival(settings: Res<GameSettings>) -> bool {
+    settings.mode == GameMode::Survival
+}
+
+/// How the ship is flown.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum ControlScheme {
+    /// Arrow keys rotate and thrust, Space fires.
+    #[default]
+    Classic,
+    /// Up/Down and turn keys (WASD) move in screen directions, the ship faces
+    /// the mouse, left-click fires.
+    TwinStick,
 }
 
-impl ShipSelectState {
-    pub fn ship_type(&self) -> lib::ShipType {
-        match self.selected {
-            1 => lib::ShipType::Shield,
-            2 => lib::ShipType::Attack,
-            _ => lib::ShipType::Normal,
+impl ControlScheme {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ControlScheme::Classic   => "CLASSIC",
+            ControlScheme::TwinStick => "TWIN STICK",
         }
     }
 }
 
+/// Option rows listed under the ship cards on the select screen.
+#[derive(Clone, Copy, PartialEq)]
+pub enum SettingRow {
+    MeteorPhysics,
+    HitInvulnerability,
+    Controls,
+    Weapon,
+    WeaponHeat,
+    Secondary,
+}
+
+impl SettingRow {
+    pub const ALL: [SettingRow; 6] = [
+        SettingRow::MeteorPhysics, SettingRow::HitInvulnerability, SettingRow::Controls,
+        SettingRow::Weapon, SettingRow::WeaponHeat, SettingRow::Secondary,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingRow::MeteorPhysics      => "METEOR PHYSICS",
+            SettingRow::HitInvulnerability => "HIT INVULNERABILITY",
+            SettingRow::Controls           => "CONTROLS",
+            SettingRow::Weapon             => "WEAPON",
+            SettingRow::WeaponHeat         => "WEAPON HEAT",
+            SettingRow::Secondary          => "SECONDARY",
+        }
+    }
+
+    pub fn value(&self, settings: &GameSettings) -> String {
+        match self {
+            SettingRow::MeteorPhysics      => on_off(settings.meteor_physics).to_string(),
+            SettingRow::HitInvulnerability => format!("{:.1}s", settings.hit_invuln),
+            SettingRow::Controls           => settings.control_scheme.label().to_string(),
+            SettingRow::Weapon             => weapon_name(settings.weapon).to_string(),
+            SettingRow::WeaponHeat         => on_off(settings.weapon_heat).to_string(),
+            SettingRow::Secondary          => secondary_name(settings.secondary).to_string(),
+        }
+    }
+
+    /// Steps the setting left (`-1`) or right (`+1`).
+    pub fn cycle(&self, settings: &mut GameSettings, dir: i32) {
+        match self {
+            SettingRow::MeteorPhysics      => settings.meteor_physics = !settings.meteor_physics,
+            SettingRow::HitInvulnerability => {
+                settings.hit_invuln = step_option(&HIT_INVULN_OPTIONS, settings.hit_invuln, dir);
+            }
+            SettingRow::Controls => {
+                settings.control_scheme = match settings.control_scheme {
+                    ControlScheme::Classic   => ControlScheme::TwinStick,
+                    ControlScheme::TwinStick => ControlScheme::Classic,
+                };
+            }
+            SettingRow::Weapon     => settings.weapon      = step_table(&WEAPON_STATS, settings.weapon, dir),
+            SettingRow::WeaponHeat => settings.weapon_heat = !settings.weapon_heat,
+            SettingRow::Secondary  => settings.secondary   = step_table(&SECONDARY_AMMO, settings.secondary, dir),
+        }
+    }
+}
+
+fn on_off(on: bool) -> &'static str {
+    if on { "ON" } else { "OFF" }
+}
+
+/// Moves `current` one entry along `options`, wrapping at either end.
+fn step_option(options: &[f32], current: f32, dir: i32) -> f32 {
+    let len = options.len() as i32;
+    let idx = options.iter().position(|&o| o == current).unwrap_or(0) as i32;
+    options[(idx + dir).rem_euclid(len) as usize]
+}
+
+/// Moves `current` one key along a lookup table, wrapping at either end.
+fn step_table<K: Copy + PartialEq, V>(table: &[(K, V)], current: K, dir: i32) -> K {
+    let len = table.len() as i32;
+    let idx = table.iter().position(|(k, _)| *k == current).unwrap_or(0) as i32;
+    table[(idx + dir).rem_euclid(len) as usize].0
+}
+
 // ── Player buff state ─────────────────────────────────────────────────────────
 /// Active timed powerup buffs.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone)]
 pub struct PlayerBuff {
-    pub bolt_timer:   f32, // speed boost seconds remaining
-    pub shield_timer: f32, // invincibility seconds remaining
+    pub bolt_timer:       f32, // speed boost seconds remaining
+    pub shield_timer:     f32, // invincibility seconds remaining
+    pub slow_timer:       f32, // ice-meteor slow seconds remaining
+    pub dash_timer:       f32, // dash invulnerability seconds remaining
+    pub overcharge_timer: f32, // overcharge burst seconds remaining
+    pub invuln_timer:     f32, // post-hit invulnerability seconds remaining
 }
 
 pub fn reset_player_buff(mut buff: ResMut<PlayerBuff>) {
     *buff = PlayerBuff::default();
 }
 
+// ── Secondary weapon ─────────────────────────────────────────────────────────
+/// The secondary weapon picked at ship select and its remaining rounds.
+#[derive(Resource, Default)]
+pub struct SecondaryAmmo {
+    pub weapon:   SecondaryWeapon,
+    pub ammo:     i32,
+    pub max_ammo: i32,
+    /// Seconds until the weapon can fire again.
+    pub cooldown: f32,
+}
+
+impl SecondaryAmmo {
+    pub fn new(weapon: SecondaryWeapon) -> Self {
+        let (ammo, max_ammo) = secondary_ammo(weapon);
+        SecondaryAmmo { weapon, ammo, max_ammo, cooldown: 0.0 }
+    }
+
+    pub fn ready(&self) -> bool {
+        self.ammo > 0 && self.cooldown <= 0.0
+    }
+
+    /// Adds `rounds`, capped at `max_ammo`.
+    pub fn refill(&mut self, rounds: i32) {
+        self.ammo = (self.ammo + rounds).min(self.max_ammo);
+    }
+}
+
+pub fn reset_secondary_ammo(settings: Res<GameSettings>, mut ammo: ResMut<SecondaryAmmo>) {
+    *ammo = SecondaryAmmo::new(settings.secondary);
+}
+
 // ── Pause state ───────────────────────────────────────────────────────────────
 #[derive(Resource, Default)]
 pub struct IsPaused(pub bool);
@@ -409,3 +712,48 @@ pub struct IsPaused(pub bool);
 pub fn reset_paused(mut paused: ResMut<IsPaused>) {
     paused.0 = false;
 }
+
+// ── Run RNG ───────────────────────────────────────────────────────────────────
+/// Random source for rolls that shape a run, like upgrade offers. Seeded per
+/// run and saved with it, so a resumed run rolls exactly what it would have.
+/// SplitMix64: the whole state is one `u64`.
+#[derive(Resource)]
+pub struct RunRng {
+    pub state: u64,
+}
+
+impl Default for RunRng {
+    fn default() -> Self {
+        RunRng { state: thread_rng().gen() }
+    }
+}
+
+impl RngCore for RunRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+pub fn reset_run_rng(mut rng: ResMut<RunRng>) {
+    *rng = RunRng::default();
+}
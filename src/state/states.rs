@@ -9,11 +9,25 @@ use crate::{
         despawn_if_reached_bounds_system, warp_if_reached_window_bounds_system,
     },
     common::common_components::{Velocity, Position, RotationAngle},
-    player::player::{clean_up_player_tween, PlayerComponent},
-    resources::{reset_life, reset_score, reset_level, reset_upgrades, reset_player_buff, reset_paused, CountdownResource, IsPaused, LevelResource, WindowSize},
+    input::{
+        actions::{rebind_input_system, Action, Actions, RebindState},
+        gamepad::{PadInput, BACK_BUTTON, CONFIRM_BUTTON, PAUSE_BUTTON},
+    },
+    player::{player::{clean_up_player_tween, PlayerComponent}, ship::SHIPS, skin::SkinProgress},
+    resources::{
+        is_classic, reset_life, reset_score, reset_level, reset_upgrades, reset_player_buff, reset_paused,
+        reset_secondary_ammo, reset_run_rng,
+        CountdownResource, GameMode, GameSettings, IsPaused, LevelResource, SettingRow, UpgradeSelectionState,
+        WindowSize,
+    },
+    upgrades::hangar::{apply_hangar_bonuses, MetaProgress, HANGAR_BUTTON},
     utils::{
+        achievements::ACHIEVEMENTS_BUTTON,
         cleanup::{cleanup_system, CleanUpOnGameOver, CleanUpOnLevelEnd},
-        manager::{goto_countdown, goto_upgrade_selection},
+        highscores::{initials_input_system, InitialsEntry},
+        manager::goto_upgrade_selection,
+        run_stats::reset_run_stats,
+        savegame::{continue_run, goto_run_start, restore_run_system, SavedRun, CONTINUE_BUTTON},
     },
 };
 
@@ -21,15 +35,24 @@ use crate::{
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
 pub enum GameStates {
-    /// Ship selection screen. Player picks Normal/Shield/Attack before starting.
+    /// Mode selection screen, shown first. Confirm → ShipSelect.
     #[default]
+    ModeSelect,
+    /// Ship selection screen. Player picks a ship, skin and options before starting.
+    /// Escape returns to ModeSelect.
     ShipSelect,
+    /// Spends scrap earned across runs on permanent unlocks. Reached from and
+    /// returns to ShipSelect.
+    Hangar,
+    /// Lists every achievement and its progress. Reached from and returns to ShipSelect.
+    Achievements,
     /// Player spawns from off-screen; all resources reset. Auto-advances to Countdown.
     StartGame,
     /// 3–2–1–GO! countdown displayed before each level. Auto-advances to InGame.
     Countdown,
     /// Active gameplay: asteroids, shooting, collisions. Ends when all asteroids are
-    /// cleared (→ LevelComplete) or the player dies (→ GameOver).
+    /// cleared (→ LevelComplete) or the player dies (→ GameOver). A survival run
+    /// only ends on death; its upgrade offers open here over the paused game.
     InGame,
     /// Brief celebration screen after clearing a level. Auto-advances to UpgradeSelection.
     LevelComplete,
@@ -57,6 +80,40 @@ impl Plugin for BaseStatePlugin {
     }
 }
 
+// ── ModeSelect ────────────────────────────────────────────────────────────────
+
+pub struct ModeSelectStatePlugin;
+
+impl Plugin for ModeSelectStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            mode_select_input_system.run_if(in_state(GameStates::ModeSelect)),
+        );
+    }
+}
+
+/// The turn actions, or the D-pad, pick a mode; Confirm or A moves on to
+/// ship select with it.
+fn mode_select_input_system(
+    actions:        Actions,
+    pad:            PadInput,
+    mut settings:   ResMut<GameSettings>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    let len   = GameMode::ALL.len();
+    let index = settings.mode.index();
+    if actions.just_pressed(Action::TurnLeft) || pad.just_pressed(GamepadButtonType::DPadLeft) {
+        settings.mode = GameMode::ALL[(index + len - 1) % len];
+    }
+    if actions.just_pressed(Action::TurnRight) || pad.just_pressed(GamepadButtonType::DPadRight) {
+        settings.mode = GameMode::ALL[(index + 1) % len];
+    }
+    if actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON) {
+        next_state.set(GameStates::ShipSelect);
+    }
+}
+
 // ── ShipSelect ────────────────────────────────────────────────────────────────
 
 pub struct ShipSelectStatePlugin;
@@ -70,18 +127,68 @@ impl Plugin for ShipSelectStatePlugin {
     }
 }
 
+/// Up/Down moves between the ship cards (row 0), the skin (row 1) and the
+/// option rows below; the turn actions change whatever the focused row holds.
+/// On a pad the D-pad does the same, A starts and B jumps back to the ship
+/// cards, or from the ship cards to mode select, as Escape does. C, or Y on a
+/// pad, continues a saved run instead; H, or View on a pad, opens the hangar
+/// and T, or RB, the achievements. A ship still locked in the hangar can't
+/// start.
 fn ship_select_input_system(
-    kb:             Res<Input<KeyCode>>,
+    actions:        Actions,
+    pad:            PadInput,
     mut selection:  ResMut<crate::resources::ShipSelectState>,
+    mut settings:   ResMut<GameSettings>,
+    mut skins:      ResMut<SkinProgress>,
+    mut saved:      ResMut<SavedRun>,
+    meta:           Res<MetaProgress>,
     mut next_state: ResMut<NextState<GameStates>>,
 ) {
-    if kb.just_pressed(KeyCode::Left) || kb.just_pressed(KeyCode::A) {
-        selection.selected = (selection.selected + 2) % 3;
+    if saved.snapshot.is_some() && (actions.just_pressed(Action::Continue) || pad.just_pressed(CONTINUE_BUTTON)) {
+        continue_run(&mut saved, &mut selection, &mut settings, &mut skins, &mut next_state);
+        return;
+    }
+    if actions.just_pressed(Action::Hangar) || pad.just_pressed(HANGAR_BUTTON) {
+        next_state.set(GameStates::Hangar);
+        return;
     }
-    if kb.just_pressed(KeyCode::Right) || kb.just_pressed(KeyCode::D) {
-        selection.selected = (selection.selected + 1) % 3;
+    if actions.just_pressed(Action::Achievements) || pad.just_pressed(ACHIEVEMENTS_BUTTON) {
+        next_state.set(GameStates::Achievements);
+        return;
     }
-    if kb.just_pressed(KeyCode::Space) || kb.just_pressed(KeyCode::Return) {
+    if actions.just_pressed(Action::Pause) || (pad.just_pressed(BACK_BUTTON) && selection.row == 0) {
+        next_state.set(GameStates::ModeSelect);
+        return;
+    }
+
+    let rows = SettingRow::ALL.len() + 2;
+    if actions.just_pressed(Action::Up) || pad.just_pressed(GamepadButtonType::DPadUp) {
+        selection.row = (selection.row + rows - 1) % rows;
+    }
+    if actions.just_pressed(Action::Down) || pad.just_pressed(GamepadButtonType::DPadDown) {
+        selection.row = (selection.row + 1) % rows;
+    }
+    if pad.just_pressed(BACK_BUTTON) {
+        selection.row = 0;
+    }
+
+    let dir = if actions.just_pressed(Action::TurnLeft) || pad.just_pressed(GamepadButtonType::DPadLeft) {
+        -1
+    } else if actions.just_pressed(Action::TurnRight) || pad.just_pressed(GamepadButtonType::DPadRight) {
+        1
+    } else {
+        0
+    };
+    if dir != 0 {
+        match selection.row {
+            0 => selection.selected = (selection.selected as i32 + dir).rem_euclid(SHIPS.len() as i32) as usize,
+            1 => skins.cycle(dir),
+            r => SettingRow::ALL[r - 2].cycle(&mut settings, dir),
+        }
+    }
+
+    let confirmed = actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON);
+    if confirmed && meta.ship_unlocked(selection.selected) {
         next_state.set(GameStates::StartGame);
     }
 }
@@ -94,15 +201,22 @@ impl Plugin for StartGameStatePlugin {
     fn build(&self, app: &mut App) {
         app
             // Clean up any entities from the previous run, reset resources,
-            // then spawn the player — in strict order using apply_deferred.
+            // add the hangar bonuses, then spawn the player — in strict order
+            // using apply_deferred. A continued run then gets its saved
+            // resources back.
             .add_systems(
                 OnEnter(GameStates::StartGame),
                 (
                     (cleanup_system::<CleanUpOnGameOver>, cleanup_system::<CleanUpOnLevelEnd>),
                     apply_deferred,
-                    (reset_life, reset_score, reset_level, reset_upgrades, reset_player_buff, reset_paused),
+                    (
+                        reset_life, reset_score, reset_level, reset_upgrades, reset_player_buff, reset_paused,
+                        reset_secondary_ammo, reset_run_rng, reset_run_stats,
+                    ),
                     apply_deferred,
+                    apply_hangar_bonuses,
                     crate::player::player::player_spawn_system,
+                    restore_run_system,
                 )
                     .chain(),
             )
@@ -111,6 +225,11 @@ impl Plugin for StartGameStatePlugin {
                 OnTransition { from: GameStates::StartGame, to: GameStates::Countdown },
                 clean_up_player_tween,
             )
+            // Same for a continued run that resumes on the upgrade screen.
+            .add_systems(
+                OnTransition { from: GameStates::StartGame, to: GameStates::UpgradeSelection },
+                clean_up_player_tween,
+            )
             // Between levels: fly ship in from bottom again.
             .add_systems(
                 OnTransition { from: GameStates::UpgradeSelection, to: GameStates::Countdown },
@@ -121,10 +240,11 @@ impl Plugin for StartGameStatePlugin {
                 OnTransition { from: GameStates::Countdown, to: GameStates::InGame },
                 (clean_up_player_tween, center_player_on_level_start).chain(),
             )
-            // After the spawn animation, move to the countdown.
+            // After the spawn animation, move to the countdown, or back to
+            // the screen a continued run was saved on.
             .add_systems(
                 Update,
-                goto_countdown
+                goto_run_start
                     .run_if(in_state(GameStates::StartGame))
                     .run_if(bevy::time::common_conditions::on_timer(
                         std::time::Duration::from_secs_f32(1.8),
@@ -182,10 +302,11 @@ pub struct InGameStatePlugin;
 impl Plugin for InGameStatePlugin {
     fn build(&self, app: &mut App) {
         app
-            // Spawn this level's asteroids when entering play.
+            // Spawn this level's asteroids when entering play. Survival streams
+            // its own in instead.
             .add_systems(
                 OnEnter(GameStates::InGame),
-                crate::objects::meteor::spawn_level_asteroids,
+                crate::objects::meteor::spawn_level_asteroids.run_if(is_classic),
             )
             // Bounds systems run only during active gameplay and while not paused.
             .add_systems(
@@ -194,10 +315,13 @@ impl Plugin for InGameStatePlugin {
                     .run_if(in_state(GameStates::InGame))
                     .run_if(|p: Res<IsPaused>| !p.0),
             )
-            // Pause toggle and restart from pause menu.
+            // Pause toggle and restart from pause menu. Runs before the
+            // rebinding screen so the key that closes it doesn't also unpause.
             .add_systems(
                 Update,
-                pause_input_system.run_if(in_state(GameStates::InGame)),
+                pause_input_system
+                    .before(rebind_input_system)
+                    .run_if(in_state(GameStates::InGame)),
             )
             // Remove leftover projectiles and powerups when leaving InGame.
             .add_systems(OnExit(GameStates::InGame), cleanup_system::<CleanUpOnLevelEnd>);
@@ -205,20 +329,28 @@ impl Plugin for InGameStatePlugin {
 }
 
 fn pause_input_system(
-    kb:             Res<Input<KeyCode>>,
+    actions:        Actions,
+    pad:            PadInput,
+    rebind:         Res<RebindState>,
+    offer:          Res<UpgradeSelectionState>,
     mut paused:     ResMut<IsPaused>,
     mut next_state: ResMut<NextState<GameStates>>,
 ) {
-    if kb.just_pressed(KeyCode::Escape) {
+    // The rebinding screen owns the keyboard while it's open, and a survival
+    // upgrade offer holds the pause until a card is taken.
+    if rebind.open || offer.mid_run {
+        return;
+    }
+    if actions.just_pressed(Action::Pause) || pad.just_pressed(PAUSE_BUTTON) {
         paused.0 = !paused.0;
     }
-    // While paused: Enter/Space restarts; R resumes.
+    // While paused: Confirm/A restarts; Back/B resumes.
     if paused.0 {
-        if kb.just_pressed(KeyCode::Space) || kb.just_pressed(KeyCode::Return) {
+        if actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON) {
             paused.0 = false;
             next_state.set(GameStates::ShipSelect);
         }
-        if kb.just_pressed(KeyCode::R) {
+        if actions.just_pressed(Action::Back) || pad.just_pressed(BACK_BUTTON) {
             paused.0 = false;
         }
     }
@@ -268,16 +400,26 @@ impl Plugin for GameOverStatePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            game_over_input_system.run_if(in_state(GameStates::GameOver)),
+            // Play-again input runs first, so the press that saves initials
+            // doesn't also restart.
+            (game_over_input_system, initials_input_system)
+                .chain()
+                .run_if(in_state(GameStates::GameOver)),
         );
     }
 }
 
-fn game_over_input_system(
-    kb:             Res<Input<KeyCode>>,
+pub fn game_over_input_system(
+    actions:        Actions,
+    pad:            PadInput,
+    touches:        Res<Touches>,
+    entry:          Res<InitialsEntry>,
     mut next_state: ResMut<NextState<GameStates>>,
 ) {
-    if kb.just_pressed(KeyCode::Space) || kb.just_pressed(KeyCode::Return) {
+    if entry.active {
+        return;
+    }
+    if actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON) || touches.any_just_pressed() {
         next_state.set(GameStates::ShipSelect);
     }
 }
@@ -1,11 +1,35 @@
 use bevy::prelude::*;
 
-use lib::ShipType;
+use lib::{ACHIEVEMENT_TOAST_SECS, PLAYER_START_HP};
 use crate::{
-    player::{ship::ShipComponent, player::PlayerComponent},
-    resources::{CountdownResource, GameSprites, IsPaused, Life, LevelResource, PlayerBuff, PlayerUpgrades, Score, ShipSelectState, UpgradeSelectionState},
+    input::{
+        actions::{key_name, Action, ActionMap, RebindState, MAX_BINDINGS},
+        gamepad::{InputDevice, PromptText},
+    },
+    objects::{boss::BossComponent, secondary::secondary_name, survival::SurvivalState},
+    player::{
+        ability::{ability_name, ShipAbility},
+        ship::{ShipComponent, SHIPS},
+        skin::{SkinProgress, SKINS},
+        player::PlayerComponent,
+    },
+    resources::{
+        CountdownResource, GameMode, GameSettings, GameSprites, IsPaused, Life, LevelResource, PlayerBuff,
+        PlayerUpgrades, Score, SecondaryAmmo, SettingRow, ShipSelectState, UpgradeSelectionState,
+    },
     state::states::GameStates,
-    upgrades::upgrades::UpgradeType,
+    upgrades::{
+        hangar::{award_scrap, HangarState, ItemStatus, MetaProgress, HANGAR_ITEMS},
+        upgrades::{UpgradeType, UpgradesRerolled},
+    },
+    utils::{
+        achievements::{AchievementProgress, ACHIEVEMENTS},
+        highscores::{begin_initials_entry, HighScores, InitialsEntry, INITIALS_LEN, TABLE_SIZE},
+        run_stats::{
+            finish_level_timer, format_level_times, RunStats, DAMAGE_LABELS, METEOR_LABELS, POWERUP_LABELS, UFO_LABELS,
+        },
+        savegame::SavedRun,
+    },
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -17,10 +41,22 @@ pub struct UIPlugin;
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
         app
+            // Mode select screen
+            .add_systems(OnEnter(GameStates::ModeSelect),  spawn_mode_select_ui)
+            .add_systems(OnExit(GameStates::ModeSelect),   despawn_mode_select_ui)
+            .add_systems(Update, update_mode_select_ui.run_if(in_state(GameStates::ModeSelect)))
             // Ship select screen
             .add_systems(OnEnter(GameStates::ShipSelect),  spawn_ship_select_ui)
             .add_systems(OnExit(GameStates::ShipSelect),   despawn_ship_select_ui)
             .add_systems(Update, update_ship_select_ui.run_if(in_state(GameStates::ShipSelect)))
+            // Hangar
+            .add_systems(OnEnter(GameStates::Hangar),      spawn_hangar_ui)
+            .add_systems(OnExit(GameStates::Hangar),       despawn_hangar_ui)
+            .add_systems(Update, update_hangar_ui.run_if(in_state(GameStates::Hangar)))
+            // Achievements screen, and the unlock toast over any screen
+            .add_systems(OnEnter(GameStates::Achievements), spawn_achievements_ui)
+            .add_systems(OnExit(GameStates::Achievements),  despawn_achievements_ui)
+            .add_systems(Update, achievement_toast_system)
             // HUD
             .add_systems(OnEnter(GameStates::StartGame),   spawn_hud)
             .add_systems(OnExit(GameStates::GameOver),     despawn_hud)
@@ -28,6 +64,8 @@ impl Plugin for UIPlugin {
             .add_systems(Update, update_life_ui)
             .add_systems(Update, update_level_ui)
             .add_systems(Update, update_buff_ui)
+            .add_systems(Update, update_ability_ui)
+            .add_systems(Update, update_secondary_ui)
             // Countdown
             .add_systems(OnEnter(GameStates::Countdown),   spawn_countdown_ui)
             .add_systems(OnExit(GameStates::Countdown),    despawn_countdown_ui)
@@ -35,6 +73,10 @@ impl Plugin for UIPlugin {
             // Level indicator
             .add_systems(OnEnter(GameStates::InGame),      spawn_level_indicator)
             .add_systems(Update, fade_level_indicator.run_if(in_state(GameStates::InGame)))
+            // Boss HP bar
+            .add_systems(OnEnter(GameStates::InGame),      spawn_boss_bar)
+            .add_systems(OnExit(GameStates::InGame),       despawn_boss_bar)
+            .add_systems(Update, update_boss_bar.run_if(in_state(GameStates::InGame)))
             // Level-clear screen
             .add_systems(OnEnter(GameStates::LevelComplete),  spawn_level_clear_ui)
             .add_systems(OnExit(GameStates::LevelComplete),   despawn_level_clear_ui)
@@ -42,12 +84,30 @@ impl Plugin for UIPlugin {
             .add_systems(OnEnter(GameStates::UpgradeSelection),  spawn_upgrade_ui)
             .add_systems(OnExit(GameStates::UpgradeSelection),   despawn_upgrade_ui)
             .add_systems(Update, update_upgrade_ui.run_if(in_state(GameStates::UpgradeSelection)))
+            .add_systems(
+                Update,
+                (despawn_upgrade_ui, spawn_upgrade_ui)
+                    .chain()
+                    .run_if(on_event::<UpgradesRerolled>()),
+            )
+            // Survival offers open and close over the paused game
+            .add_systems(Update, spawn_upgrade_ui.run_if(in_state(GameStates::InGame)).run_if(offer_opened))
+            .add_systems(Update, despawn_upgrade_ui.run_if(in_state(GameStates::InGame)).run_if(offer_closed))
+            .add_systems(Update, update_upgrade_ui.run_if(in_state(GameStates::InGame)))
+            .add_systems(OnExit(GameStates::InGame),       despawn_upgrade_ui)
             // Game-over
-            .add_systems(OnEnter(GameStates::GameOver),    spawn_game_over_ui)
+            .add_systems(
+                OnEnter(GameStates::GameOver),
+                spawn_game_over_ui.after(begin_initials_entry).after(finish_level_timer).after(award_scrap),
+            )
             .add_systems(OnExit(GameStates::GameOver),     despawn_game_over_ui)
+            .add_systems(Update, update_game_over_ui.run_if(in_state(GameStates::GameOver)))
             // Pause overlay
             .add_systems(Update, update_pause_ui.run_if(in_state(GameStates::InGame)))
-            .add_systems(OnExit(GameStates::InGame),       despawn_pause_ui);
+            .add_systems(OnExit(GameStates::InGame),       despawn_pause_ui)
+            // Key rebinding (opened from the pause overlay)
+            .add_systems(Update, update_rebind_ui.run_if(in_state(GameStates::InGame)))
+            .add_systems(OnExit(GameStates::InGame),       despawn_rebind_ui);
     }
 }
 
@@ -61,23 +121,60 @@ impl Plugin for UIPlugin {
 #[derive(Component)] struct BuffText;
 #[derive(Component)] struct LifeShipIcon;
 #[derive(Component)] struct LifeCountText;
+#[derive(Component)] struct AbilityLabel;
+#[derive(Component)] struct AbilityEnergyFill;
+#[derive(Component)] struct SecondaryAmmoText;
+
+#[derive(Component)] struct ModeSelectRoot;
+#[derive(Component)] pub struct ModeCard(pub usize);
 
 #[derive(Component)] struct ShipSelectRoot;
-#[derive(Component)] struct ShipSelectCard(usize);
+#[derive(Component)] pub struct ShipSelectCard(pub usize);
+#[derive(Component)] struct ShipSelectCardImage(usize);
+#[derive(Component)] struct ShipSelectOptionRow(usize);
+#[derive(Component)] pub struct ContinueBanner;
+#[derive(Component)] pub struct ModeButton;
+#[derive(Component)] pub struct HangarButton;
+
+#[derive(Component)] struct HangarRoot;
+#[derive(Component)] pub struct HangarRow(pub usize);
+#[derive(Component)] struct HangarStatusText(usize);
+#[derive(Component)] struct HangarScrapText;
+#[derive(Component)] pub struct HangarBackButton;
+
+#[derive(Component)] pub struct AchievementsButton;
+#[derive(Component)] struct AchievementsRoot;
+#[derive(Component)] pub struct AchievementsBackButton;
+#[derive(Component)] struct AchievementToast { timer: Timer }
 
 #[derive(Component)] struct CountdownRoot;
 #[derive(Component)] struct CountdownText;
 
 #[derive(Component)] struct LevelIndicator { timer: Timer }
 
+#[derive(Component)] struct BossBarRoot;
+#[derive(Component)] struct BossBarLabel;
+#[derive(Component)] struct BossBarFill;
+
 #[derive(Component)] struct LevelClearRoot;
 
 #[derive(Component)] struct UpgradeRoot;
-#[derive(Component)] struct UpgradeCard { index: usize }
+#[derive(Component)] pub struct UpgradeCard { pub index: usize }
+#[derive(Component)] pub struct RerollButton;
 
 #[derive(Component)] struct GameOverRoot;
+#[derive(Component)] struct InitialsRoot;
+/// Letter box `0..INITIALS_LEN`, or the DONE button at `INITIALS_LEN`.
+#[derive(Component)] pub struct InitialsButton(pub usize);
+#[derive(Component)] struct InitialsLetter(usize);
+#[derive(Component)] struct HighScoreRow(usize);
+#[derive(Component)] struct InitialsPrompt;
+#[derive(Component)] struct PlayAgainPrompt;
 #[derive(Component)] struct PauseRoot;
 
+#[derive(Component)] struct RebindRoot;
+#[derive(Component)] struct RebindRow(usize);
+
 // ─────────────────────────────────────────────────────────────────────────────
 // HUD
 // ─────────────────────────────────────────────────────────────────────────────
@@ -141,7 +238,7 @@ fn spawn_hud(mut commands: Commands, game_sprites: Res<GameSprites>) {
                     .with_children(|icon_row| {
                         icon_row.spawn((
                             ImageBundle {
-                                image: UiImage::new(game_sprites.life_normal.clone()),
+                                image: UiImage::new(game_sprites.ships[0].life.clone()),
                                 style: Style {
                                     width:  Val::Px(28.0),
                                     height: Val::Px(28.0),
@@ -177,6 +274,57 @@ fn spawn_hud(mut commands: Commands, game_sprites: Res<GameSprites>) {
                         ),
                         LevelText,
                     ));
+
+                    // Ability name + energy meter
+                    col.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font:      font.clone(),
+                                font_size: 12.0,
+                                color:     Color::rgba(1.0, 1.0, 1.0, 0.8),
+                            },
+                        ),
+                        AbilityLabel,
+                    ));
+                    col.spawn(NodeBundle {
+                        style: Style {
+                            width:  Val::Px(120.0),
+                            height: Val::Px(8.0),
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                        border_color:     Color::rgba(1.0, 1.0, 1.0, 0.4).into(),
+                        ..default()
+                    })
+                    .with_children(|frame| {
+                        frame.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width:  Val::Percent(100.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                background_color: ENERGY_READY_COLOR.into(),
+                                ..default()
+                            },
+                            AbilityEnergyFill,
+                        ));
+                    });
+
+                    // Secondary weapon + ammo
+                    col.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font:      font.clone(),
+                                font_size: 12.0,
+                                color:     AMMO_READY_COLOR,
+                            },
+                        ),
+                        SecondaryAmmoText,
+                    ));
                 });
 
                 // Right: score + buff
@@ -235,12 +383,8 @@ fn update_life_ui(
     mut icons:    Query<&mut UiImage, With<LifeShipIcon>>,
     mut texts:    Query<&mut Text, With<LifeCountText>>,
 ) {
-    let ship_type = ship_q.get_single().map(|s| s.ship_type).unwrap_or(ShipType::Normal);
-    let life_handle = match ship_type {
-        ShipType::Attack => game_sprites.life_attack.clone(),
-        ShipType::Shield => game_sprites.life_shield.clone(),
-        ShipType::Normal => game_sprites.life_normal.clone(),
-    };
+    let index       = ship_q.get_single().map_or(0, |s| s.index);
+    let life_handle = game_sprites.ships[index].life.clone();
     for mut img in &mut icons {
         img.texture = life_handle.clone();
     }
@@ -249,9 +393,20 @@ fn update_life_ui(
     }
 }
 
-fn update_level_ui(level: Res<LevelResource>, mut query: Query<&mut Text, With<LevelText>>) {
+/// In survival the level follows the stage, so the time survived goes beside it.
+fn update_level_ui(
+    level:     Res<LevelResource>,
+    settings:  Res<GameSettings>,
+    survival:  Res<SurvivalState>,
+    mut query: Query<&mut Text, With<LevelText>>,
+) {
     for mut t in &mut query {
-        t.sections[0].value = format!("LEVEL {}", level.current);
+        t.sections[0].value = if settings.mode == GameMode::Survival {
+            let secs = survival.elapsed as u32;
+            format!("LEVEL {}   {}:{:02}", level.current, secs / 60, secs % 60)
+        } else {
+            format!("LEVEL {}", level.current)
+        };
     }
 }
 
@@ -260,10 +415,59 @@ fn update_buff_ui(buff: Res<PlayerBuff>, mut query: Query<&mut Text, With<BuffTe
         let mut parts = Vec::new();
         if buff.bolt_timer > 0.0  { parts.push(format!("SPEED {:.1}s",  buff.bolt_timer)); }
         if buff.shield_timer > 0.0 { parts.push(format!("SHIELD {:.1}s", buff.shield_timer)); }
+        if buff.slow_timer > 0.0   { parts.push(format!("SLOWED {:.1}s", buff.slow_timer)); }
+        if buff.overcharge_timer > 0.0 { parts.push(format!("OVERCHARGE {:.1}s", buff.overcharge_timer)); }
         t.sections[0].value = parts.join("  ");
     }
 }
 
+const ENERGY_READY_COLOR:    Color = Color::rgb(0.3, 0.85, 1.0);
+const ENERGY_CHARGING_COLOR: Color = Color::rgb(0.2, 0.35, 0.55);
+
+fn update_ability_ui(
+    device:     Res<InputDevice>,
+    map:        Res<ActionMap>,
+    player_q:   Query<(&ShipComponent, &ShipAbility), With<PlayerComponent>>,
+    mut fills:  Query<(&mut Style, &mut BackgroundColor), With<AbilityEnergyFill>>,
+    mut labels: Query<&mut Text, With<AbilityLabel>>,
+) {
+    let Ok((ship, ability)) = player_q.get_single() else { return };
+
+    for (mut style, mut bg) in &mut fills {
+        style.width = Val::Percent(ability.fraction() * 100.0);
+        *bg = if ability.ready() { ENERGY_READY_COLOR } else { ENERGY_CHARGING_COLOR }.into();
+    }
+    for mut t in &mut labels {
+        let name = ability_name(ship.def().ability);
+        t.sections[0].value = match *device {
+            InputDevice::Keyboard => format!("[{}] {}", map.describe(Action::Ability), name),
+            InputDevice::Gamepad  => format!("[X] {}", name),
+            // The on-screen button is labelled already.
+            InputDevice::Touch    => name.to_string(),
+        };
+    }
+}
+
+const AMMO_READY_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.8);
+const AMMO_EMPTY_COLOR: Color = Color::rgba(1.0, 0.4, 0.4, 0.6);
+
+fn update_secondary_ui(
+    device:     Res<InputDevice>,
+    map:        Res<ActionMap>,
+    ammo:       Res<SecondaryAmmo>,
+    mut labels: Query<&mut Text, With<SecondaryAmmoText>>,
+) {
+    let count = format!("{} {}/{}", secondary_name(ammo.weapon), ammo.ammo, ammo.max_ammo);
+    for mut t in &mut labels {
+        t.sections[0].value = match *device {
+            InputDevice::Keyboard => format!("[{}] {}", map.describe(Action::Secondary), count),
+            InputDevice::Gamepad  => format!("[LT] {}", count),
+            InputDevice::Touch    => count.clone(),
+        };
+        t.sections[0].style.color = if ammo.ammo > 0 { AMMO_READY_COLOR } else { AMMO_EMPTY_COLOR };
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Ship select screen
 // ─────────────────────────────────────────────────────────────────────────────
@@ -271,6 +475,11 @@ fn update_buff_ui(buff: Res<PlayerBuff>, mut query: Query<&mut Text, With<BuffTe
 fn spawn_ship_select_ui(
     mut commands: Commands,
     selection:    Res<ShipSelectState>,
+    settings:     Res<GameSettings>,
+    skins:        Res<SkinProgress>,
+    saved:        Res<SavedRun>,
+    meta:         Res<MetaProgress>,
+    achievements: Res<AchievementProgress>,
     game_sprites: Res<GameSprites>,
 ) {
     let font = game_sprites.font.clone();
@@ -295,41 +504,243 @@ fn spawn_ship_select_ui(
             ShipSelectRoot,
         ))
         .with_children(|root| {
-            root.spawn(TextBundle::from_section(
-                "SELECT YOUR SHIP",
-                TextStyle { font: font.clone(), font_size: 52.0, color: Color::WHITE },
-            ));
+            // Title with the chosen mode under it; tapping either goes back to mode select
+            root.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items:    AlignItems::Center,
+                        row_gap:        Val::Px(4.0),
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                },
+                ModeButton,
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    "SELECT YOUR SHIP",
+                    TextStyle { font: font.clone(), font_size: 52.0, color: Color::WHITE },
+                ));
+                button.spawn(TextBundle::from_section(
+                    format!("MODE: {}", settings.mode.label()),
+                    TextStyle { font: font.clone(), font_size: 22.0, color: Color::rgb(1.0, 1.0, 0.5) },
+                ));
+                button.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                    ),
+                    PromptText::new("{Pause} to change", "B to change").with_touch("TAP to change"),
+                ));
+            });
+
+            // Saved run, if any
+            if let Some(run) = &saved.snapshot {
+                root.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            align_items:    AlignItems::Center,
+                            padding:        UiRect::axes(Val::Px(24.0), Val::Px(10.0)),
+                            row_gap:        Val::Px(4.0),
+                            border:         UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.1, 0.1, 0.22, 0.97).into(),
+                        border_color:     SHIPS[run.ship].color.into(),
+                        ..default()
+                    },
+                    ContinueBanner,
+                ))
+                .with_children(|banner| {
+                    banner.spawn(TextBundle::from_section(
+                        format!("CONTINUE: LEVEL {}   {}   SCORE {}", run.level, SHIPS[run.ship].name, run.score),
+                        TextStyle { font: font.clone(), font_size: 22.0, color: SHIPS[run.ship].color },
+                    ));
+                    banner.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                        ),
+                        PromptText::new("{Continue} to continue", "Y to continue").with_touch("TAP to continue"),
+                    ));
+                });
+            }
 
             root.spawn(NodeBundle {
                 style: Style {
                     flex_direction: FlexDirection::Row,
-                    column_gap:     Val::Px(28.0),
+                    column_gap:     Val::Px(14.0),
                     ..default()
                 },
                 background_color: Color::NONE.into(),
                 ..default()
             })
             .with_children(|row| {
-                let ships = [
-                    (0usize, "STANDARD",  Color::rgb(0.4, 0.6, 1.0), game_sprites.ship_type_normal.clone(),
-                     "HP 3  Speed 3  Fire 3",  "Balanced fighter. No bonus."),
-                    (1, "GUARDIAN",  Color::rgb(0.4, 1.0, 0.5), game_sprites.ship_type_shield.clone(),
-                     "HP 5  Speed 2  Fire 2",  "Starts with 2 extra HP.\nSlightly slower."),
-                    (2, "DESTROYER", Color::rgb(1.0, 0.4, 0.4), game_sprites.ship_type_attack.clone(),
-                     "HP 2  Speed 3  Fire 4",  "Starts with Heavy Rounds.\nCosts 1 max HP."),
-                ];
-                for (idx, name, color, texture, stats, desc) in ships {
-                    spawn_ship_card(row, idx, name, color, texture, stats, desc, idx == selection.selected, &font);
+                for (idx, def) in SHIPS.iter().enumerate() {
+                    let texture = card_hull(&game_sprites, idx, selection.selected, &skins);
+                    let stats   = if meta.ship_unlocked(idx) {
+                        format!("HP {}  {}", (PLAYER_START_HP + def.hp_bonus).max(1), ability_name(def.ability))
+                    } else {
+                        "LOCKED - SEE HANGAR".to_string()
+                    };
+                    spawn_ship_card(
+                        row, idx, def.name, def.color, texture, &stats, def.description, idx == selection.selected, &font,
+                    );
                 }
             });
 
-            root.spawn(TextBundle::from_section(
-                "LEFT RIGHT to choose   SPACE or ENTER to start",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0,1.0,1.0,0.55) },
+            // Option rows
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items:    AlignItems::Center,
+                    row_gap:        Val::Px(8.0),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            })
+            .with_children(|col| {
+                col.spawn((
+                    TextBundle::from_section(
+                        skin_row_label(&skins, selection.row == 1),
+                        TextStyle { font: font.clone(), font_size: 20.0, color: option_row_color(selection.row == 1) },
+                    ),
+                    ShipSelectOptionRow(1),
+                ));
+                let hint = match skins.next_locked() {
+                    Some(skin) => format!("NEXT SKIN: {} - {}", skin.name, skin.unlock.describe()),
+                    None       => "ALL SKINS UNLOCKED".to_string(),
+                };
+                col.spawn(TextBundle::from_section(
+                    hint,
+                    TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(1.0, 1.0, 1.0, 0.45) },
+                ));
+                for (i, setting) in SettingRow::ALL.iter().enumerate() {
+                    let row = i + 2;
+                    col.spawn((
+                        TextBundle::from_section(
+                            option_row_label(*setting, &settings, row == selection.row),
+                            TextStyle { font: font.clone(), font_size: 20.0, color: option_row_color(row == selection.row) },
+                        ),
+                        ShipSelectOptionRow(row),
+                    ));
+                }
+            });
+
+            // Hangar, pinned to the top-right corner
+            root.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items:    AlignItems::Center,
+                        padding:        UiRect::axes(Val::Px(20.0), Val::Px(8.0)),
+                        row_gap:        Val::Px(4.0),
+                        border:         UiRect::all(Val::Px(2.0)),
+                        position_type:  PositionType::Absolute,
+                        top:            Val::Px(20.0),
+                        right:          Val::Px(20.0),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.1, 0.1, 0.22, 0.97).into(),
+                    border_color:     Color::rgba(1.0, 0.85, 0.3, 0.6).into(),
+                    ..default()
+                },
+                HangarButton,
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    format!("HANGAR   {} SCRAP", meta.scrap),
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgb(1.0, 0.85, 0.3) },
+                ));
+                button.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                    ),
+                    PromptText::new("{Hangar} to open", "VIEW to open").with_touch("TAP to open"),
+                ));
+            });
+
+            // Achievements, pinned to the top-left corner
+            root.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items:    AlignItems::Center,
+                        padding:        UiRect::axes(Val::Px(20.0), Val::Px(8.0)),
+                        row_gap:        Val::Px(4.0),
+                        border:         UiRect::all(Val::Px(2.0)),
+                        position_type:  PositionType::Absolute,
+                        top:            Val::Px(20.0),
+                        left:           Val::Px(20.0),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.1, 0.1, 0.22, 0.97).into(),
+                    border_color:     Color::rgba(0.5, 0.8, 1.0, 0.6).into(),
+                    ..default()
+                },
+                AchievementsButton,
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    format!("ACHIEVEMENTS   {}/{}", achievements.unlocked.len(), ACHIEVEMENTS.len()),
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgb(0.5, 0.8, 1.0) },
+                ));
+                button.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                    ),
+                    PromptText::new("{Achievements} to open", "RB to open").with_touch("TAP to open"),
+                ));
+            });
+
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0,1.0,1.0,0.55) },
+                ),
+                PromptText::new(
+                    "UP DOWN to pick a row   {TurnLeft} {TurnRight} to change   {Confirm} to start",
+                    "D-PAD UP DOWN to pick a row   D-PAD LEFT RIGHT to change   A to start   B for ships",
+                )
+                .with_touch("TAP a ship to pick it   TAP it again to start"),
             ));
         });
 }
 
+fn option_row_label(setting: SettingRow, settings: &GameSettings, focused: bool) -> String {
+    if focused {
+        format!("{}   < {} >", setting.label(), setting.value(settings))
+    } else {
+        format!("{}     {}", setting.label(), setting.value(settings))
+    }
+}
+
+fn option_row_color(focused: bool) -> Color {
+    if focused { Color::rgb(1.0, 1.0, 0.5) } else { Color::rgba(1.0, 1.0, 1.0, 0.6) }
+}
+
+fn skin_row_label(skins: &SkinProgress, focused: bool) -> String {
+    let name  = SKINS[skins.equipped()].name;
+    let count = format!("({}/{})", skins.unlocked_count(), SKINS.len());
+    if focused {
+        format!("SKIN   < {name} >   {count}")
+    } else {
+        format!("SKIN     {name}   {count}")
+    }
+}
+
+/// The highlighted card previews the chosen skin; the rest show their stock hull.
+fn card_hull(game_sprites: &GameSprites, card: usize, selected: usize, skins: &SkinProgress) -> Handle<Image> {
+    let skin = if card == selected { skins.equipped() } else { 0 };
+    game_sprites.hull(&ShipComponent::new(card, skin))
+}
+
 fn spawn_ship_card(
     parent:      &mut ChildBuilder,
     index:       usize,
@@ -349,10 +760,10 @@ fn spawn_ship_card(
         .spawn((
             NodeBundle {
                 style: Style {
-                    width:          Val::Px(200.0),
+                    width:          Val::Px(160.0),
                     flex_direction: FlexDirection::Column,
                     align_items:    AlignItems::Center,
-                    padding:        UiRect::all(Val::Px(18.0)),
+                    padding:        UiRect::all(Val::Px(12.0)),
                     row_gap:        Val::Px(10.0),
                     border:         UiRect::all(Val::Px(2.0)),
                     ..default()
@@ -364,44 +775,560 @@ fn spawn_ship_card(
             ShipSelectCard(index),
         ))
         .with_children(|card| {
-            card.spawn(ImageBundle {
-                image: UiImage::new(texture),
-                style: Style { width: Val::Px(64.0), height: Val::Px(64.0), ..default() },
-                background_color: Color::WHITE.into(),
+            card.spawn((
+                ImageBundle {
+                    image: UiImage::new(texture),
+                    style: Style { width: Val::Px(64.0), height: Val::Px(64.0), ..default() },
+                    background_color: Color::WHITE.into(),
+                    ..default()
+                },
+                ShipSelectCardImage(index),
+            ));
+            card.spawn(TextBundle::from_section(
+                name,
+                TextStyle { font: font.clone(), font_size: 22.0, color: accent },
+            ));
+            card.spawn(TextBundle::from_section(
+                stats,
+                TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgba(1.0,1.0,1.0,0.8) },
+            ));
+            card.spawn(TextBundle::from_section(
+                desc,
+                TextStyle { font: font.clone(), font_size: 12.0, color: Color::rgba(0.8,0.8,0.8,0.7) },
+            ));
+        });
+}
+
+fn despawn_ship_select_ui(mut commands: Commands, query: Query<Entity, With<ShipSelectRoot>>) {
+    for e in &query { commands.entity(e).despawn_recursive(); }
+}
+
+fn update_ship_select_ui(
+    selection:    Res<ShipSelectState>,
+    settings:     Res<GameSettings>,
+    skins:        Res<SkinProgress>,
+    game_sprites: Res<GameSprites>,
+    mut cards:    Query<(&ShipSelectCard, &mut BackgroundColor, &mut BorderColor)>,
+    mut images:   Query<(&ShipSelectCardImage, &mut UiImage)>,
+    mut options:  Query<(&ShipSelectOptionRow, &mut Text)>,
+) {
+    if !selection.is_changed() && !settings.is_changed() && !skins.is_changed() { return; }
+    for (card, mut bg, mut border) in &mut cards {
+        let is_sel = card.0 == selection.selected;
+        *bg     = if is_sel { Color::rgba(0.1,0.1,0.22,0.97).into() } else { Color::rgba(0.05,0.05,0.12,0.88).into() };
+        *border = if is_sel { SHIPS[card.0].color.into() } else { Color::rgba(1.0,1.0,1.0,0.15).into() };
+    }
+    for (card, mut image) in &mut images {
+        image.texture = card_hull(&game_sprites, card.0, selection.selected, &skins);
+    }
+    for (option, mut text) in &mut options {
+        let focused = option.0 == selection.row;
+        text.sections[0].value = match option.0 {
+            1 => skin_row_label(&skins, focused),
+            r => option_row_label(SettingRow::ALL[r - 2], &settings, focused),
+        };
+        text.sections[0].style.color = option_row_color(focused);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Mode select screen
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn spawn_mode_select_ui(mut commands: Commands, settings: Res<GameSettings>, game_sprites: Res<GameSprites>) {
+    let font = game_sprites.font.clone();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width:           Val::Percent(100.0),
+                    height:          Val::Percent(100.0),
+                    flex_direction:  FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items:     AlignItems::Center,
+                    row_gap:         Val::Px(36.0),
+                    position_type:   PositionType::Absolute,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.08, 0.95).into(),
+                z_index: ZIndex::Local(50),
+                ..default()
+            },
+            ModeSelectRoot,
+        ))
+        .with_children(|root| {
+            root.spawn(TextBundle::from_section(
+                "SELECT MODE",
+                TextStyle { font: font.clone(), font_size: 52.0, color: Color::WHITE },
+            ));
+
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap:     Val::Px(24.0),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            })
+            .with_children(|row| {
+                for (idx, mode) in GameMode::ALL.iter().enumerate() {
+                    let is_selected = *mode == settings.mode;
+                    row.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width:          Val::Px(280.0),
+                                flex_direction: FlexDirection::Column,
+                                align_items:    AlignItems::Center,
+                                padding:        UiRect::all(Val::Px(18.0)),
+                                row_gap:        Val::Px(12.0),
+                                border:         UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            background_color: mode_card_bg(is_selected).into(),
+                            border_color:     mode_card_border(is_selected).into(),
+                            ..default()
+                        },
+                        ModeCard(idx),
+                    ))
+                    .with_children(|card| {
+                        card.spawn(TextBundle::from_section(
+                            mode.label(),
+                            TextStyle { font: font.clone(), font_size: 28.0, color: Color::WHITE },
+                        ));
+                        card.spawn(TextBundle::from_section(
+                            mode.description(),
+                            TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(0.85, 0.85, 0.85, 0.85) },
+                        ));
+                    });
+                }
+            });
+
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                ),
+                PromptText::new(
+                    "{TurnLeft} {TurnRight} to choose   {Confirm} to pick a ship",
+                    "D-PAD LEFT RIGHT to choose   A to pick a ship",
+                )
+                .with_touch("TAP a mode to choose   TAP it again to pick a ship"),
+            ));
+        });
+}
+
+fn mode_card_bg(selected: bool) -> Color {
+    if selected { Color::rgba(0.1, 0.1, 0.22, 0.97) } else { Color::rgba(0.05, 0.05, 0.12, 0.88) }
+}
+
+fn mode_card_border(selected: bool) -> Color {
+    if selected { Color::rgb(1.0, 1.0, 0.5) } else { Color::rgba(1.0, 1.0, 1.0, 0.15) }
+}
+
+fn despawn_mode_select_ui(mut commands: Commands, query: Query<Entity, With<ModeSelectRoot>>) {
+    for e in &query { commands.entity(e).despawn_recursive(); }
+}
+
+fn update_mode_select_ui(
+    settings:  Res<GameSettings>,
+    mut cards: Query<(&ModeCard, &mut BackgroundColor, &mut BorderColor)>,
+) {
+    if !settings.is_changed() { return; }
+    for (card, mut bg, mut border) in &mut cards {
+        let is_sel = card.0 == settings.mode.index();
+        *bg     = mode_card_bg(is_sel).into();
+        *border = mode_card_border(is_sel).into();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Hangar screen
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn spawn_hangar_ui(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    hangar:       Res<HangarState>,
+    meta:         Res<MetaProgress>,
+    skins:        Res<SkinProgress>,
+) {
+    let font = game_sprites.font.clone();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width:           Val::Percent(100.0),
+                    height:          Val::Percent(100.0),
+                    flex_direction:  FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items:     AlignItems::Center,
+                    row_gap:         Val::Px(14.0),
+                    position_type:   PositionType::Absolute,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.08, 0.95).into(),
+                z_index: ZIndex::Local(50),
+                ..default()
+            },
+            HangarRoot,
+        ))
+        .with_children(|root| {
+            root.spawn(TextBundle::from_section(
+                "HANGAR",
+                TextStyle { font: font.clone(), font_size: 52.0, color: Color::WHITE },
+            ));
+            root.spawn((
+                TextBundle::from_section(
+                    format!("{} SCRAP", meta.scrap),
+                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::rgb(1.0, 0.85, 0.3) },
+                ),
+                HangarScrapText,
+            ));
+
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap:        Val::Px(4.0),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            })
+            .with_children(|list| {
+                for (i, item) in HANGAR_ITEMS.iter().enumerate() {
+                    let is_selected   = i == hangar.selected;
+                    let (status, tint) = hangar_status(meta.status(item, &skins), item.cost);
+                    list.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width:          Val::Px(760.0),
+                                flex_direction: FlexDirection::Row,
+                                align_items:    AlignItems::Center,
+                                padding:        UiRect::axes(Val::Px(14.0), Val::Px(5.0)),
+                                column_gap:     Val::Px(16.0),
+                                border:         UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            background_color: hangar_row_bg(is_selected).into(),
+                            border_color:     hangar_row_border(is_selected).into(),
+                            ..default()
+                        },
+                        HangarRow(i),
+                    ))
+                    .with_children(|row| {
+                        row.spawn(TextBundle {
+                            text: Text::from_section(
+                                item.name,
+                                TextStyle { font: font.clone(), font_size: 18.0, color: Color::WHITE },
+                            ),
+                            style: Style { width: Val::Px(200.0), ..default() },
+                            ..default()
+                        });
+                        row.spawn(TextBundle {
+                            text: Text::from_section(
+                                item.description,
+                                TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgba(0.85, 0.85, 0.85, 0.85) },
+                            ),
+                            style: Style { flex_grow: 1.0, ..default() },
+                            ..default()
+                        });
+                        row.spawn((
+                            TextBundle::from_section(
+                                status,
+                                TextStyle { font: font.clone(), font_size: 16.0, color: tint },
+                            ),
+                            HangarStatusText(i),
+                        ));
+                    });
+                }
+            });
+
+            root.spawn((
+                NodeBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(24.0), Val::Px(6.0)),
+                        border:  UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.06, 0.06, 0.14, 0.85).into(),
+                    border_color:     Color::rgba(1.0, 1.0, 1.0, 0.3).into(),
+                    ..default()
+                },
+                HangarBackButton,
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    "BACK",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::WHITE },
+                ));
+            });
+
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                ),
+                PromptText::new(
+                    "UP DOWN to pick   {Confirm} to buy   {Back} to return",
+                    "D-PAD UP DOWN to pick   A to buy   B to return",
+                )
+                .with_touch("TAP an item to pick it   TAP it again to buy"),
+            ));
+        });
+}
+
+fn hangar_status(status: ItemStatus, cost: u32) -> (String, Color) {
+    match status {
+        ItemStatus::Owned        => ("OWNED".to_string(), Color::rgb(0.4, 1.0, 0.5)),
+        ItemStatus::Affordable   => (format!("{cost} SCRAP"), Color::rgb(1.0, 1.0, 0.5)),
+        ItemStatus::TooExpensive => (format!("{cost} SCRAP"), Color::rgba(1.0, 0.4, 0.4, 0.8)),
+    }
+}
+
+fn hangar_row_bg(selected: bool) -> Color {
+    if selected { Color::rgba(0.1, 0.1, 0.22, 0.97) } else { Color::rgba(0.05, 0.05, 0.12, 0.88) }
+}
+
+fn hangar_row_border(selected: bool) -> Color {
+    if selected { Color::rgb(1.0, 0.85, 0.3) } else { Color::rgba(1.0, 1.0, 1.0, 0.15) }
+}
+
+fn despawn_hangar_ui(mut commands: Commands, query: Query<Entity, With<HangarRoot>>) {
+    for e in &query { commands.entity(e).despawn_recursive(); }
+}
+
+fn update_hangar_ui(
+    hangar:       Res<HangarState>,
+    meta:         Res<MetaProgress>,
+    skins:        Res<SkinProgress>,
+    mut rows:     Query<(&HangarRow, &mut BackgroundColor, &mut BorderColor)>,
+    mut statuses: Query<(&HangarStatusText, &mut Text), Without<HangarScrapText>>,
+    mut scrap:    Query<&mut Text, With<HangarScrapText>>,
+) {
+    if !hangar.is_changed() && !meta.is_changed() { return; }
+    for (row, mut bg, mut border) in &mut rows {
+        let is_sel = row.0 == hangar.selected;
+        *bg     = hangar_row_bg(is_sel).into();
+        *border = hangar_row_border(is_sel).into();
+    }
+    for (status, mut text) in &mut statuses {
+        let item          = &HANGAR_ITEMS[status.0];
+        let (label, tint) = hangar_status(meta.status(item, &skins), item.cost);
+        text.sections[0].value       = label;
+        text.sections[0].style.color = tint;
+    }
+    for mut text in &mut scrap {
+        text.sections[0].value = format!("{} SCRAP", meta.scrap);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Achievements screen and unlock toast
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn spawn_achievements_ui(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    progress:     Res<AchievementProgress>,
+    skins:        Res<SkinProgress>,
+) {
+    let font = game_sprites.font.clone();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width:           Val::Percent(100.0),
+                    height:          Val::Percent(100.0),
+                    flex_direction:  FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items:     AlignItems::Center,
+                    row_gap:         Val::Px(14.0),
+                    position_type:   PositionType::Absolute,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.08, 0.95).into(),
+                z_index: ZIndex::Local(50),
+                ..default()
+            },
+            AchievementsRoot,
+        ))
+        .with_children(|root| {
+            root.spawn(TextBundle::from_section(
+                "ACHIEVEMENTS",
+                TextStyle { font: font.clone(), font_size: 52.0, color: Color::WHITE },
+            ));
+            root.spawn(TextBundle::from_section(
+                format!("{} OF {} UNLOCKED", progress.unlocked.len(), ACHIEVEMENTS.len()),
+                TextStyle { font: font.clone(), font_size: 24.0, color: Color::rgb(0.5, 0.8, 1.0) },
+            ));
+
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap:        Val::Px(4.0),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            })
+            .with_children(|list| {
+                for (i, def) in ACHIEVEMENTS.iter().enumerate() {
+                    let unlocked = progress.is_unlocked(i);
+                    let border   = if unlocked { Color::rgba(0.4, 1.0, 0.5, 0.5) } else { Color::rgba(1.0, 1.0, 1.0, 0.15) };
+                    let (status, tint) = if unlocked {
+                        ("UNLOCKED".to_string(), Color::rgb(0.4, 1.0, 0.5))
+                    } else if let Some((done, needed)) = def.condition.progress(&progress, &skins) {
+                        (format!("{done}/{needed}"), Color::rgba(1.0, 1.0, 1.0, 0.6))
+                    } else {
+                        ("LOCKED".to_string(), Color::rgba(1.0, 1.0, 1.0, 0.4))
+                    };
+                    list.spawn(NodeBundle {
+                        style: Style {
+                            width:          Val::Px(760.0),
+                            flex_direction: FlexDirection::Row,
+                            align_items:    AlignItems::Center,
+                            padding:        UiRect::axes(Val::Px(14.0), Val::Px(5.0)),
+                            column_gap:     Val::Px(16.0),
+                            border:         UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.05, 0.05, 0.12, 0.88).into(),
+                        border_color:     border.into(),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn(TextBundle {
+                            text: Text::from_section(
+                                def.name,
+                                TextStyle {
+                                    font:      font.clone(),
+                                    font_size: 18.0,
+                                    color:     if unlocked { Color::WHITE } else { Color::rgba(1.0, 1.0, 1.0, 0.6) },
+                                },
+                            ),
+                            style: Style { width: Val::Px(220.0), ..default() },
+                            ..default()
+                        });
+                        row.spawn(TextBundle {
+                            text: Text::from_section(
+                                def.description,
+                                TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgba(0.85, 0.85, 0.85, 0.85) },
+                            ),
+                            style: Style { flex_grow: 1.0, ..default() },
+                            ..default()
+                        });
+                        row.spawn(TextBundle::from_section(
+                            status,
+                            TextStyle { font: font.clone(), font_size: 16.0, color: tint },
+                        ));
+                    });
+                }
+            });
+
+            root.spawn((
+                NodeBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(24.0), Val::Px(6.0)),
+                        border:  UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.06, 0.06, 0.14, 0.85).into(),
+                    border_color:     Color::rgba(1.0, 1.0, 1.0, 0.3).into(),
+                    ..default()
+                },
+                AchievementsBackButton,
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    "BACK",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::WHITE },
+                ));
+            });
+
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                ),
+                PromptText::new("{Back} to return", "B to return").with_touch("TAP BACK to return"),
+            ));
+        });
+}
+
+fn despawn_achievements_ui(mut commands: Commands, query: Query<Entity, With<AchievementsRoot>>) {
+    for e in &query { commands.entity(e).despawn_recursive(); }
+}
+
+/// Shows each new unlock in turn near the top of the screen, over whatever
+/// state the game is in.
+fn achievement_toast_system(
+    mut commands: Commands,
+    time:         Res<Time>,
+    game_sprites: Res<GameSprites>,
+    mut progress: ResMut<AchievementProgress>,
+    mut toasts:   Query<(Entity, &mut AchievementToast)>,
+) {
+    if let Ok((entity, mut toast)) = toasts.get_single_mut() {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+    let Some(index) = progress.toasts.pop_front() else { return };
+    let font = game_sprites.font.clone();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width:           Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    position_type:   PositionType::Absolute,
+                    top:             Val::Px(90.0),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                z_index: ZIndex::Global(100),
+                ..default()
+            },
+            AchievementToast { timer: Timer::from_seconds(ACHIEVEMENT_TOAST_SECS, TimerMode::Once) },
+        ))
+        .with_children(|root| {
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items:    AlignItems::Center,
+                    padding:        UiRect::axes(Val::Px(24.0), Val::Px(10.0)),
+                    row_gap:        Val::Px(4.0),
+                    border:         UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.1, 0.1, 0.22, 0.95).into(),
+                border_color:     Color::rgb(0.5, 0.8, 1.0).into(),
                 ..default()
+            })
+            .with_children(|toast| {
+                toast.spawn(TextBundle::from_section(
+                    "ACHIEVEMENT UNLOCKED",
+                    TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgb(0.5, 0.8, 1.0) },
+                ));
+                toast.spawn(TextBundle::from_section(
+                    ACHIEVEMENTS[index].name,
+                    TextStyle { font: font.clone(), font_size: 24.0, color: Color::WHITE },
+                ));
+                toast.spawn(TextBundle::from_section(
+                    ACHIEVEMENTS[index].description,
+                    TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgba(0.85, 0.85, 0.85, 0.85) },
+                ));
             });
-            card.spawn(TextBundle::from_section(
-                name,
-                TextStyle { font: font.clone(), font_size: 22.0, color: accent },
-            ));
-            card.spawn(TextBundle::from_section(
-                stats,
-                TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgba(1.0,1.0,1.0,0.8) },
-            ));
-            card.spawn(TextBundle::from_section(
-                desc,
-                TextStyle { font: font.clone(), font_size: 12.0, color: Color::rgba(0.8,0.8,0.8,0.7) },
-            ));
         });
 }
 
-fn despawn_ship_select_ui(mut commands: Commands, query: Query<Entity, With<ShipSelectRoot>>) {
-    for e in &query { commands.entity(e).despawn_recursive(); }
-}
-
-fn update_ship_select_ui(
-    selection: Res<ShipSelectState>,
-    mut cards: Query<(&ShipSelectCard, &mut BackgroundColor, &mut BorderColor)>,
-) {
-    if !selection.is_changed() { return; }
-    let colors = [Color::rgb(0.4, 0.6, 1.0), Color::rgb(0.4, 1.0, 0.5), Color::rgb(1.0, 0.4, 0.4)];
-    for (card, mut bg, mut border) in &mut cards {
-        let is_sel = card.0 == selection.selected;
-        *bg     = if is_sel { Color::rgba(0.1,0.1,0.22,0.97).into() } else { Color::rgba(0.05,0.05,0.12,0.88).into() };
-        *border = if is_sel { colors[card.0].into() } else { Color::rgba(1.0,1.0,1.0,0.15).into() };
-    }
-}
-
 // ─────────────────────────────────────────────────────────────────────────────
 // Countdown
 // ─────────────────────────────────────────────────────────────────────────────
@@ -476,8 +1403,13 @@ fn spawn_level_indicator(
             LevelIndicator { timer: Timer::from_seconds(2.5, TimerMode::Once) },
         ))
         .with_children(|root| {
+            let label = if level.is_boss_level() {
+                format!("LEVEL {}  BOSS", level.current)
+            } else {
+                format!("LEVEL {}", level.current)
+            };
             root.spawn(TextBundle::from_section(
-                format!("LEVEL {}", level.current),
+                label,
                 TextStyle { font, font_size: 36.0, color: Color::rgba(1.0, 1.0, 0.5, 0.0) },
             ));
         });
@@ -502,6 +1434,117 @@ fn fade_level_indicator(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Boss HP bar
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Phase colours for the boss bar fill (phase 1, 2, 3).
+const BOSS_PHASE_COLORS: [Color; 3] = [
+    Color::rgb(1.0, 0.8, 0.3),
+    Color::rgb(1.0, 0.5, 0.2),
+    Color::rgb(1.0, 0.25, 0.25),
+];
+
+fn spawn_boss_bar(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    level:        Res<LevelResource>,
+) {
+    if !level.is_boss_level() { return; }
+
+    let font = game_sprites.font.clone();
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width:           Val::Percent(100.0),
+                    flex_direction:  FlexDirection::Column,
+                    align_items:     AlignItems::Center,
+                    row_gap:         Val::Px(6.0),
+                    padding:         UiRect::top(Val::Px(16.0)),
+                    position_type:   PositionType::Absolute,
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                z_index: ZIndex::Local(4),
+                ..default()
+            },
+            BossBarRoot,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 16.0, color: Color::rgba(1.0, 1.0, 1.0, 0.85) },
+                ),
+                BossBarLabel,
+            ));
+
+            // Frame + fill. Phase markers sit at the HP thresholds.
+            root.spawn(NodeBundle {
+                style: Style {
+                    width:  Val::Percent(46.0),
+                    height: Val::Px(14.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                border_color:     Color::rgba(1.0, 1.0, 1.0, 0.4).into(),
+                ..default()
+            })
+            .with_children(|frame| {
+                frame.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width:  Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: BOSS_PHASE_COLORS[0].into(),
+                        ..default()
+                    },
+                    BossBarFill,
+                ));
+                for threshold in lib::BOSS_PHASE_THRESHOLDS {
+                    frame.spawn(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left:          Val::Percent(threshold * 100.0),
+                            width:         Val::Px(2.0),
+                            height:        Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: Color::rgba(1.0, 1.0, 1.0, 0.7).into(),
+                        ..default()
+                    });
+                }
+            });
+        });
+}
+
+fn despawn_boss_bar(mut commands: Commands, query: Query<Entity, With<BossBarRoot>>) {
+    for e in &query { commands.entity(e).despawn_recursive(); }
+}
+
+fn update_boss_bar(
+    boss_q:     Query<&BossComponent>,
+    mut fills:  Query<(&mut Style, &mut BackgroundColor), With<BossBarFill>>,
+    mut labels: Query<&mut Text, With<BossBarLabel>>,
+) {
+    let (fraction, phase, name) = match boss_q.get_single() {
+        Ok(boss) => (boss.hp_fraction(), boss.phase, boss.boss_type.name()),
+        Err(_)   => (0.0, 3, ""),
+    };
+
+    for (mut style, mut bg) in &mut fills {
+        style.width = Val::Percent(fraction * 100.0);
+        *bg = BOSS_PHASE_COLORS[(phase as usize - 1).min(2)].into();
+    }
+    for mut t in &mut labels {
+        t.sections[0].value = if name.is_empty() { String::new() } else { format!("{}  PHASE {}", name, phase) };
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Level-clear screen
 // ─────────────────────────────────────────────────────────────────────────────
@@ -533,12 +1576,17 @@ fn spawn_level_clear_ui(
             LevelClearRoot,
         ))
         .with_children(|root| {
+            let headline = if level.boss_defeated {
+                "BOSS DEFEATED".to_string()
+            } else {
+                format!("LEVEL {} CLEARED", cleared)
+            };
             root.spawn(TextBundle::from_section(
-                format!("LEVEL {} CLEARED", cleared),
+                headline,
                 TextStyle { font: font.clone(), font_size: 60.0, color: Color::rgb(0.4, 1.0, 0.5) },
             ));
             root.spawn(TextBundle::from_section(
-                "Choosing upgrade",
+                if level.boss_defeated { "Rare upgrade guaranteed" } else { "Choosing upgrade" },
                 TextStyle { font: font.clone(), font_size: 26.0, color: Color::rgba(1.0, 1.0, 1.0, 0.7) },
             ));
         });
@@ -557,6 +1605,7 @@ fn spawn_upgrade_ui(
     game_sprites: Res<GameSprites>,
     selection:    Res<UpgradeSelectionState>,
     upgrades:     Res<PlayerUpgrades>,
+    settings:     Res<GameSettings>,
 ) {
     let font = game_sprites.font.clone();
 
@@ -581,7 +1630,13 @@ fn spawn_upgrade_ui(
         ))
         .with_children(|root| {
             root.spawn(TextBundle::from_section(
-                "CHOOSE AN UPGRADE",
+                if selection.boss_reward {
+                    "BOSS REWARD  CHOOSE AN UPGRADE"
+                } else if selection.mid_run {
+                    "SCORE BONUS  CHOOSE AN UPGRADE"
+                } else {
+                    "CHOOSE AN UPGRADE"
+                },
                 TextStyle { font: font.clone(), font_size: 38.0, color: Color::WHITE },
             ));
 
@@ -597,13 +1652,53 @@ fn spawn_upgrade_ui(
             .with_children(|row| {
                 for (i, upgrade) in selection.choices.iter().enumerate() {
                     let is_selected = i == selection.selected;
-                    spawn_upgrade_card(row, *upgrade, i, is_selected, &upgrades, &font);
+                    spawn_upgrade_card(row, *upgrade, i, is_selected, &upgrades, settings.weapon_heat, &font);
                 }
             });
 
-            root.spawn(TextBundle::from_section(
-                "LEFT RIGHT to choose   SPACE or ENTER to confirm",
-                TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0, 1.0, 1.0, 0.6) },
+            // Free rerolls from the hangar
+            if selection.rerolls > 0 {
+                root.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            align_items:    AlignItems::Center,
+                            padding:        UiRect::axes(Val::Px(20.0), Val::Px(8.0)),
+                            row_gap:        Val::Px(4.0),
+                            border:         UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.06, 0.06, 0.14, 0.85).into(),
+                        border_color:     Color::rgba(1.0, 0.85, 0.3, 0.6).into(),
+                        ..default()
+                    },
+                    RerollButton,
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        format!("REROLL ({} LEFT)", selection.rerolls),
+                        TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgb(1.0, 0.85, 0.3) },
+                    ));
+                    button.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle { font: font.clone(), font_size: 14.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                        ),
+                        PromptText::new("{Reroll} to reroll", "Y to reroll").with_touch("TAP to reroll"),
+                    ));
+                });
+            }
+
+            root.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0, 1.0, 1.0, 0.6) },
+                ),
+                PromptText::new(
+                    "{TurnLeft} {TurnRight} to choose   {Confirm} to confirm",
+                    "D-PAD LEFT RIGHT to choose   A to confirm",
+                )
+                .with_touch("TAP a card to choose   TAP it again to confirm"),
             ));
         });
 }
@@ -614,6 +1709,7 @@ fn spawn_upgrade_card(
     index:       usize,
     is_selected: bool,
     upgrades:    &PlayerUpgrades,
+    weapon_heat: bool,
     font:        &Handle<Font>,
 ) {
     let border_color = if is_selected { upgrade.category_color() } else { Color::rgba(1.0, 1.0, 1.0, 0.15) };
@@ -645,8 +1741,13 @@ fn spawn_upgrade_card(
             UpgradeCard { index },
         ))
         .with_children(|card| {
+            let category = if upgrade.is_rare() {
+                format!("{}  RARE", upgrade.category())
+            } else {
+                upgrade.category().to_string()
+            };
             card.spawn(TextBundle::from_section(
-                upgrade.category(),
+                category,
                 TextStyle { font: font.clone(), font_size: 11.0, color: upgrade.category_color() },
             ));
             card.spawn(TextBundle::from_section(
@@ -659,7 +1760,7 @@ fn spawn_upgrade_card(
             ));
             card.spawn(TextBundle {
                 text: Text::from_section(
-                    upgrade.description(),
+                    upgrade.description(weapon_heat),
                     TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgba(0.85, 0.85, 0.85, 0.85) },
                 ),
                 style: Style { flex_grow: 1.0, ..default() },
@@ -676,6 +1777,16 @@ fn despawn_upgrade_ui(mut commands: Commands, query: Query<Entity, With<UpgradeR
     for e in &query { commands.entity(e).despawn_recursive(); }
 }
 
+/// A survival offer is up but its screen isn't built yet.
+fn offer_opened(selection: Res<UpgradeSelectionState>, root: Query<(), With<UpgradeRoot>>) -> bool {
+    selection.mid_run && root.is_empty()
+}
+
+/// A survival offer was taken and its screen is still up.
+fn offer_closed(selection: Res<UpgradeSelectionState>, root: Query<(), With<UpgradeRoot>>) -> bool {
+    !selection.mid_run && !root.is_empty()
+}
+
 fn update_upgrade_ui(
     selection: Res<UpgradeSelectionState>,
     mut cards: Query<(&UpgradeCard, &mut BackgroundColor, &mut BorderColor)>,
@@ -698,8 +1809,15 @@ fn spawn_game_over_ui(
     mut commands: Commands,
     game_sprites: Res<GameSprites>,
     score:        Res<Score>,
+    scores:       Res<HighScores>,
+    settings:     Res<GameSettings>,
+    selection:    Res<ShipSelectState>,
+    entry:        Res<InitialsEntry>,
+    stats:        Res<RunStats>,
+    meta:         Res<MetaProgress>,
 ) {
     let font = game_sprites.font.clone();
+    let best = scores.best(settings.mode, selection.selected).max(score.current);
     commands
         .spawn((
             NodeBundle {
@@ -709,7 +1827,7 @@ fn spawn_game_over_ui(
                     flex_direction:  FlexDirection::Column,
                     justify_content: JustifyContent::Center,
                     align_items:     AlignItems::Center,
-                    row_gap:         Val::Px(20.0),
+                    row_gap:         Val::Px(14.0),
                     position_type:   PositionType::Absolute,
                     ..default()
                 },
@@ -725,16 +1843,228 @@ fn spawn_game_over_ui(
                 TextStyle { font: font.clone(), font_size: 80.0, color: Color::rgb(1.0, 0.25, 0.25) },
             ));
             root.spawn(TextBundle::from_section(
-                format!("Score {}   Best {}", score.current, score.high_score),
+                format!("Score {}   Best {}", score.current, best),
                 TextStyle { font: font.clone(), font_size: 28.0, color: Color::WHITE },
             ));
             root.spawn(TextBundle::from_section(
-                "Press SPACE or ENTER to play again",
-                TextStyle { font: font.clone(), font_size: 22.0, color: Color::rgba(1.0, 1.0, 1.0, 0.6) },
+                format!("+{} SCRAP   {} IN THE HANGAR", meta.last_earned, meta.scrap),
+                TextStyle { font: font.clone(), font_size: 20.0, color: Color::rgb(1.0, 0.85, 0.3) },
+            ));
+
+            // Initials entry, shown while `InitialsEntry::active`
+            root.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items:    AlignItems::Center,
+                        row_gap:        Val::Px(8.0),
+                        display:        if entry.active { Display::Flex } else { Display::None },
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                },
+                InitialsRoot,
+            ))
+            .with_children(|col| {
+                col.spawn(TextBundle::from_section(
+                    "NEW HIGH SCORE!",
+                    TextStyle { font: font.clone(), font_size: 26.0, color: Color::rgb(1.0, 0.85, 0.3) },
+                ));
+                col.spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items:    AlignItems::Center,
+                        column_gap:     Val::Px(10.0),
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for slot in 0..=INITIALS_LEN {
+                        let (label, size) = if slot < INITIALS_LEN { ("", 36.0) } else { ("DONE", 20.0) };
+                        row.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    min_width:       Val::Px(44.0),
+                                    height:          Val::Px(52.0),
+                                    padding:         UiRect::horizontal(Val::Px(8.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items:     AlignItems::Center,
+                                    border:          UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                background_color: Color::rgba(0.05, 0.05, 0.12, 0.88).into(),
+                                border_color:     Color::rgba(1.0, 1.0, 1.0, 0.15).into(),
+                                ..default()
+                            },
+                            InitialsButton(slot),
+                        ))
+                        .with_children(|button| {
+                            let text = TextBundle::from_section(
+                                label,
+                                TextStyle { font: font.clone(), font_size: size, color: Color::WHITE },
+                            );
+                            if slot < INITIALS_LEN {
+                                button.spawn((text, InitialsLetter(slot)));
+                            } else {
+                                button.spawn(text);
+                            }
+                        });
+                    }
+                });
+            });
+
+            // High score table for this ship and mode, beside the run summary
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items:    AlignItems::Start,
+                    column_gap:     Val::Px(48.0),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items:    AlignItems::Start,
+                        row_gap:        Val::Px(2.0),
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                })
+                .with_children(|col| {
+                    col.spawn(TextBundle::from_section(
+                        format!("{} - {} HIGH SCORES", SHIPS[selection.selected].name, settings.mode.label()),
+                        TextStyle { font: font.clone(), font_size: 20.0, color: SHIPS[selection.selected].color },
+                    ));
+                    for rank in 0..TABLE_SIZE {
+                        col.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle { font: font.clone(), font_size: 17.0, color: Color::rgba(1.0, 1.0, 1.0, 0.7) },
+                            ),
+                            HighScoreRow(rank),
+                        ));
+                    }
+                });
+
+                row.spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items:    AlignItems::Start,
+                        row_gap:        Val::Px(4.0),
+                        max_width:      Val::Px(460.0),
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                })
+                .with_children(|col| {
+                    col.spawn(TextBundle::from_section(
+                        "RUN SUMMARY",
+                        TextStyle { font: font.clone(), font_size: 20.0, color: Color::rgb(1.0, 0.85, 0.3) },
+                    ));
+                    for line in run_summary(&stats) {
+                        col.spawn(TextBundle::from_section(
+                            line,
+                            TextStyle { font: font.clone(), font_size: 15.0, color: Color::rgba(1.0, 1.0, 1.0, 0.7) },
+                        ));
+                    }
+                });
+            });
+
+            let prompt_style = TextStyle { font: font.clone(), font_size: 22.0, color: Color::rgba(1.0, 1.0, 1.0, 0.6) };
+            root.spawn((
+                TextBundle::from_section("", prompt_style.clone()),
+                PromptText::new(
+                    "Type your initials   {Confirm} to save",
+                    "D-PAD to pick letters   A to save",
+                )
+                .with_touch("TAP a letter to change it   TAP DONE to save"),
+                InitialsPrompt,
+            ));
+            root.spawn((
+                TextBundle::from_section("", prompt_style),
+                PromptText::new("Press {Confirm} to play again", "Press A to play again")
+                    .with_touch("TAP to play again"),
+                PlayAgainPrompt,
             ));
         });
 }
 
+/// Keeps the initials boxes, the table and the prompts in step with entry.
+fn update_game_over_ui(
+    entry:       Res<InitialsEntry>,
+    scores:      Res<HighScores>,
+    settings:    Res<GameSettings>,
+    selection:   Res<ShipSelectState>,
+    mut roots:   Query<&mut Style, With<InitialsRoot>>,
+    mut buttons: Query<(&InitialsButton, &mut BorderColor)>,
+    mut letters: Query<(&InitialsLetter, &mut Text), Without<HighScoreRow>>,
+    mut rows:    Query<(&HighScoreRow, &mut Text), Without<InitialsLetter>>,
+    mut prompts: Query<(&mut Visibility, Option<&InitialsPrompt>), Or<(With<InitialsPrompt>, With<PlayAgainPrompt>)>>,
+) {
+    if !entry.is_changed() && !scores.is_changed() { return; }
+
+    for mut style in &mut roots {
+        style.display = if entry.active { Display::Flex } else { Display::None };
+    }
+    for (button, mut border) in &mut buttons {
+        *border = if button.0 == entry.slot { Color::rgb(1.0, 1.0, 0.5) } else { Color::rgba(1.0, 1.0, 1.0, 0.15) }.into();
+    }
+    for (letter, mut text) in &mut letters {
+        text.sections[0].value = (entry.letters[letter.0] as char).to_string();
+    }
+
+    let table = scores.table(settings.mode, selection.selected);
+    for (row, mut text) in &mut rows {
+        text.sections[0].value = match table.get(row.0) {
+            Some(e) => format!("{:>2}. {}  {:>7}  LV {:<3} {}", row.0 + 1, e.initials, e.score, e.level, e.date),
+            None    => format!("{:>2}. ---", row.0 + 1),
+        };
+        text.sections[0].style.color = if Some(row.0) == entry.rank {
+            Color::rgb(1.0, 0.85, 0.3)
+        } else {
+            Color::rgba(1.0, 1.0, 1.0, 0.7)
+        };
+    }
+
+    for (mut visibility, initials) in &mut prompts {
+        *visibility = if initials.is_some() == entry.active { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
+
+/// One line per stat for the game over screen.
+fn run_summary(stats: &RunStats) -> Vec<String> {
+    let upgrades: Vec<&str> = stats.upgrades.iter().map(|u| u.name()).collect();
+    vec![
+        format!("ACCURACY  {:.0}%  ({}/{})", stats.accuracy() * 100.0, stats.shots_hit, stats.shots_fired),
+        format!("METEORS  {}", tally(&METEOR_LABELS, &stats.meteors)),
+        format!("UFOS  {}   BOSSES {}", tally(&UFO_LABELS, &stats.ufos), stats.bosses),
+        format!("DAMAGE TAKEN  {}", tally(&DAMAGE_LABELS, &stats.damage)),
+        format!("POWERUPS  {}", tally(&POWERUP_LABELS, &stats.powerups)),
+        format!("UPGRADES  {}", if upgrades.is_empty() { "NONE".to_string() } else { upgrades.join(", ") }),
+        format!("LEVEL TIMES  {}", format_level_times(&stats.level_times)),
+    ]
+}
+
+/// `LABEL n` for every non-zero count, or `NONE`.
+fn tally<T: Copy + Default + PartialEq + std::fmt::Display>(labels: &[&str], counts: &[T]) -> String {
+    let parts: Vec<String> = labels
+        .iter()
+        .zip(counts)
+        .filter(|(_, n)| **n != T::default())
+        .map(|(label, n)| format!("{label} {n}"))
+        .collect();
+    if parts.is_empty() { "NONE".to_string() } else { parts.join("  ") }
+}
+
 fn despawn_game_over_ui(mut commands: Commands, query: Query<Entity, With<GameOverRoot>>) {
     for e in &query { commands.entity(e).despawn_recursive(); }
 }
@@ -747,12 +2077,15 @@ fn update_pause_ui(
     mut commands: Commands,
     game_sprites: Res<GameSprites>,
     is_paused:    Res<IsPaused>,
+    offer:        Res<UpgradeSelectionState>,
     upgrades:     Res<PlayerUpgrades>,
     pause_q:      Query<Entity, With<PauseRoot>>,
 ) {
     let exists = !pause_q.is_empty();
+    // A survival upgrade offer pauses the game without the pause menu.
+    let show   = is_paused.0 && !offer.mid_run;
 
-    if is_paused.0 && !exists {
+    if show && !exists {
         let font = game_sprites.font.clone();
 
         // Collect active upgrades for display
@@ -829,16 +2162,26 @@ fn update_pause_ui(
                     ));
                 });
 
-                root.spawn(TextBundle::from_section(
-                    "ESC or R to Resume",
-                    TextStyle { font: font.clone(), font_size: 26.0, color: Color::rgba(0.6, 1.0, 0.6, 0.9) },
+                root.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 26.0, color: Color::rgba(0.6, 1.0, 0.6, 0.9) },
+                    ),
+                    PromptText::new("{Pause} or {Back} to Resume", "START or B to Resume"),
+                ));
+                root.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 26.0, color: Color::rgba(1.0, 0.6, 0.6, 0.9) },
+                    ),
+                    PromptText::new("{Confirm} to Restart", "A to Restart"),
                 ));
                 root.spawn(TextBundle::from_section(
-                    "SPACE or ENTER to Restart",
-                    TextStyle { font: font.clone(), font_size: 26.0, color: Color::rgba(1.0, 0.6, 0.6, 0.9) },
+                    "TAB to Rebind Keys",
+                    TextStyle { font: font.clone(), font_size: 20.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
                 ));
             });
-    } else if !is_paused.0 && exists {
+    } else if !show && exists {
         for e in &pause_q { commands.entity(e).despawn_recursive(); }
     }
 }
@@ -846,3 +2189,100 @@ fn update_pause_ui(
 fn despawn_pause_ui(mut commands: Commands, q: Query<Entity, With<PauseRoot>>) {
     for e in &q { commands.entity(e).despawn_recursive(); }
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Key rebinding overlay
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn update_rebind_ui(
+    mut commands: Commands,
+    game_sprites: Res<GameSprites>,
+    rebind:       Res<RebindState>,
+    map:          Res<ActionMap>,
+    root_q:       Query<Entity, With<RebindRoot>>,
+    mut rows:     Query<(&RebindRow, &mut Text)>,
+) {
+    let exists = !root_q.is_empty();
+
+    if rebind.open && !exists {
+        let font = game_sprites.font.clone();
+
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        width:           Val::Percent(100.0),
+                        height:          Val::Percent(100.0),
+                        flex_direction:  FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items:     AlignItems::Center,
+                        row_gap:         Val::Px(6.0),
+                        position_type:   PositionType::Absolute,
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.0, 0.0, 0.0, 0.92).into(),
+                    z_index: ZIndex::Local(60),
+                    ..default()
+                },
+                RebindRoot,
+            ))
+            .with_children(|root| {
+                root.spawn(TextBundle::from_section(
+                    "CONTROLS",
+                    TextStyle { font: font.clone(), font_size: 56.0, color: Color::WHITE },
+                ));
+
+                for row in 0..RebindState::rows() {
+                    let focused = row == rebind.row;
+                    root.spawn((
+                        TextBundle::from_section(
+                            rebind_row_label(row, &rebind, &map),
+                            TextStyle { font: font.clone(), font_size: 20.0, color: option_row_color(focused) },
+                        ),
+                        RebindRow(row),
+                    ));
+                }
+
+                root.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle { font: font.clone(), font_size: 18.0, color: Color::rgba(1.0, 1.0, 1.0, 0.55) },
+                    ),
+                    PromptText::new(
+                        "UP DOWN to pick an action   LEFT RIGHT to pick a key   ENTER to rebind   DELETE to clear   ESC to go back",
+                        "Rebinding needs a keyboard   B to go back",
+                    ),
+                ));
+            });
+    } else if !rebind.open && exists {
+        for e in &root_q { commands.entity(e).despawn_recursive(); }
+    } else if exists && (rebind.is_changed() || map.is_changed()) {
+        for (row, mut text) in &mut rows {
+            text.sections[0].value       = rebind_row_label(row.0, &rebind, &map);
+            text.sections[0].style.color = option_row_color(row.0 == rebind.row);
+        }
+    }
+}
+
+/// `THRUST   [UP]   W`: brackets mark the focused key slot, `-` an empty one.
+fn rebind_row_label(row: usize, rebind: &RebindState, map: &ActionMap) -> String {
+    let Some(action) = Action::ALL.get(row).copied() else {
+        return "RESET TO DEFAULTS".to_string();
+    };
+    let keys  = map.keys(action);
+    let slots: Vec<String> = (0..MAX_BINDINGS)
+        .map(|slot| {
+            let name = keys.get(slot).map_or("-".to_string(), |k| key_name(*k));
+            match (row == rebind.row && slot == rebind.slot, rebind.listening) {
+                (true, true)  => "[ PRESS A KEY ]".to_string(),
+                (true, false) => format!("[{name}]"),
+                _             => name,
+            }
+        })
+        .collect();
+    format!("{}   {}", action.label(), slots.join("   "))
+}
+
+fn despawn_rebind_ui(mut commands: Commands, q: Query<Entity, With<RebindRoot>>) {
+    for e in &q { commands.entity(e).despawn_recursive(); }
+}
@@ -0,0 +1,317 @@
+use bevy::prelude::*;
+
+use lib::{SCRAP_PER_BOSS, SCRAP_PER_LEVEL, SCRAP_PER_METEOR, SCRAP_PER_UFO};
+use crate::{
+    input::{
+        actions::{Action, Actions},
+        gamepad::{PadInput, BACK_BUTTON, CONFIRM_BUTTON},
+    },
+    player::{ship::SHIPS, skin::{SkinProgress, SKINS}},
+    resources::{LevelResource, Life, UpgradeSelectionState},
+    state::states::GameStates,
+    upgrades::upgrades::UpgradeType,
+    utils::{run_stats::RunStats, storage},
+};
+
+const HANGAR_FILE: &str = "hangar.cfg";
+
+/// Opens the hangar from the ship select screen. View / Back on Xbox layouts.
+pub const HANGAR_BUTTON: GamepadButtonType = GamepadButtonType::Select;
+
+// ── Catalogue ─────────────────────────────────────────────────────────────────
+
+/// What a hangar item gives. Ships, upgrades and skins are named, so the
+/// catalogue survives their tables being reordered.
+#[derive(Clone, Copy, PartialEq)]
+pub enum HangarUnlock {
+    /// A ship from `SHIPS`; it can't be launched until bought.
+    Ship(&'static str),
+    /// An upgrade that stays out of the level-up offers until bought.
+    Upgrade(UpgradeType),
+    /// +1 starting max HP on every ship.
+    MaxHp,
+    /// One free reroll of an upgrade offer per run.
+    Reroll,
+    /// A skin from `SKINS`, unlocked without reaching its milestone.
+    Skin(&'static str),
+}
+
+/// One entry on the hangar screen. Bought items are saved by name.
+pub struct HangarItem {
+    pub name:        &'static str,
+    pub description: &'static str,
+    pub cost:        u32,
+    pub unlock:      HangarUnlock,
+}
+
+pub const HANGAR_ITEMS: [HangarItem; 12] = [
+    HangarItem {
+        name:        "INTERCEPTOR",
+        description: "Unlocks the Interceptor.",
+        cost:        300,
+        unlock:      HangarUnlock::Ship("INTERCEPTOR"),
+    },
+    HangarItem {
+        name:        "WARDEN",
+        description: "Unlocks the Warden.",
+        cost:        400,
+        unlock:      HangarUnlock::Ship("WARDEN"),
+    },
+    HangarItem {
+        name:        "RAVAGER",
+        description: "Unlocks the Ravager.",
+        cost:        500,
+        unlock:      HangarUnlock::Ship("RAVAGER"),
+    },
+    HangarItem {
+        name:        "GLASS CANNON",
+        description: "Adds Glass Cannon to the upgrade offers.",
+        cost:        150,
+        unlock:      HangarUnlock::Upgrade(UpgradeType::GlassCannon),
+    },
+    HangarItem {
+        name:        "DETONATOR",
+        description: "Adds Detonator Rounds to the upgrade offers.",
+        cost:        200,
+        unlock:      HangarUnlock::Upgrade(UpgradeType::DetonatorRounds),
+    },
+    HangarItem {
+        name:        "DRONE BAY",
+        description: "Adds drones to the upgrade offers.",
+        cost:        250,
+        unlock:      HangarUnlock::Upgrade(UpgradeType::Drone),
+    },
+    HangarItem {
+        name:        "HULL PLATING I",
+        description: "+1 max HP at the start of every run.",
+        cost:        200,
+        unlock:      HangarUnlock::MaxHp,
+    },
+    HangarItem {
+        name:        "HULL PLATING II",
+        description: "Another +1 max HP at the start of every run.",
+        cost:        400,
+        unlock:      HangarUnlock::MaxHp,
+    },
+    HangarItem {
+        name:        "FREE REROLL",
+        description: "Reroll one upgrade offer per run.",
+        cost:        250,
+        unlock:      HangarUnlock::Reroll,
+    },
+    HangarItem {
+        name:        "VIPER SKIN",
+        description: "Unlocks the Viper skin early.",
+        cost:        150,
+        unlock:      HangarUnlock::Skin("VIPER"),
+    },
+    HangarItem {
+        name:        "MANTA SKIN",
+        description: "Unlocks the Manta skin early.",
+        cost:        200,
+        unlock:      HangarUnlock::Skin("MANTA"),
+    },
+    HangarItem {
+        name:        "INFERNO SKIN",
+        description: "Unlocks the Inferno skin early.",
+        cost:        300,
+        unlock:      HangarUnlock::Skin("INFERNO"),
+    },
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ItemStatus {
+    /// Bought, or for a skin, already unlocked by its milestone.
+    Owned,
+    Affordable,
+    TooExpensive,
+}
+
+// ── Progress ──────────────────────────────────────────────────────────────────
+
+/// Scrap and hangar purchases, kept across runs. Loaded at startup and saved
+/// whenever it changes.
+#[derive(Resource, Default)]
+pub struct MetaProgress {
+    pub scrap:       u32,
+    /// Names of the bought `HANGAR_ITEMS`.
+    owned:           Vec<&'static str>,
+    /// Scrap paid for the last finished run, for the game over screen.
+    pub last_earned: u32,
+}
+
+impl MetaProgress {
+    pub fn owns(&self, item: &HangarItem) -> bool {
+        self.owned.contains(&item.name)
+    }
+
+    /// True unless some unbought item gates `unlock`.
+    fn unlocked(&self, unlock: HangarUnlock) -> bool {
+        HANGAR_ITEMS.iter().all(|item| item.unlock != unlock || self.owns(item))
+    }
+
+    /// How many bought items give `unlock`.
+    fn count(&self, unlock: HangarUnlock) -> usize {
+        HANGAR_ITEMS.iter().filter(|item| item.unlock == unlock && self.owns(item)).count()
+    }
+
+    pub fn ship_unlocked(&self, ship: usize) -> bool {
+        self.unlocked(HangarUnlock::Ship(SHIPS[ship].name))
+    }
+
+    pub fn upgrade_unlocked(&self, upgrade: UpgradeType) -> bool {
+        self.unlocked(HangarUnlock::Upgrade(upgrade))
+    }
+
+    pub fn hp_bonus(&self) -> i32 {
+        self.count(HangarUnlock::MaxHp) as i32
+    }
+
+    pub fn rerolls(&self) -> u32 {
+        self.count(HangarUnlock::Reroll) as u32
+    }
+
+    /// Indices into `SKINS` of the bought skins.
+    pub fn skins(&self) -> Vec<usize> {
+        HANGAR_ITEMS
+            .iter()
+            .filter(|item| self.owns(item))
+            .filter_map(|item| match item.unlock {
+                HangarUnlock::Skin(name) => SKINS.iter().position(|s| s.name == name),
+                _                        => None,
+            })
+            .collect()
+    }
+
+    pub fn status(&self, item: &HangarItem, skins: &SkinProgress) -> ItemStatus {
+        let owned = match item.unlock {
+            HangarUnlock::Skin(name) => SKINS.iter().enumerate().any(|(i, s)| s.name == name && skins.unlocked(i)),
+            _                        => self.owns(item),
+        };
+        if owned {
+            ItemStatus::Owned
+        } else if self.scrap >= item.cost {
+            ItemStatus::Affordable
+        } else {
+            ItemStatus::TooExpensive
+        }
+    }
+
+    /// Buys `HANGAR_ITEMS[index]` if it's affordable and not owned yet, and
+    /// saves. A bought skin is handed to `skins` straight away.
+    pub fn buy(&mut self, index: usize, skins: &mut SkinProgress) {
+        let item = &HANGAR_ITEMS[index];
+        if self.status(item, skins) != ItemStatus::Affordable {
+            return;
+        }
+        self.scrap -= item.cost;
+        self.owned.push(item.name);
+        self.save();
+        skins.purchased = self.skins();
+    }
+
+    /// Saved progress, or a fresh start; unknown lines and items are skipped.
+    pub fn load() -> Self {
+        let mut progress = MetaProgress::default();
+        let Some(text) = storage::load_data(HANGAR_FILE) else { return progress };
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let value = value.trim();
+            match key.trim() {
+                "scrap" => progress.scrap = value.parse().unwrap_or(0),
+                "owned" => {
+                    progress.owned = value
+                        .split(',')
+                        .filter_map(|name| HANGAR_ITEMS.iter().find(|item| item.name == name.trim()))
+                        .map(|item| item.name)
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        progress
+    }
+
+    pub fn save(&self) {
+        let text = format!("scrap={}\nowned={}\n", self.scrap, self.owned.join(","));
+        storage::save_data(HANGAR_FILE, &text);
+    }
+}
+
+/// Scrap earned by a finished run: the levels it cleared plus everything it
+/// destroyed.
+pub fn scrap_for_run(level: u32, stats: &RunStats) -> u32 {
+    level.saturating_sub(1) * SCRAP_PER_LEVEL
+        + stats.meteors.iter().sum::<u32>() * SCRAP_PER_METEOR
+        + stats.ufos.iter().sum::<u32>() * SCRAP_PER_UFO
+        + stats.bosses * SCRAP_PER_BOSS
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+/// Highlighted row on the hangar screen, an index into `HANGAR_ITEMS`.
+#[derive(Resource, Default)]
+pub struct HangarState {
+    pub selected: usize,
+}
+
+pub struct HangarPlugin;
+
+impl Plugin for HangarPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<HangarState>()
+            .add_systems(Startup, sync_hangar_skins)
+            .add_systems(OnEnter(GameStates::GameOver), award_scrap)
+            .add_systems(Update, hangar_input_system.run_if(in_state(GameStates::Hangar)));
+    }
+}
+
+/// Hands the skins bought in earlier sessions to `SkinProgress`.
+fn sync_hangar_skins(meta: Res<MetaProgress>, mut skins: ResMut<SkinProgress>) {
+    skins.purchased = meta.skins();
+}
+
+pub fn award_scrap(level: Res<LevelResource>, stats: Res<RunStats>, mut meta: ResMut<MetaProgress>) {
+    let earned = scrap_for_run(level.current, &stats);
+    meta.last_earned = earned;
+    meta.scrap      += earned;
+    meta.save();
+}
+
+/// Gives a new run its hangar bonuses. Runs before the player spawns, which
+/// adds the ship's own HP bonus and fills up the hull.
+pub fn apply_hangar_bonuses(
+    meta:      Res<MetaProgress>,
+    mut life:  ResMut<Life>,
+    mut offer: ResMut<UpgradeSelectionState>,
+) {
+    life.max_life += meta.hp_bonus();
+    offer.rerolls  = meta.rerolls();
+}
+
+/// Up/Down picks an item, Confirm buys it, Back or Escape returns to ship
+/// select. On a pad the D-pad picks, A buys and B returns.
+fn hangar_input_system(
+    actions:        Actions,
+    pad:            PadInput,
+    mut hangar:     ResMut<HangarState>,
+    mut meta:       ResMut<MetaProgress>,
+    mut skins:      ResMut<SkinProgress>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    let len = HANGAR_ITEMS.len();
+    if actions.just_pressed(Action::Up) || pad.just_pressed(GamepadButtonType::DPadUp) {
+        hangar.selected = (hangar.selected + len - 1) % len;
+    }
+    if actions.just_pressed(Action::Down) || pad.just_pressed(GamepadButtonType::DPadDown) {
+        hangar.selected = (hangar.selected + 1) % len;
+    }
+    if actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON) {
+        meta.buy(hangar.selected, &mut skins);
+    }
+    if actions.just_pressed(Action::Back) || actions.just_pressed(Action::Pause) || pad.just_pressed(BACK_BUTTON) {
+        next_state.set(GameStates::ShipSelect);
+    }
+}
@@ -1 +1,2 @@
+pub mod hangar;
 pub mod upgrades;
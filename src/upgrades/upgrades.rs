@@ -1,7 +1,22 @@
 use bevy::prelude::*;
-use rand::{seq::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, Rng};
 
-use crate::resources::{Life, PlayerUpgrades, UpgradeSelectionState};
+use crate::{
+    input::{
+        actions::{Action, Actions},
+        gamepad::{PadInput, CONFIRM_BUTTON},
+    },
+    resources::{IsPaused, Life, PlayerUpgrades, RunRng, UpgradeSelectionState},
+    upgrades::hangar::MetaProgress,
+    utils::run_stats::RunStats,
+};
+
+/// Rerolls the upgrade offer while the hangar's free rerolls last. Y on Xbox layouts.
+pub const REROLL_BUTTON: GamepadButtonType = GamepadButtonType::North;
+
+/// Sent when the offer is rerolled, so the screen rebuilds its cards.
+#[derive(Event)]
+pub struct UpgradesRerolled;
 
 // ── Upgrade catalogue ─────────────────────────────────────────────────────────
 
@@ -22,12 +37,17 @@ pub enum UpgradeType {
     QuickReflexes,
     LongShot,         // NEW: longer range, slower bullet
     Bulwark,          // NEW: chance to heal on large kill
+    StableWarp,       // hyperspace never malfunctions
     // Special
     Overclock,
     ChainReaction,
     AsteroidMagnet,
     GlassCannon,      // NEW: double damage+speed, -1 max HP, slower fire
     DetonatorRounds,  // NEW: bullets explode at max range
+    // Drones
+    Drone,            // adds an orbiting drone
+    DroneOverdrive,   // drones fire faster
+    DronePlating,     // drones survive one hit
 }
 
 impl UpgradeType {
@@ -36,9 +56,10 @@ impl UpgradeType {
         &[
             SplitShot, RearGuard, RapidFire, HeavyRounds, Ricochet,
             Accelerator, PiercingRounds, ExplosiveRounds,
-            ExtraArmor, Afterburner, QuickReflexes, LongShot, Bulwark,
+            ExtraArmor, Afterburner, QuickReflexes, LongShot, Bulwark, StableWarp,
             Overclock, ChainReaction, AsteroidMagnet, GlassCannon,
             DetonatorRounds,
+            Drone, DroneOverdrive, DronePlating,
         ]
     }
 
@@ -57,15 +78,30 @@ impl UpgradeType {
             UpgradeType::QuickReflexes   => "QUICK REFLEXES",
             UpgradeType::LongShot        => "LONG SHOT",
             UpgradeType::Bulwark         => "BULWARK",
+            UpgradeType::StableWarp      => "STABLE WARP",
             UpgradeType::Overclock       => "OVERCLOCK",
             UpgradeType::ChainReaction   => "CHAIN REACTION",
             UpgradeType::AsteroidMagnet  => "ASTEROID MAGNET",
             UpgradeType::GlassCannon     => "GLASS CANNON",
             UpgradeType::DetonatorRounds => "DETONATOR",
+            UpgradeType::Drone           => "DRONE",
+            UpgradeType::DroneOverdrive  => "DRONE OVERDRIVE",
+            UpgradeType::DronePlating    => "DRONE PLATING",
         }
     }
 
-    pub fn description(&self) -> &'static str {
+    /// Card text. With the weapon heat model on, Rapid Fire and Chain
+    /// Reaction work on heat instead of fire rate.
+    pub fn description(&self, weapon_heat: bool) -> &'static str {
+        if weapon_heat {
+            match self {
+                UpgradeType::RapidFire =>
+                    return "20% less heat per shot per level. Fire longer before overheating.",
+                UpgradeType::ChainReaction =>
+                    return "Each kill vents weapon heat 2.5x faster for 3s.",
+                _ => {}
+            }
+        }
         match self {
             UpgradeType::SplitShot =>
                 "Lv1: 2 bullets. Lv2: 3 bullets. Lv3: 5 bullets in a spread.",
@@ -84,7 +120,7 @@ impl UpgradeType {
             UpgradeType::ExplosiveRounds =>
                 "On large asteroid kill, scatter 3 shrapnel fragments.",
             UpgradeType::ExtraArmor =>
-                "+1 max HP and restore 1 HP immediately.",
+                "+1 max HP and restore 1 HP immediately. Longer invulnerability after hits.",
             UpgradeType::Afterburner =>
                 "+30% top ship speed per level.",
             UpgradeType::QuickReflexes =>
@@ -93,6 +129,8 @@ impl UpgradeType {
                 "+35% bullet range per level. 10% speed penalty per level.",
             UpgradeType::Bulwark =>
                 "35% chance to restore 1 HP when destroying a large asteroid.",
+            UpgradeType::StableWarp =>
+                "Hyperspace jumps never malfunction.",
             UpgradeType::Overclock =>
                 "Asteroids move at 60% normal speed.",
             UpgradeType::ChainReaction =>
@@ -103,6 +141,12 @@ impl UpgradeType {
                 "DOUBLE damage and +50% bullet speed. Costs 1 max HP. 50% slower fire rate.",
             UpgradeType::DetonatorRounds =>
                 "Bullets explode at max range. 55% range penalty ensures on-screen detonation.",
+            UpgradeType::Drone =>
+                "Adds a drone that orbits the ship and shoots the nearest target. Uses Heavy and Piercing Rounds.",
+            UpgradeType::DroneOverdrive =>
+                "Drones fire 30% faster per level.",
+            UpgradeType::DronePlating =>
+                "Each drone survives one meteor hit before going down.",
         }
     }
 
@@ -121,11 +165,15 @@ impl UpgradeType {
             UpgradeType::QuickReflexes   => 2,
             UpgradeType::LongShot        => 2,
             UpgradeType::Bulwark         => 1,
+            UpgradeType::StableWarp      => 1,
             UpgradeType::Overclock       => 1,
             UpgradeType::ChainReaction   => 1,
             UpgradeType::AsteroidMagnet  => 1,
             UpgradeType::GlassCannon     => 1,
             UpgradeType::DetonatorRounds => 1,
+            UpgradeType::Drone           => 3,
+            UpgradeType::DroneOverdrive  => 2,
+            UpgradeType::DronePlating    => 1,
         }
     }
 
@@ -144,11 +192,15 @@ impl UpgradeType {
             UpgradeType::QuickReflexes   => upgrades.quick_reflexes,
             UpgradeType::LongShot        => upgrades.long_shot,
             UpgradeType::Bulwark         => upgrades.bulwark as u32,
+            UpgradeType::StableWarp      => upgrades.stable_warp as u32,
             UpgradeType::Overclock       => upgrades.overclock as u32,
             UpgradeType::ChainReaction   => upgrades.chain_reaction as u32,
             UpgradeType::AsteroidMagnet  => upgrades.asteroid_magnet as u32,
             UpgradeType::GlassCannon     => upgrades.glass_cannon as u32,
             UpgradeType::DetonatorRounds => upgrades.detonator_rounds as u32,
+            UpgradeType::Drone           => upgrades.drones,
+            UpgradeType::DroneOverdrive  => upgrades.drone_overdrive,
+            UpgradeType::DronePlating    => upgrades.drone_plating as u32,
         }
     }
 
@@ -157,6 +209,10 @@ impl UpgradeType {
         if *self == UpgradeType::GlassCannon && upgrades.glass_cannon {
             return false;
         }
+        // Drone upgrades need a drone to work on.
+        if matches!(self, UpgradeType::DroneOverdrive | UpgradeType::DronePlating) && upgrades.drones == 0 {
+            return false;
+        }
         self.current_level(upgrades) < self.max_level()
     }
 
@@ -182,6 +238,7 @@ impl UpgradeType {
             UpgradeType::QuickReflexes   => upgrades.quick_reflexes    += 1,
             UpgradeType::LongShot        => upgrades.long_shot          += 1,
             UpgradeType::Bulwark         => upgrades.bulwark            = true,
+            UpgradeType::StableWarp      => upgrades.stable_warp        = true,
             UpgradeType::Overclock       => upgrades.overclock          = true,
             UpgradeType::ChainReaction   => upgrades.chain_reaction     = true,
             UpgradeType::AsteroidMagnet  => upgrades.asteroid_magnet    = true,
@@ -192,10 +249,22 @@ impl UpgradeType {
                 life.current_life = life.current_life.min(life.max_life);
             }
             UpgradeType::DetonatorRounds => upgrades.detonator_rounds = true,
+            UpgradeType::Drone           => upgrades.drones          += 1,
+            UpgradeType::DroneOverdrive  => upgrades.drone_overdrive += 1,
+            UpgradeType::DronePlating    => upgrades.drone_plating    = true,
         }
         true
     }
 
+    /// Rare upgrades are the run-defining ones; a boss kill guarantees one is offered.
+    pub fn is_rare(&self) -> bool {
+        matches!(
+            self,
+            UpgradeType::PiercingRounds | UpgradeType::ExplosiveRounds | UpgradeType::Bulwark |
+            UpgradeType::ChainReaction  | UpgradeType::GlassCannon     | UpgradeType::DetonatorRounds
+        )
+    }
+
     pub fn category(&self) -> &'static str {
         match self {
             UpgradeType::SplitShot | UpgradeType::RearGuard | UpgradeType::RapidFire |
@@ -204,7 +273,7 @@ impl UpgradeType {
             UpgradeType::DetonatorRounds => "OFFENSE",
 
             UpgradeType::ExtraArmor | UpgradeType::Afterburner | UpgradeType::QuickReflexes |
-            UpgradeType::LongShot   | UpgradeType::Bulwark => "DEFENSE",
+            UpgradeType::LongShot   | UpgradeType::Bulwark | UpgradeType::StableWarp => "DEFENSE",
 
             _ => "SPECIAL",
         }
@@ -221,15 +290,31 @@ impl UpgradeType {
 
 // ── Selection resource helpers ────────────────────────────────────────────────
 
-pub fn generate_choices(upgrades: &PlayerUpgrades, count: usize) -> Vec<UpgradeType> {
+/// Picks `count` random eligible upgrades, leaving out any still locked in the
+/// hangar. With `guarantee_rare`, a rare upgrade is swapped into the first slot
+/// if the shuffle didn't already offer one.
+pub fn generate_choices(
+    upgrades:       &PlayerUpgrades,
+    meta:           &MetaProgress,
+    count:          usize,
+    guarantee_rare: bool,
+    rng:            &mut impl Rng,
+) -> Vec<UpgradeType> {
     let mut eligible: Vec<UpgradeType> = UpgradeType::all()
         .iter()
-        .filter(|u| u.is_eligible(upgrades))
+        .filter(|u| u.is_eligible(upgrades) && meta.upgrade_unlocked(**u))
         .copied()
         .collect();
 
-    let mut rng = thread_rng();
-    eligible.shuffle(&mut rng);
+    eligible.shuffle(rng);
+
+    let offered = count.min(eligible.len());
+    if guarantee_rare && !eligible[..offered].iter().any(|u| u.is_rare()) {
+        if let Some(rare_idx) = eligible.iter().position(|u| u.is_rare()) {
+            eligible.swap(0, rare_idx);
+        }
+    }
+
     eligible.truncate(count);
     eligible
 }
@@ -241,6 +326,7 @@ pub struct UpgradePlugin;
 impl Plugin for UpgradePlugin {
     fn build(&self, app: &mut App) {
         app
+            .add_event::<UpgradesRerolled>()
             .add_systems(
                 OnEnter(crate::state::states::GameStates::UpgradeSelection),
                 setup_upgrade_selection,
@@ -249,42 +335,101 @@ impl Plugin for UpgradePlugin {
                 Update,
                 upgrade_input_system
                     .run_if(in_state(crate::state::states::GameStates::UpgradeSelection)),
+            )
+            // Survival offers open mid-level, over the paused game.
+            .add_systems(
+                Update,
+                upgrade_input_system
+                    .run_if(in_state(crate::state::states::GameStates::InGame))
+                    .run_if(|s: Res<UpgradeSelectionState>| s.mid_run),
             );
     }
 }
 
-fn setup_upgrade_selection(
+pub fn setup_upgrade_selection(
     upgrades:      Res<PlayerUpgrades>,
+    meta:          Res<MetaProgress>,
+    mut rng:       ResMut<RunRng>,
     mut selection: ResMut<UpgradeSelectionState>,
 ) {
     let count = lib::UPGRADE_CHOICES;
-    selection.choices  = generate_choices(&upgrades, count);
+    selection.choices  = generate_choices(&upgrades, &meta, count, selection.boss_reward, &mut *rng);
     selection.selected = 0;
 }
 
+/// Spends a free reroll on a fresh offer. A boss reward still gets its rare
+/// card. Returns false with no rerolls left.
+pub fn reroll_upgrades(
+    selection: &mut UpgradeSelectionState,
+    upgrades:  &PlayerUpgrades,
+    meta:      &MetaProgress,
+    rng:       &mut RunRng,
+) -> bool {
+    if selection.rerolls == 0 {
+        return false;
+    }
+    selection.rerolls -= 1;
+    selection.choices  = generate_choices(upgrades, meta, lib::UPGRADE_CHOICES, selection.boss_reward, rng);
+    selection.selected = 0;
+    true
+}
+
 fn upgrade_input_system(
-    kb:             Res<Input<KeyCode>>,
+    actions:        Actions,
+    pad:            PadInput,
+    meta:           Res<MetaProgress>,
+    mut rng:        ResMut<RunRng>,
     mut selection:  ResMut<UpgradeSelectionState>,
     mut upgrades:   ResMut<PlayerUpgrades>,
     mut life:       ResMut<Life>,
+    mut stats:      ResMut<RunStats>,
+    mut paused:     ResMut<IsPaused>,
+    mut rerolled:   EventWriter<UpgradesRerolled>,
     mut next_state: ResMut<NextState<crate::state::states::GameStates>>,
 ) {
     if selection.choices.is_empty() {
         return;
     }
 
+    if (actions.just_pressed(Action::Reroll) || pad.just_pressed(REROLL_BUTTON))
+        && reroll_upgrades(&mut selection, &upgrades, &meta, &mut rng)
+    {
+        rerolled.send(UpgradesRerolled);
+        return;
+    }
+
     let len = selection.choices.len();
 
-    if kb.just_pressed(KeyCode::Left) || kb.just_pressed(KeyCode::A) {
+    if actions.just_pressed(Action::TurnLeft) || pad.just_pressed(GamepadButtonType::DPadLeft) {
         selection.selected = (selection.selected + len - 1) % len;
     }
-    if kb.just_pressed(KeyCode::Right) || kb.just_pressed(KeyCode::D) {
+    if actions.just_pressed(Action::TurnRight) || pad.just_pressed(GamepadButtonType::DPadRight) {
         selection.selected = (selection.selected + 1) % len;
     }
 
-    if kb.just_pressed(KeyCode::Space) || kb.just_pressed(KeyCode::Return) {
-        let chosen = selection.choices[selection.selected];
-        chosen.apply(&mut upgrades, &mut life);
+    if actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON) {
+        confirm_upgrade(&mut selection, &mut upgrades, &mut life, &mut stats, &mut paused, &mut next_state);
+    }
+}
+
+/// Applies the selected card and heads into the next level's countdown, or
+/// for a survival offer, straight back into play.
+pub fn confirm_upgrade(
+    selection:  &mut UpgradeSelectionState,
+    upgrades:   &mut PlayerUpgrades,
+    life:       &mut Life,
+    stats:      &mut RunStats,
+    paused:     &mut IsPaused,
+    next_state: &mut NextState<crate::state::states::GameStates>,
+) {
+    let chosen = selection.choices[selection.selected];
+    chosen.apply(upgrades, life);
+    stats.upgrades.push(chosen);
+    selection.boss_reward = false;
+    if selection.mid_run {
+        selection.mid_run = false;
+        paused.0          = false;
+    } else {
         next_state.set(crate::state::states::GameStates::Countdown);
     }
 }
@@ -0,0 +1,310 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{
+    events::events::ShotKillEvent,
+    input::{
+        actions::{Action, Actions},
+        gamepad::{PadInput, BACK_BUTTON},
+    },
+    player::skin::SkinProgress,
+    resources::{IsPaused, LevelResource, PlayerUpgrades, Score},
+    state::states::GameStates,
+    upgrades::upgrades::UpgradeType,
+    utils::{run_stats::RunStats, savegame::restore_run_system, storage},
+};
+
+const ACHIEVEMENTS_FILE: &str = "achievements.cfg";
+
+/// Opens the achievements screen from the ship select screen. RB on Xbox layouts.
+pub const ACHIEVEMENTS_BUTTON: GamepadButtonType = GamepadButtonType::RightTrigger;
+
+// ── Definitions ───────────────────────────────────────────────────────────────
+
+/// What has to happen to unlock an achievement. "Run" conditions look at the
+/// current run; "total" ones count across every run and session.
+#[derive(Clone, Copy)]
+pub enum Condition {
+    ReachLevel(u32),
+    Score(i32),
+    /// Clear a level without losing any HP.
+    FlawlessLevel,
+    TotalMeteors(u32),
+    TotalBosses(u32),
+    /// Reach a level while holding an upgrade.
+    ReachLevelWith(u32, UpgradeType),
+    /// Destroy this many meteors with a single shot.
+    MultiKill(u32),
+}
+
+impl Condition {
+    fn met(&self, check: &Check) -> bool {
+        match *self {
+            Condition::ReachLevel(n)              => check.level >= n,
+            Condition::Score(n)                   => check.score >= n,
+            Condition::FlawlessLevel              => check.flawless,
+            Condition::TotalMeteors(n)            => check.total_meteors >= n,
+            Condition::TotalBosses(n)             => check.total_bosses >= n,
+            Condition::ReachLevelWith(n, upgrade) => check.level >= n && upgrade.current_level(check.upgrades) > 0,
+            Condition::MultiKill(n)               => check.shot_kills >= n,
+        }
+    }
+
+    /// `(done, needed)` for the counting conditions, for the achievements screen.
+    pub fn progress(&self, progress: &AchievementProgress, skins: &SkinProgress) -> Option<(u32, u32)> {
+        match *self {
+            Condition::TotalMeteors(n) => Some((progress.meteors.min(n), n)),
+            Condition::TotalBosses(n)  => Some((skins.boss_kills.min(n), n)),
+            _                          => None,
+        }
+    }
+}
+
+pub struct AchievementDef {
+    pub name:        &'static str,
+    pub description: &'static str,
+    pub condition:   Condition,
+}
+
+pub const ACHIEVEMENTS: [AchievementDef; 10] = [
+    AchievementDef {
+        name:        "ON A ROLL",
+        description: "Reach level 5.",
+        condition:   Condition::ReachLevel(5),
+    },
+    AchievementDef {
+        name:        "VETERAN",
+        description: "Reach level 10.",
+        condition:   Condition::ReachLevel(10),
+    },
+    AchievementDef {
+        name:        "HIGH ROLLER",
+        description: "Score 10000 in one run.",
+        condition:   Condition::Score(10_000),
+    },
+    AchievementDef {
+        name:        "UNTOUCHABLE",
+        description: "Clear a level without taking damage.",
+        condition:   Condition::FlawlessLevel,
+    },
+    AchievementDef {
+        name:        "ROCK BREAKER",
+        description: "Destroy 100 meteors.",
+        condition:   Condition::TotalMeteors(100),
+    },
+    AchievementDef {
+        name:        "DEMOLITION CREW",
+        description: "Destroy 1000 meteors.",
+        condition:   Condition::TotalMeteors(1000),
+    },
+    AchievementDef {
+        name:        "GIANT SLAYER",
+        description: "Defeat a boss.",
+        condition:   Condition::TotalBosses(1),
+    },
+    AchievementDef {
+        name:        "BOSS HUNTER",
+        description: "Defeat 10 bosses.",
+        condition:   Condition::TotalBosses(10),
+    },
+    AchievementDef {
+        name:        "GLASS JAW",
+        description: "Reach level 10 with Glass Cannon.",
+        condition:   Condition::ReachLevelWith(10, UpgradeType::GlassCannon),
+    },
+    AchievementDef {
+        name:        "FIVE FOR ONE",
+        description: "Destroy 5 meteors with one bullet.",
+        condition:   Condition::MultiKill(5),
+    },
+];
+
+/// Everything the conditions look at, gathered once per check.
+struct Check<'a> {
+    level:         u32,
+    score:         i32,
+    flawless:      bool,
+    total_meteors: u32,
+    total_bosses:  u32,
+    upgrades:      &'a PlayerUpgrades,
+    /// Most meteors any one shot has destroyed since the last check.
+    shot_kills:    u32,
+}
+
+// ── Progress ──────────────────────────────────────────────────────────────────
+
+/// Unlocked achievements and the meteor total counted across runs. Loaded at
+/// startup and saved on every unlock, level clear and run end. Lifetime boss
+/// kills are `SkinProgress::boss_kills`, which the skin unlocks count too.
+#[derive(Resource, Default)]
+pub struct AchievementProgress {
+    /// Indices into `ACHIEVEMENTS`. Saved by name.
+    pub unlocked:   Vec<usize>,
+    pub meteors:    u32,
+    /// How much of the current run's `RunStats` is already in the total.
+    run_meteors:    u32,
+    /// HP lost in the run when the current level began.
+    level_damage:   i32,
+    /// Unlocked this session and not shown yet; the toast pops them in order.
+    pub toasts:     VecDeque<usize>,
+}
+
+impl AchievementProgress {
+    pub fn is_unlocked(&self, index: usize) -> bool {
+        self.unlocked.contains(&index)
+    }
+
+    /// Unlocks whatever `check` now satisfies, queues a toast for each and
+    /// saves if anything changed.
+    fn unlock_met(&mut self, check: &Check) {
+        let mut changed = false;
+        for (i, def) in ACHIEVEMENTS.iter().enumerate() {
+            if !self.is_unlocked(i) && def.condition.met(check) {
+                self.unlocked.push(i);
+                self.toasts.push_back(i);
+                changed = true;
+            }
+        }
+        if changed {
+            self.save();
+        }
+    }
+
+    /// Adds the meteors the run has destroyed since the last call to the total.
+    fn count_run(&mut self, stats: &RunStats) {
+        let meteors = stats.meteors.iter().sum::<u32>();
+        self.meteors    += meteors.saturating_sub(self.run_meteors);
+        self.run_meteors = meteors;
+    }
+
+    /// Saved progress, or a fresh start; unknown lines and names are skipped,
+    /// including the `bosses` total older files carry.
+    pub fn load() -> Self {
+        let mut progress = AchievementProgress::default();
+        let Some(text) = storage::load_data(ACHIEVEMENTS_FILE) else { return progress };
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let value = value.trim();
+            match key.trim() {
+                "meteors"  => progress.meteors = value.parse().unwrap_or(0),
+                "unlocked" => {
+                    progress.unlocked = value
+                        .split(',')
+                        .filter_map(|name| ACHIEVEMENTS.iter().position(|a| a.name == name.trim()))
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        progress
+    }
+
+    pub fn save(&self) {
+        let names: Vec<&str> = self.unlocked.iter().map(|i| ACHIEVEMENTS[*i].name).collect();
+        let text = format!("meteors={}\nunlocked={}\n", self.meteors, names.join(","));
+        storage::save_data(ACHIEVEMENTS_FILE, &text);
+    }
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct AchievementPlugin;
+
+impl Plugin for AchievementPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // After a continued run gets its stats back, so they aren't counted twice.
+            .add_systems(OnEnter(GameStates::StartGame), begin_run_tracking.after(restore_run_system))
+            .add_systems(OnEnter(GameStates::InGame), begin_level_tracking)
+            .add_systems(
+                Update,
+                achievement_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            .add_systems(OnEnter(GameStates::LevelComplete), level_clear_achievements)
+            .add_systems(OnEnter(GameStates::GameOver), save_achievements)
+            .add_systems(
+                OnTransition { from: GameStates::InGame, to: GameStates::ShipSelect },
+                save_achievements,
+            )
+            .add_systems(Update, achievements_input_system.run_if(in_state(GameStates::Achievements)));
+    }
+}
+
+/// The run's stats start out counted: zero for a new run, or whatever a
+/// continued run had when it was saved.
+fn begin_run_tracking(stats: Res<RunStats>, mut progress: ResMut<AchievementProgress>) {
+    progress.run_meteors = stats.meteors.iter().sum();
+}
+
+fn begin_level_tracking(stats: Res<RunStats>, mut progress: ResMut<AchievementProgress>) {
+    progress.level_damage = stats.damage.iter().sum();
+}
+
+/// Counts kills into the totals and checks every condition that can change
+/// mid-level.
+fn achievement_system(
+    level:        Res<LevelResource>,
+    score:        Res<Score>,
+    upgrades:     Res<PlayerUpgrades>,
+    stats:        Res<RunStats>,
+    skins:        Res<SkinProgress>,
+    mut kills:    EventReader<ShotKillEvent>,
+    mut progress: ResMut<AchievementProgress>,
+) {
+    progress.count_run(&stats);
+
+    let check = Check {
+        level:         level.current,
+        score:         score.current,
+        flawless:      false,
+        total_meteors: progress.meteors,
+        total_bosses:  skins.boss_kills,
+        upgrades:      &upgrades,
+        shot_kills:    kills.iter().map(|e| e.kills).max().unwrap_or(0),
+    };
+    progress.unlock_met(&check);
+}
+
+/// Checks the level just cleared, then saves the totals.
+fn level_clear_achievements(
+    level:        Res<LevelResource>,
+    score:        Res<Score>,
+    upgrades:     Res<PlayerUpgrades>,
+    stats:        Res<RunStats>,
+    skins:        Res<SkinProgress>,
+    mut progress: ResMut<AchievementProgress>,
+) {
+    progress.count_run(&stats);
+
+    let check = Check {
+        level:         level.current,
+        score:         score.current,
+        flawless:      stats.damage.iter().sum::<i32>() == progress.level_damage,
+        total_meteors: progress.meteors,
+        total_bosses:  skins.boss_kills,
+        upgrades:      &upgrades,
+        shot_kills:    0,
+    };
+    progress.unlock_met(&check);
+    progress.save();
+}
+
+fn save_achievements(stats: Res<RunStats>, mut progress: ResMut<AchievementProgress>) {
+    progress.count_run(&stats);
+    progress.save();
+}
+
+/// Back, Escape or B returns to ship select.
+fn achievements_input_system(
+    actions:        Actions,
+    pad:            PadInput,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    if actions.just_pressed(Action::Back) || actions.just_pressed(Action::Pause) || pad.just_pressed(BACK_BUTTON) {
+        next_state.set(GameStates::ShipSelect);
+    }
+}
@@ -0,0 +1,397 @@
+use bevy::prelude::*;
+
+use crate::{
+    input::{actions::{Action, Actions}, gamepad::{PadInput, CONFIRM_BUTTON}},
+    player::ship::SHIPS,
+    resources::{GameMode, GameSettings, LevelResource, Score, ShipSelectState},
+    state::states::GameStates,
+    utils::storage,
+};
+
+const SCORES_FILE:    &str = "highscores.txt";
+/// First line of the file. Bump `FORMAT_VERSION` whenever the entry layout
+/// changes, and keep reading the old layouts in `parse_entry`.
+const FORMAT_HEADER:  &str = "asteroid-defender-highscores";
+const FORMAT_VERSION: u32  = 1;
+
+/// Entries kept per ship and mode.
+pub const TABLE_SIZE:   usize = 10;
+pub const INITIALS_LEN: usize = 3;
+
+// ── Table ─────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq)]
+pub struct HighScoreEntry {
+    pub mode:     GameMode,
+    /// Index into `SHIPS`. Saved by name so reordering ships keeps the tables.
+    pub ship:     usize,
+    pub initials: String,
+    pub score:    i32,
+    pub level:    u32,
+    /// `YYYY-MM-DD`.
+    pub date:     String,
+}
+
+/// Top scores for every ship and game mode. Loaded at startup and saved
+/// whenever an entry is added.
+#[derive(Resource, Default)]
+pub struct HighScores {
+    entries:           Vec<HighScoreEntry>,
+    /// Initials of the last entry, offered again next time.
+    pub last_initials: Option<String>,
+}
+
+impl HighScores {
+    /// One table, best first.
+    pub fn table(&self, mode: GameMode, ship: usize) -> Vec<&HighScoreEntry> {
+        let mut table: Vec<&HighScoreEntry> =
+            self.entries.iter().filter(|e| e.mode == mode && e.ship == ship).collect();
+        table.sort_by_key(|e| std::cmp::Reverse(e.score));
+        table.truncate(TABLE_SIZE);
+        table
+    }
+
+    pub fn best(&self, mode: GameMode, ship: usize) -> i32 {
+        self.table(mode, ship).first().map_or(0, |e| e.score)
+    }
+
+    /// Whether `score` would make it onto the table. Ties don't bump older entries.
+    pub fn qualifies(&self, mode: GameMode, ship: usize, score: i32) -> bool {
+        let table = self.table(mode, ship);
+        score > 0 && (table.len() < TABLE_SIZE || table.last().is_none_or(|e| score > e.score))
+    }
+
+    /// Adds an entry, drops whatever fell off its table and saves. Returns
+    /// the entry's place in the table, 0 being the top.
+    pub fn insert(&mut self, entry: HighScoreEntry) -> usize {
+        let (mode, ship, score) = (entry.mode, entry.ship, entry.score);
+        self.last_initials = Some(entry.initials.clone());
+        // Later entries sort after earlier ones with the same score.
+        let rank = self.table(mode, ship).iter().filter(|e| e.score >= score).count();
+        self.entries.push(entry);
+        self.trim();
+        self.save();
+        rank
+    }
+
+    fn trim(&mut self) {
+        let mut kept: Vec<HighScoreEntry> = Vec::new();
+        for mode in GameMode::ALL {
+            for ship in 0..SHIPS.len() {
+                kept.extend(self.table(mode, ship).into_iter().cloned());
+            }
+        }
+        self.entries = kept;
+    }
+
+    /// Saved tables, or empty ones. Lines that don't parse are skipped; a file
+    /// with a missing header or an unknown version is set aside as `.bak` so
+    /// the next save doesn't destroy it.
+    pub fn load() -> Self {
+        let Some(text) = storage::load_data(SCORES_FILE) else { return HighScores::default() };
+        HighScores::parse(&text).unwrap_or_else(|| {
+            warn!("{SCORES_FILE} is unreadable or from a newer version; starting a new table");
+            storage::save_data(&format!("{SCORES_FILE}.bak"), &text);
+            HighScores::default()
+        })
+    }
+
+    pub fn save(&self) {
+        storage::save_data(SCORES_FILE, &self.to_text());
+    }
+
+    /// The whole file: `None` without a known header and version, otherwise
+    /// the tables with any damaged lines skipped.
+    fn parse(text: &str) -> Option<Self> {
+        let mut lines = text.lines();
+        let version = lines
+            .next()
+            .and_then(|header| header.strip_prefix(FORMAT_HEADER))
+            .and_then(|v| v.trim().strip_prefix('v'))
+            .and_then(|v| v.parse::<u32>().ok());
+        if !matches!(version, Some(v) if v <= FORMAT_VERSION) {
+            return None;
+        }
+
+        let mut scores = HighScores::default();
+        for line in lines.filter(|l| !l.trim().is_empty()) {
+            match parse_entry(line) {
+                Some(entry) => scores.entries.push(entry),
+                None        => warn!("Skipping damaged high score line: {line}"),
+            }
+        }
+        scores.trim();
+        Some(scores)
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!("{FORMAT_HEADER} v{FORMAT_VERSION}\n");
+        for e in &self.entries {
+            text += &format!(
+                "{};{};{};{};{};{}\n",
+                e.mode.id(), SHIPS[e.ship].name, e.initials, e.score, e.level, e.date,
+            );
+        }
+        text
+    }
+}
+
+/// `mode;ship;initials;score;level;date`, or `None` if any field is off.
+fn parse_entry(line: &str) -> Option<HighScoreEntry> {
+    let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+    let [mode, ship, initials, score, level, date] = fields[..] else { return None };
+
+    let valid_initials = initials.len() == INITIALS_LEN && initials.bytes().all(|b| b.is_ascii_uppercase());
+    let valid_date     = date.len() == 10 && date.bytes().all(|b| b.is_ascii_digit() || b == b'-');
+    if !valid_initials || !valid_date {
+        return None;
+    }
+    Some(HighScoreEntry {
+        mode:     *GameMode::ALL.iter().find(|m| m.id() == mode)?,
+        ship:     SHIPS.iter().position(|s| s.name == ship)?,
+        initials: initials.to_string(),
+        score:    score.parse().ok().filter(|s| *s > 0)?,
+        level:    level.parse().ok().filter(|l| *l > 0)?,
+        date:     date.to_string(),
+    })
+}
+
+// ── Initials entry ────────────────────────────────────────────────────────────
+
+/// Initials being typed on the game over screen after a qualifying run.
+#[derive(Resource, Default)]
+pub struct InitialsEntry {
+    /// Waiting for initials; the play-again prompt is held back meanwhile.
+    pub active:  bool,
+    pub letters: [u8; INITIALS_LEN],
+    /// Letter the cursor is on.
+    pub slot:    usize,
+    /// Where the finished entry landed, for highlighting in the table.
+    pub rank:    Option<usize>,
+}
+
+impl InitialsEntry {
+    pub fn initials(&self) -> String {
+        String::from_utf8_lossy(&self.letters).into_owned()
+    }
+
+    /// Steps the letter under the cursor through A–Z, wrapping.
+    pub fn cycle(&mut self, dir: i32) {
+        let letter = &mut self.letters[self.slot];
+        *letter = b'A' + (*letter as i32 - b'A' as i32 + dir).rem_euclid(26) as u8;
+    }
+
+    /// Sets the letter under the cursor and moves on to the next one.
+    pub fn type_letter(&mut self, letter: u8) {
+        self.letters[self.slot] = letter.to_ascii_uppercase();
+        self.slot = (self.slot + 1).min(INITIALS_LEN - 1);
+    }
+
+    pub fn move_cursor(&mut self, dir: i32) {
+        self.slot = (self.slot as i32 + dir).clamp(0, INITIALS_LEN as i32 - 1) as usize;
+    }
+}
+
+/// Records the finished initials with this run's result.
+pub fn submit_initials(
+    entry:     &mut InitialsEntry,
+    scores:    &mut HighScores,
+    settings:  &GameSettings,
+    selection: &ShipSelectState,
+    score:     &Score,
+    level:     &LevelResource,
+) {
+    entry.rank = Some(scores.insert(HighScoreEntry {
+        mode:     settings.mode,
+        ship:     selection.selected,
+        initials: entry.initials(),
+        score:    score.current,
+        level:    level.current,
+        date:     today(),
+    }));
+    entry.active = false;
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct HighScorePlugin;
+
+impl Plugin for HighScorePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<InitialsEntry>()
+            .add_systems(OnEnter(GameStates::GameOver), begin_initials_entry);
+    }
+}
+
+/// Opens initials entry if the run made the table, starting from the last
+/// initials used.
+pub fn begin_initials_entry(
+    scores:    Res<HighScores>,
+    settings:  Res<GameSettings>,
+    selection: Res<ShipSelectState>,
+    score:     Res<Score>,
+    mut entry: ResMut<InitialsEntry>,
+) {
+    let mut letters = [b'A'; INITIALS_LEN];
+    if let Some(last) = &scores.last_initials {
+        letters.copy_from_slice(&last.as_bytes()[..INITIALS_LEN]);
+    }
+    *entry = InitialsEntry {
+        active: scores.qualifies(settings.mode, selection.selected, score.current),
+        letters,
+        slot:   0,
+        rank:   None,
+    };
+}
+
+/// Letter keys type initials and Backspace steps back; the arrow keys or
+/// D-pad move the cursor (Left/Right) and change the letter (Up/Down).
+/// Confirm, or A on a pad, saves the entry.
+pub fn initials_input_system(
+    keyboard:   Res<Input<KeyCode>>,
+    actions:    Actions,
+    pad:        PadInput,
+    settings:   Res<GameSettings>,
+    selection:  Res<ShipSelectState>,
+    score:      Res<Score>,
+    level:      Res<LevelResource>,
+    mut scores: ResMut<HighScores>,
+    mut entry:  ResMut<InitialsEntry>,
+) {
+    if !entry.active {
+        return;
+    }
+
+    for key in keyboard.get_just_pressed() {
+        let name = format!("{key:?}");
+        if let [letter] = name.as_bytes() {
+            if letter.is_ascii_alphabetic() {
+                entry.type_letter(*letter);
+            }
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Back) {
+        entry.move_cursor(-1);
+    }
+    if keyboard.just_pressed(KeyCode::Left) || pad.just_pressed(GamepadButtonType::DPadLeft) {
+        entry.move_cursor(-1);
+    }
+    if keyboard.just_pressed(KeyCode::Right) || pad.just_pressed(GamepadButtonType::DPadRight) {
+        entry.move_cursor(1);
+    }
+    if keyboard.just_pressed(KeyCode::Up) || pad.just_pressed(GamepadButtonType::DPadUp) {
+        entry.cycle(1);
+    }
+    if keyboard.just_pressed(KeyCode::Down) || pad.just_pressed(GamepadButtonType::DPadDown) {
+        entry.cycle(-1);
+    }
+
+    if actions.just_pressed(Action::Confirm) || pad.just_pressed(CONFIRM_BUTTON) {
+        submit_initials(&mut entry, &mut scores, &settings, &selection, &score, &level);
+    }
+}
+
+// ── Dates ─────────────────────────────────────────────────────────────────────
+
+/// Today's date (UTC) as `YYYY-MM-DD`.
+pub fn today() -> String {
+    let (y, m, d) = civil_from_days(unix_days());
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn unix_days() -> i64 {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    (secs / 86_400) as i64
+}
+
+/// `SystemTime` isn't available in the browser.
+#[cfg(target_arch = "wasm32")]
+fn unix_days() -> i64 {
+    (js_sys::Date::now() / 86_400_000.0) as i64
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian (year, month, day).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z     = days + 719_468;
+    let era   = z.div_euclid(146_097);
+    let doe   = z.rem_euclid(146_097);
+    let yoe   = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy   = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp    = (5 * doy + 2) / 153;
+    let day   = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year  = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(mode: GameMode, ship: usize, initials: &str, score: i32) -> HighScoreEntry {
+        HighScoreEntry { mode, ship, initials: initials.to_string(), score, level: 4, date: "2024-05-01".to_string() }
+    }
+
+    fn header() -> String {
+        format!("{FORMAT_HEADER} v{FORMAT_VERSION}")
+    }
+
+    #[test]
+    fn round_trip() {
+        let scores = HighScores {
+            entries: vec![
+                entry(GameMode::Classic,  0, "ABC", 1200),
+                entry(GameMode::Classic,  0, "XYZ", 800),
+                entry(GameMode::Survival, 1, "JKL", 5000),
+            ],
+            last_initials: None,
+        };
+        let parsed = HighScores::parse(&scores.to_text()).expect("own output parses");
+        assert!(parsed.entries == scores.entries);
+    }
+
+    #[test]
+    fn skips_truncated_and_garbled_lines() {
+        let good = format!("Classic;{};ABC;1200;4;2024-05-01", SHIPS[0].name);
+        let text = [
+            header(),
+            good.clone(),
+            format!("Classic;{};ABC;12", SHIPS[0].name),
+            "###garbage###".to_string(),
+            format!("Classic;{};abc;1200;4;2024-05-01", SHIPS[0].name),
+            format!("Classic;{};ABC;-5;4;2024-05-01", SHIPS[0].name),
+            "Arcade;NOSHIP;ABC;1200;4;2024-05-01".to_string(),
+        ]
+        .join("\n");
+        let parsed = HighScores::parse(&text).expect("header is fine");
+        assert_eq!(parsed.entries.len(), 1);
+        assert!(parsed.entries[0] == parse_entry(&good).unwrap());
+    }
+
+    #[test]
+    fn rejects_missing_header_and_newer_version() {
+        let line = format!("Classic;{};ABC;1200;4;2024-05-01", SHIPS[0].name);
+        assert!(HighScores::parse(&line).is_none());
+        assert!(HighScores::parse("").is_none());
+        let newer = format!("{FORMAT_HEADER} v{}\n{line}", FORMAT_VERSION + 1);
+        assert!(HighScores::parse(&newer).is_none());
+        let garbled = format!("{FORMAT_HEADER} vX\n{line}");
+        assert!(HighScores::parse(&garbled).is_none());
+    }
+
+    #[test]
+    fn keeps_only_the_top_of_each_table() {
+        let mut text = header();
+        for score in 1..=(TABLE_SIZE as i32 + 5) {
+            text += &format!("\nClassic;{};ABC;{};1;2024-05-01", SHIPS[0].name, score * 100);
+        }
+        let parsed = HighScores::parse(&text).unwrap();
+        let table  = parsed.table(GameMode::Classic, 0);
+        assert_eq!(table.len(), TABLE_SIZE);
+        assert_eq!(table[0].score, (TABLE_SIZE as i32 + 5) * 100);
+    }
+}
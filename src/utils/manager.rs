@@ -1,10 +1,6 @@
 use bevy::prelude::{ResMut, NextState};
 use crate::state::states::GameStates;
 
-pub fn goto_countdown(mut next: ResMut<NextState<GameStates>>) {
-    next.set(GameStates::Countdown);
-}
-
 pub fn goto_in_game(mut next: ResMut<NextState<GameStates>>) {
     next.set(GameStates::InGame);
 }
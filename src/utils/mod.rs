@@ -1,3 +1,8 @@
+pub mod achievements;
 pub mod cleanup;
+pub mod highscores;
 pub mod manager;
-pub mod utils;
+pub mod run_stats;
+pub mod savegame;
+pub mod storage;
+pub mod utils;
\ No newline at end of file
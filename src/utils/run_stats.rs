@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use lib::MeteorSizeType;
+use crate::{
+    common::common_components::DamageSource,
+    objects::{powerup::PowerUpKind, ufo::UfoType},
+    player::ship::SHIPS,
+    resources::{GameSettings, IsPaused, LevelResource, Score, ShipSelectState},
+    state::states::GameStates,
+    upgrades::upgrades::UpgradeType,
+    utils::{highscores::today, storage},
+};
+
+/// One line per finished run, oldest first.
+const HISTORY_FILE: &str = "run_history.txt";
+
+pub const METEOR_LABELS:  [&str; 3] = ["SMALL", "MEDIUM", "LARGE"];
+pub const UFO_LABELS:     [&str; 4] = ["SCOUT", "GUNSHIP", "BOMBER", "SNIPER"];
+pub const DAMAGE_LABELS:  [&str; 5] = ["METEORS", "UFO FIRE", "BOSS", "BLASTS", "HYPERSPACE"];
+pub const POWERUP_LABELS: [&str; 4] = ["HP", "BOLT", "SHIELD", "AMMO"];
+
+// ── Stats ─────────────────────────────────────────────────────────────────────
+
+/// What happened during the current run, for the game over summary and the
+/// run history. Reset when a run starts and saved with the run.
+#[derive(Resource, Default, Clone)]
+pub struct RunStats {
+    /// Primary weapon shots; every pellet and beam tick counts.
+    pub shots_fired: u32,
+    /// Shots that struck something at least once.
+    pub shots_hit:   u32,
+    /// Indexed like `METEOR_LABELS`.
+    pub meteors:     [u32; 3],
+    /// Indexed by `UfoType as usize`, like `UFO_LABELS`.
+    pub ufos:        [u32; 4],
+    pub bosses:      u32,
+    /// HP lost, indexed by `DamageSource as usize`.
+    pub damage:      [i32; 5],
+    /// Indexed by `PowerUpKind as usize`.
+    pub powerups:    [u32; 4],
+    /// Upgrades in the order they were picked.
+    pub upgrades:    Vec<UpgradeType>,
+    /// Seconds spent in each level played; the last is unfinished after a death.
+    pub level_times: Vec<f32>,
+    /// Seconds in the current level so far.
+    level_timer:     f32,
+}
+
+impl RunStats {
+    /// Share of shots that hit, 0–1.
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired == 0 { 0.0 } else { (self.shots_hit as f32 / self.shots_fired as f32).min(1.0) }
+    }
+
+    pub fn record_meteor(&mut self, size: MeteorSizeType) {
+        self.meteors[size as usize - 1] += 1;
+    }
+
+    pub fn record_ufo(&mut self, ufo: UfoType) {
+        self.ufos[ufo as usize] += 1;
+    }
+
+    pub fn record_damage(&mut self, source: DamageSource, amount: i32) {
+        self.damage[source as usize] += amount;
+    }
+
+    pub fn record_powerup(&mut self, kind: PowerUpKind) {
+        self.powerups[kind as usize] += 1;
+    }
+
+    /// `key=value` pairs separated by `;`, lists by `,`. Used for the run
+    /// history and the run save.
+    pub fn to_line(&self) -> String {
+        let upgrades: Vec<&str> = self.upgrades.iter().map(|u| u.name()).collect();
+        let times: Vec<String> = self.level_times.iter().map(|t| format!("{t:.1}")).collect();
+        format!(
+            "shots={};hits={};meteors={};ufos={};bosses={};damage={};powerups={};upgrades={};level_times={}",
+            self.shots_fired,
+            self.shots_hit,
+            join(&self.meteors),
+            join(&self.ufos),
+            self.bosses,
+            join(&self.damage),
+            join(&self.powerups),
+            upgrades.join(","),
+            times.join(","),
+        )
+    }
+
+    /// Reads back `to_line`, or `None` if anything is missing or malformed.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let map: HashMap<&str, &str> = line
+            .split(';')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .collect();
+
+        let upgrades = list(map.get("upgrades")?)
+            .into_iter()
+            .map(|name| UpgradeType::all().iter().copied().find(|u| u.name() == name))
+            .collect::<Option<Vec<_>>>()?;
+        let level_times = list(map.get("level_times")?)
+            .into_iter()
+            .map(|t| t.parse().ok())
+            .collect::<Option<Vec<f32>>>()?;
+
+        Some(RunStats {
+            shots_fired: map.get("shots")?.parse().ok()?,
+            shots_hit:   map.get("hits")?.parse().ok()?,
+            meteors:     counts(map.get("meteors")?)?,
+            ufos:        counts(map.get("ufos")?)?,
+            bosses:      map.get("bosses")?.parse().ok()?,
+            damage:      counts(map.get("damage")?)?,
+            powerups:    counts(map.get("powerups")?)?,
+            upgrades,
+            level_times,
+            level_timer: 0.0,
+        })
+    }
+
+    /// Closes the current level's timer.
+    fn finish_level(&mut self) {
+        self.level_times.push(self.level_timer);
+        self.level_timer = 0.0;
+    }
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// Comma-separated items; empty for an empty list.
+fn list(text: &str) -> Vec<&str> {
+    if text.is_empty() { Vec::new() } else { text.split(',').collect() }
+}
+
+/// A fixed-size comma-separated list of counts.
+fn counts<T: std::str::FromStr + Default + Copy, const N: usize>(text: &str) -> Option<[T; N]> {
+    let items = list(text);
+    if items.len() != N {
+        return None;
+    }
+    let mut out = [T::default(); N];
+    for (slot, item) in out.iter_mut().zip(items) {
+        *slot = item.parse().ok()?;
+    }
+    Some(out)
+}
+
+/// Level times as `m:ss`, numbered from level 1.
+pub fn format_level_times(times: &[f32]) -> String {
+    times
+        .iter()
+        .enumerate()
+        .map(|(i, t)| format!("{}: {}:{:02}", i + 1, *t as u32 / 60, *t as u32 % 60))
+        .collect::<Vec<_>>()
+        .join("   ")
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct RunStatsPlugin;
+
+impl Plugin for RunStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RunStats>()
+            .add_systems(
+                Update,
+                level_timer_system
+                    .run_if(in_state(GameStates::InGame))
+                    .run_if(|p: Res<IsPaused>| !p.0),
+            )
+            .add_systems(OnEnter(GameStates::LevelComplete), finish_level_timer)
+            .add_systems(OnEnter(GameStates::GameOver), (finish_level_timer, append_run_history).chain());
+    }
+}
+
+pub fn reset_run_stats(mut stats: ResMut<RunStats>) {
+    *stats = RunStats::default();
+}
+
+fn level_timer_system(time: Res<Time>, mut stats: ResMut<RunStats>) {
+    stats.level_timer += time.delta_seconds();
+}
+
+pub fn finish_level_timer(mut stats: ResMut<RunStats>) {
+    stats.finish_level();
+}
+
+/// Adds the finished run to the history file.
+fn append_run_history(
+    stats:     Res<RunStats>,
+    settings:  Res<GameSettings>,
+    selection: Res<ShipSelectState>,
+    score:     Res<Score>,
+    level:     Res<LevelResource>,
+) {
+    let line = format!(
+        "date={};mode={};ship={};score={};level={};{}",
+        today(),
+        settings.mode.id(),
+        SHIPS[selection.selected].name,
+        score.current,
+        level.current,
+        stats.to_line(),
+    );
+    storage::append_data(HISTORY_FILE, &line);
+}
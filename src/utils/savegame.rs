@@ -0,0 +1,476 @@
+use std::{collections::HashMap, str::FromStr};
+
+use bevy::prelude::*;
+
+use lib::{SecondaryWeapon, WeaponArchetype, SECONDARY_AMMO, WEAPON_STATS};
+use crate::{
+    objects::{secondary::secondary_name, weapon::weapon_name},
+    player::{ship::SHIPS, skin::{SkinProgress, SKINS}},
+    resources::{
+        is_classic, GameMode, GameSettings, LevelResource, Life, PlayerBuff, PlayerUpgrades, RunRng, Score, SecondaryAmmo,
+        ShipSelectState, UpgradeSelectionState,
+    },
+    state::states::GameStates,
+    upgrades::upgrades::setup_upgrade_selection,
+    utils::{run_stats::RunStats, storage},
+};
+
+const RUN_FILE:       &str = "run.sav";
+/// First line of the file. A save from another version is thrown away
+/// rather than guessed at; it only ever holds one run.
+const FORMAT_HEADER:  &str = "asteroid-defender-run";
+const FORMAT_VERSION: u32  = 1;
+
+/// Resumes the saved run from the ship select screen. Y on Xbox layouts.
+pub const CONTINUE_BUTTON: GamepadButtonType = GamepadButtonType::North;
+
+// ── Snapshot ──────────────────────────────────────────────────────────────────
+
+/// Everything needed to pick a run back up between levels.
+#[derive(Clone)]
+pub struct RunSnapshot {
+    /// `UpgradeSelection` or `Countdown`; where the run resumes.
+    pub state:          GameStates,
+    /// Index into `SHIPS`. Saved by name, like the high score tables.
+    pub ship:           usize,
+    /// Index into `SKINS`, saved by name.
+    pub skin:           usize,
+    pub mode:           GameMode,
+    pub weapon:         WeaponArchetype,
+    pub weapon_heat:    bool,
+    pub secondary:      SecondaryWeapon,
+    pub meteor_physics: bool,
+    pub hit_invuln:     f32,
+    pub level:          u32,
+    pub boss_defeated:  bool,
+    /// The next upgrade offer is owed a rare card.
+    pub boss_reward:    bool,
+    pub rerolls:        u32,
+    pub score:          i32,
+    pub max_life:       i32,
+    pub current_life:   i32,
+    pub ammo:           i32,
+    pub rng:            u64,
+    pub upgrades:       PlayerUpgrades,
+    pub buff:           PlayerBuff,
+    pub stats:          RunStats,
+}
+
+impl RunSnapshot {
+    fn save(&self) {
+        storage::save_data(RUN_FILE, &self.to_text());
+    }
+
+    /// The snapshot as `key=value` lines under the format header.
+    fn to_text(&self) -> String {
+        let u = &self.upgrades;
+        let b = &self.buff;
+        let fields: Vec<(&str, String)> = vec![
+            ("state",            state_id(self.state).to_string()),
+            ("ship",             SHIPS[self.ship].name.to_string()),
+            ("skin",             SKINS[self.skin].name.to_string()),
+            ("mode",             self.mode.id().to_string()),
+            ("weapon",           weapon_name(self.weapon).to_string()),
+            ("weapon_heat",      self.weapon_heat.to_string()),
+            ("secondary",        secondary_name(self.secondary).to_string()),
+            ("meteor_physics",   self.meteor_physics.to_string()),
+            ("hit_invuln",       self.hit_invuln.to_string()),
+            ("level",            self.level.to_string()),
+            ("boss_defeated",    self.boss_defeated.to_string()),
+            ("boss_reward",      self.boss_reward.to_string()),
+            ("rerolls",          self.rerolls.to_string()),
+            ("score",            self.score.to_string()),
+            ("max_life",         self.max_life.to_string()),
+            ("current_life",     self.current_life.to_string()),
+            ("ammo",             self.ammo.to_string()),
+            ("rng",              self.rng.to_string()),
+            // Upgrades
+            ("split_shot",       u.split_shot.to_string()),
+            ("rear_guard",       u.rear_guard.to_string()),
+            ("rapid_fire",       u.rapid_fire.to_string()),
+            ("heavy_rounds",     u.heavy_rounds.to_string()),
+            ("ricochet",         u.ricochet.to_string()),
+            ("accelerator",      u.accelerator.to_string()),
+            ("piercing_rounds",  u.piercing_rounds.to_string()),
+            ("explosive_rounds", u.explosive_rounds.to_string()),
+            ("extra_armor",      u.extra_armor.to_string()),
+            ("afterburner",      u.afterburner.to_string()),
+            ("quick_reflexes",   u.quick_reflexes.to_string()),
+            ("long_shot",        u.long_shot.to_string()),
+            ("bulwark",          u.bulwark.to_string()),
+            ("stable_warp",      u.stable_warp.to_string()),
+            ("overclock",        u.overclock.to_string()),
+            ("chain_reaction",   u.chain_reaction.to_string()),
+            ("asteroid_magnet",  u.asteroid_magnet.to_string()),
+            ("glass_cannon",     u.glass_cannon.to_string()),
+            ("detonator_rounds", u.detonator_rounds.to_string()),
+            ("drones",           u.drones.to_string()),
+            ("drone_overdrive",  u.drone_overdrive.to_string()),
+            ("drone_plating",    u.drone_plating.to_string()),
+            ("chain_active",     u.chain_active.to_string()),
+            ("chain_timer",      u.chain_timer.to_string()),
+            // Buffs
+            ("bolt_timer",       b.bolt_timer.to_string()),
+            ("shield_timer",     b.shield_timer.to_string()),
+            ("slow_timer",       b.slow_timer.to_string()),
+            ("dash_timer",       b.dash_timer.to_string()),
+            ("overcharge_timer", b.overcharge_timer.to_string()),
+            ("invuln_timer",     b.invuln_timer.to_string()),
+            ("stats",            self.stats.to_line()),
+        ];
+
+        let mut text = format!("{FORMAT_HEADER} v{FORMAT_VERSION}\n");
+        for (key, value) in fields {
+            text += &format!("{key}={value}\n");
+        }
+        text
+    }
+
+    /// The saved run, if there is one and every field reads back. A damaged
+    /// save is deleted so it isn't offered again.
+    fn load() -> Option<Self> {
+        let text = storage::load_data(RUN_FILE)?;
+        let snapshot = parse(&text);
+        if snapshot.is_none() {
+            warn!("{RUN_FILE} is damaged or from another version; discarding it");
+            storage::remove_data(RUN_FILE);
+        }
+        snapshot
+    }
+}
+
+/// Reads a whole save; any missing or malformed field rejects it.
+fn parse(text: &str) -> Option<RunSnapshot> {
+    let mut lines = text.lines();
+    if lines.next()?.trim() != format!("{FORMAT_HEADER} v{FORMAT_VERSION}") {
+        return None;
+    }
+    let map: HashMap<&str, &str> = lines
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .collect();
+    let value = |key: &str| map.get(key).copied();
+
+    let upgrades = PlayerUpgrades {
+        split_shot:       field(&map, "split_shot")?,
+        rear_guard:       field(&map, "rear_guard")?,
+        rapid_fire:       field(&map, "rapid_fire")?,
+        heavy_rounds:     field(&map, "heavy_rounds")?,
+        ricochet:         field(&map, "ricochet")?,
+        accelerator:      field(&map, "accelerator")?,
+        piercing_rounds:  field(&map, "piercing_rounds")?,
+        explosive_rounds: field(&map, "explosive_rounds")?,
+        extra_armor:      field(&map, "extra_armor")?,
+        afterburner:      field(&map, "afterburner")?,
+        quick_reflexes:   field(&map, "quick_reflexes")?,
+        long_shot:        field(&map, "long_shot")?,
+        bulwark:          field(&map, "bulwark")?,
+        stable_warp:      field(&map, "stable_warp")?,
+        overclock:        field(&map, "overclock")?,
+        chain_reaction:   field(&map, "chain_reaction")?,
+        asteroid_magnet:  field(&map, "asteroid_magnet")?,
+        glass_cannon:     field(&map, "glass_cannon")?,
+        detonator_rounds: field(&map, "detonator_rounds")?,
+        drones:           field(&map, "drones")?,
+        drone_overdrive:  field(&map, "drone_overdrive")?,
+        drone_plating:    field(&map, "drone_plating")?,
+        chain_active:     field(&map, "chain_active")?,
+        chain_timer:      field(&map, "chain_timer")?,
+    };
+    let buff = PlayerBuff {
+        bolt_timer:       field(&map, "bolt_timer")?,
+        shield_timer:     field(&map, "shield_timer")?,
+        slow_timer:       field(&map, "slow_timer")?,
+        dash_timer:       field(&map, "dash_timer")?,
+        overcharge_timer: field(&map, "overcharge_timer")?,
+        invuln_timer:     field(&map, "invuln_timer")?,
+    };
+
+    let stats = RunStats::from_line(value("stats")?)?;
+
+    let max_life:     i32 = field(&map, "max_life")?;
+    let current_life: i32 = field(&map, "current_life")?;
+    if !(1..=max_life).contains(&current_life) {
+        return None;
+    }
+
+    Some(RunSnapshot {
+        state:          [GameStates::UpgradeSelection, GameStates::Countdown]
+                            .into_iter()
+                            .find(|s| Some(state_id(*s)) == value("state"))?,
+        ship:           SHIPS.iter().position(|s| Some(s.name) == value("ship"))?,
+        skin:           SKINS.iter().position(|s| Some(s.name) == value("skin"))?,
+        mode:           *GameMode::ALL.iter().find(|m| Some(m.id()) == value("mode"))?,
+        weapon:         WEAPON_STATS
+                            .iter()
+                            .map(|(w, _)| *w)
+                            .find(|w| Some(weapon_name(*w)) == value("weapon"))?,
+        weapon_heat:    field(&map, "weapon_heat")?,
+        secondary:      SECONDARY_AMMO
+                            .iter()
+                            .map(|(s, _)| *s)
+                            .find(|s| Some(secondary_name(*s)) == value("secondary"))?,
+        meteor_physics: field(&map, "meteor_physics")?,
+        hit_invuln:     field(&map, "hit_invuln")?,
+        level:          field(&map, "level").filter(|l: &u32| *l > 0)?,
+        boss_defeated:  field(&map, "boss_defeated")?,
+        boss_reward:    field(&map, "boss_reward")?,
+        rerolls:        field(&map, "rerolls")?,
+        score:          field(&map, "score")?,
+        max_life,
+        current_life,
+        ammo:           field(&map, "ammo").filter(|a: &i32| *a >= 0)?,
+        rng:            field(&map, "rng")?,
+        upgrades,
+        buff,
+        stats,
+    })
+}
+
+fn field<T: FromStr>(map: &HashMap<&str, &str>, key: &str) -> Option<T> {
+    map.get(key)?.parse().ok()
+}
+
+fn state_id(state: GameStates) -> &'static str {
+    match state {
+        GameStates::UpgradeSelection => "UpgradeSelection",
+        _                            => "Countdown",
+    }
+}
+
+// ── Saved run ─────────────────────────────────────────────────────────────────
+
+/// The run on disk, if any, and the one being resumed. The file only exists
+/// between levels: it's deleted as soon as a level starts, so quitting or
+/// crashing mid-level can't bring the level back, and so does continuing
+/// or the run ending.
+#[derive(Resource, Default)]
+pub struct SavedRun {
+    /// Mirrors the save file; shown on the ship select screen.
+    pub snapshot: Option<RunSnapshot>,
+    /// Taken from `snapshot` by `continue_run`, applied during `StartGame`.
+    resume:       Option<RunSnapshot>,
+}
+
+impl SavedRun {
+    pub fn load() -> Self {
+        SavedRun { snapshot: RunSnapshot::load(), resume: None }
+    }
+
+    fn clear(&mut self) {
+        if self.snapshot.take().is_some() {
+            storage::remove_data(RUN_FILE);
+        }
+    }
+}
+
+/// Picks the saved ship, skin and options back up and starts the run. Does
+/// nothing without a save.
+pub fn continue_run(
+    saved:      &mut SavedRun,
+    selection:  &mut ShipSelectState,
+    settings:   &mut GameSettings,
+    skins:      &mut SkinProgress,
+    next_state: &mut NextState<GameStates>,
+) {
+    let Some(snapshot) = saved.snapshot.take() else { return };
+    storage::remove_data(RUN_FILE);
+
+    selection.selected      = snapshot.ship;
+    selection.row           = 0;
+    skins.selected          = snapshot.skin;
+    settings.mode           = snapshot.mode;
+    settings.weapon         = snapshot.weapon;
+    settings.weapon_heat    = snapshot.weapon_heat;
+    settings.secondary      = snapshot.secondary;
+    settings.meteor_physics = snapshot.meteor_physics;
+    settings.hit_invuln     = snapshot.hit_invuln;
+
+    saved.resume = Some(snapshot);
+    next_state.set(GameStates::StartGame);
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct SaveGamePlugin;
+
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Saved before the offer is rolled, so a resumed run rolls the same cards.
+            // Survival runs are played in one sitting and never saved.
+            .add_systems(
+                OnEnter(GameStates::UpgradeSelection),
+                save_run_system.before(setup_upgrade_selection).run_if(is_classic),
+            )
+            .add_systems(OnEnter(GameStates::Countdown), save_run_system.run_if(is_classic))
+            // The countdown save is only there to resume from; playing the level uses it up.
+            .add_systems(OnEnter(GameStates::InGame), clear_run_save.run_if(is_classic))
+            .add_systems(OnEnter(GameStates::GameOver), clear_run_save.run_if(is_classic))
+            .add_systems(
+                OnTransition { from: GameStates::InGame, to: GameStates::ShipSelect },
+                clear_run_save.run_if(is_classic),
+            );
+    }
+}
+
+fn save_run_system(
+    state:      Res<State<GameStates>>,
+    selection:  Res<ShipSelectState>,
+    skins:      Res<SkinProgress>,
+    settings:   Res<GameSettings>,
+    level:      Res<LevelResource>,
+    offer:      Res<UpgradeSelectionState>,
+    score:      Res<Score>,
+    life:       Res<Life>,
+    ammo:       Res<SecondaryAmmo>,
+    rng:        Res<RunRng>,
+    upgrades:   Res<PlayerUpgrades>,
+    buff:       Res<PlayerBuff>,
+    stats:      Res<RunStats>,
+    mut saved:  ResMut<SavedRun>,
+) {
+    let snapshot = RunSnapshot {
+        state:          *state.get(),
+        ship:           selection.selected,
+        skin:           skins.equipped(),
+        mode:           settings.mode,
+        weapon:         settings.weapon,
+        weapon_heat:    settings.weapon_heat,
+        secondary:      settings.secondary,
+        meteor_physics: settings.meteor_physics,
+        hit_invuln:     settings.hit_invuln,
+        level:          level.current,
+        boss_defeated:  level.boss_defeated,
+        boss_reward:    offer.boss_reward,
+        rerolls:        offer.rerolls,
+        score:          score.current,
+        max_life:       life.max_life,
+        current_life:   life.current_life,
+        ammo:           ammo.ammo,
+        rng:            rng.state,
+        upgrades:       upgrades.clone(),
+        buff:           buff.clone(),
+        stats:          stats.clone(),
+    };
+    snapshot.save();
+    saved.snapshot = Some(snapshot);
+}
+
+fn clear_run_save(mut saved: ResMut<SavedRun>) {
+    saved.clear();
+}
+
+/// Puts the resumed run's resources back after the fresh-run resets and the
+/// player spawn, overwriting the ship's starting HP and upgrades.
+pub fn restore_run_system(
+    saved:        Res<SavedRun>,
+    mut level:    ResMut<LevelResource>,
+    mut offer:    ResMut<UpgradeSelectionState>,
+    mut score:    ResMut<Score>,
+    mut life:     ResMut<Life>,
+    mut ammo:     ResMut<SecondaryAmmo>,
+    mut rng:      ResMut<RunRng>,
+    mut upgrades: ResMut<PlayerUpgrades>,
+    mut buff:     ResMut<PlayerBuff>,
+    mut stats:    ResMut<RunStats>,
+) {
+    let Some(snapshot) = &saved.resume else { return };
+
+    level.current       = snapshot.level;
+    level.boss_defeated = snapshot.boss_defeated;
+    offer.boss_reward   = snapshot.boss_reward;
+    offer.rerolls       = snapshot.rerolls;
+    score.current       = snapshot.score;
+    life.max_life       = snapshot.max_life;
+    life.current_life   = snapshot.current_life;
+    ammo.ammo           = snapshot.ammo.min(ammo.max_ammo);
+    rng.state           = snapshot.rng;
+    *upgrades           = snapshot.upgrades.clone();
+    *buff               = snapshot.buff.clone();
+    *stats              = snapshot.stats.clone();
+}
+
+/// Replaces the usual hop to the countdown at the end of `StartGame`: a
+/// resumed run goes back to the screen it was saved on.
+pub fn goto_run_start(mut saved: ResMut<SavedRun>, mut next: ResMut<NextState<GameStates>>) {
+    let state = saved.resume.take().map_or(GameStates::Countdown, |s| s.state);
+    next.set(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> RunSnapshot {
+        let mut stats = RunStats::default();
+        stats.shots_fired = 120;
+        stats.shots_hit   = 45;
+        stats.meteors     = [3, 7, 12];
+        stats.level_times = vec![41.5, 63.0];
+        RunSnapshot {
+            state:          GameStates::UpgradeSelection,
+            ship:           SHIPS.len() - 1,
+            skin:           0,
+            mode:           GameMode::Classic,
+            weapon:         WEAPON_STATS[WEAPON_STATS.len() - 1].0,
+            weapon_heat:    true,
+            secondary:      SECONDARY_AMMO[0].0,
+            meteor_physics: false,
+            hit_invuln:     1.5,
+            level:          6,
+            boss_defeated:  true,
+            boss_reward:    false,
+            rerolls:        2,
+            score:          48_250,
+            max_life:       5,
+            current_life:   3,
+            ammo:           4,
+            rng:            0xDEAD_BEEF_1234_5678,
+            upgrades:       PlayerUpgrades { split_shot: 2, ricochet: true, drones: 1, ..default() },
+            buff:           PlayerBuff { shield_timer: 2.25, ..default() },
+            stats,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let text   = snapshot().to_text();
+        let parsed = parse(&text).expect("own output parses");
+        assert_eq!(parsed.to_text(), text);
+        assert_eq!(parsed.rng, snapshot().rng);
+        assert!(parsed.state == GameStates::UpgradeSelection);
+    }
+
+    #[test]
+    fn rejects_truncated_save() {
+        let text = snapshot().to_text();
+        let cut  = &text[..text.len() / 2];
+        assert!(parse(cut).is_none());
+        assert!(parse("").is_none());
+        // Stats are written last, so a save cut at a line end loses them first.
+        let no_stats: Vec<&str> = text.lines().filter(|l| !l.starts_with("stats=")).collect();
+        assert!(parse(&no_stats.join("\n")).is_none());
+    }
+
+    #[test]
+    fn rejects_garbled_fields() {
+        let text = snapshot().to_text();
+        assert!(parse(&text.replace("score=48250", "score=lots")).is_none());
+        assert!(parse(&text.replace("shots=120", "shots=many")).is_none());
+        assert!(parse(&text.replace("level=6", "level=0")).is_none());
+        assert!(parse(&text.replace("rerolls=2\n", "")).is_none());
+        assert!(parse(&text.replace("current_life=3", "current_life=9")).is_none());
+        assert!(parse(&text.replace("state=UpgradeSelection", "state=InGame")).is_none());
+        let ship = format!("ship={}", SHIPS[SHIPS.len() - 1].name);
+        assert!(parse(&text.replace(&ship, "ship=NOSHIP")).is_none());
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let text = snapshot().to_text();
+        let current = format!("{FORMAT_HEADER} v{FORMAT_VERSION}");
+        assert!(parse(&text.replace(&current, &format!("{FORMAT_HEADER} v{}", FORMAT_VERSION + 1))).is_none());
+        assert!(parse(&text.replace(&current, "asteroid-defender-highscores v1")).is_none());
+    }
+}
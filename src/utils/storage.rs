@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+/// Folder (desktop) or key prefix (WASM) every saved file lives under.
+const APP_NAME: &str = "asteroid-defender";
+
+// ── Desktop: files in the OS config and data directories ─────────────────────
+
+/// Reads a saved settings file, or `None` if it was never written.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(name: &str) -> Option<String> {
+    std::fs::read_to_string(dirs::config_dir()?.join(APP_NAME).join(name)).ok()
+}
+
+/// Writes a settings file to the config directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(name: &str, contents: &str) {
+    if let Some(dir) = dirs::config_dir() {
+        write(&dir.join(APP_NAME).join(name), contents);
+    }
+}
+
+/// Like `load`, from the data directory, which holds records rather than settings.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_data(name: &str) -> Option<String> {
+    std::fs::read_to_string(dirs::data_dir()?.join(APP_NAME).join(name)).ok()
+}
+
+/// Like `save`, to the data directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_data(name: &str, contents: &str) {
+    if let Some(dir) = dirs::data_dir() {
+        write(&dir.join(APP_NAME).join(name), contents);
+    }
+}
+
+/// Deletes a data file; a file that was never written is fine.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn remove_data(name: &str) {
+    if let Some(dir) = dirs::data_dir() {
+        let _ = std::fs::remove_file(dir.join(APP_NAME).join(name));
+    }
+}
+
+/// Adds a line to the end of a data file, creating it on first use.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn append_data(name: &str, line: &str) {
+    use std::io::Write;
+
+    let Some(dir) = dirs::data_dir() else { return };
+    let dir = dir.join(APP_NAME);
+    let _ = std::fs::create_dir_all(&dir);
+    let path = dir.join(name);
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{line}"));
+    if let Err(e) = result {
+        warn!("Couldn't save {}: {e}", path.display());
+    }
+}
+
+/// Writes through a temporary file so a crash mid-write can't leave a
+/// half-written save, creating the folder on first use. Failures are logged
+/// and otherwise ignored; losing a save shouldn't stop the game.
+#[cfg(not(target_arch = "wasm32"))]
+fn write(path: &std::path::Path, contents: &str) {
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let tmp = path.with_extension("tmp");
+    if let Err(e) = std::fs::write(&tmp, contents).and_then(|_| std::fs::rename(&tmp, path)) {
+        warn!("Couldn't save {}: {e}", path.display());
+    }
+}
+
+// ── WASM: browser localStorage ────────────────────────────────────────────────
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(name: &str) -> Option<String> {
+    local_storage()?.get_item(&key(name)).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(name: &str, contents: &str) {
+    let Some(storage) = local_storage() else { return };
+    if storage.set_item(&key(name), contents).is_err() {
+        warn!("Couldn't save {name} to localStorage");
+    }
+}
+
+/// The browser has one store, so data files sit next to settings.
+#[cfg(target_arch = "wasm32")]
+pub fn load_data(name: &str) -> Option<String> {
+    load(name)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save_data(name: &str, contents: &str) {
+    save(name, contents);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn append_data(name: &str, line: &str) {
+    let text = load(name).unwrap_or_default();
+    save(name, &format!("{text}{line}\n"));
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn remove_data(name: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(&key(name));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn key(name: &str) -> String {
+    format!("{APP_NAME}/{name}")
+}